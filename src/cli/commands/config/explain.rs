@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::{ConfigError, ConfigRuntime},
+};
+use async_trait::async_trait;
+
+/// Command for showing where a configuration value comes from.
+///
+/// Lists every config file (and matching `[[when]]` block) that sets the
+/// path, starting with the highest precedence. Sources using array merge
+/// directives are listed as modifying the value rather than setting it.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle config explain modules.battery.enabled
+/// wayle config explain general.log_level
+/// ```
+pub struct ExplainCommand {
+    /// Shared reference to the configuration store.
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl ExplainCommand {
+    /// Creates a new ExplainCommand with the provided config store.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Shared reference to the configuration store
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for ExplainCommand {
+    /// Reports the effective value of a path and the sources that set it.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments, expecting exactly one path argument
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::MissingPath` - If no path argument is provided
+    /// * `CliError::ConfigPathNotFound` - If the configuration path doesn't exist
+    /// * `CliError::ConfigOperationFailed` - If the config files cannot be loaded
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let path = args.first().ok_or(CliError::MissingPath)?;
+
+        let value = self.config_runtime.get_by_path(path).map_err(|e| match e {
+            ConfigError::InvalidPath(_) => CliError::ConfigPathNotFound { path: path.clone() },
+            _ => CliError::ConfigOperationFailed {
+                operation: "explain".to_string(),
+                path: path.clone(),
+                details: e.to_string(),
+            },
+        })?;
+
+        let origins =
+            self.config_runtime
+                .explain(path)
+                .map_err(|e| CliError::ConfigOperationFailed {
+                    operation: "explain".to_string(),
+                    path: path.clone(),
+                    details: e.to_string(),
+                })?;
+
+        let mut output = format!("{path} = {value}");
+        let mut value_set = false;
+
+        for origin in &origins {
+            let label = if value_set {
+                "overrides"
+            } else if origin.is_merge_directive() {
+                "modified by"
+            } else {
+                value_set = true;
+                "set by"
+            };
+
+            output.push_str(&format!("\n  {label}: {} = {}", origin.source, origin.value));
+        }
+
+        if !value_set {
+            output.push_str("\n  set by: built-in default");
+        }
+
+        Ok(output)
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "explain".to_string(),
+            description: "Show which config files set a value".to_string(),
+            category: "config".to_string(),
+            args: vec![CommandArg {
+                name: "path".to_string(),
+                description: "Configuration path (e.g., modules.battery.enabled)".to_string(),
+                required: true,
+                value_type: ArgType::Path,
+            }],
+            examples: vec![
+                "wayle config explain modules.battery.enabled".to_string(),
+                "wayle config explain general.log_level".to_string(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+
+use crate::{
+    WayleError,
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config::{Config, ConfigPaths, MigrationPlan},
+};
+
+const DRY_RUN_FLAG: &str = "--dry-run";
+
+/// Command for migrating configuration files to the current schema version.
+///
+/// Migrates the main config file, its imports and all profiles. Each
+/// rewritten file is backed up next to itself first. With `--dry-run`, the
+/// changes are only printed.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle config migrate --dry-run
+/// wayle config migrate
+/// ```
+pub struct MigrateCommand {}
+
+impl MigrateCommand {
+    /// Creates a new MigrateCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn describe_plan(plan: &MigrationPlan) -> String {
+        let mut lines = vec![format!(
+            "Config schema version {} -> {}",
+            plan.from_version,
+            plan.to_version()
+        )];
+
+        for (from, description) in plan.steps() {
+            lines.push(format!("  v{from} -> v{}: {description}", from + 1));
+        }
+
+        for file in &plan.files {
+            lines.push(String::new());
+            lines.push(file.path.display().to_string());
+            lines.extend(file.diff().into_iter().map(|line| format!("  {line}")));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[async_trait]
+impl Command for MigrateCommand {
+    /// Migrates configuration files, or previews the migration.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command arguments, optionally `--dry-run`
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::InvalidArgument` - If an argument other than `--dry-run` is given
+    /// * `CliError::ConfigOperationFailed` - If planning or applying the migration fails
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let dry_run = match args.first().map(String::as_str) {
+            None => false,
+            Some(DRY_RUN_FLAG) => true,
+            Some(other) => {
+                return Err(CliError::InvalidArgument {
+                    arg: other.to_string(),
+                    reason: format!("expected {DRY_RUN_FLAG}"),
+                });
+            }
+        };
+
+        let main_config = ConfigPaths::main_config();
+        let operation_failed = |e: WayleError| CliError::ConfigOperationFailed {
+            operation: "migrate".to_string(),
+            path: main_config.display().to_string(),
+            details: e.to_string(),
+        };
+
+        let plan = Config::plan_migration(&main_config).map_err(operation_failed)?;
+
+        if plan.is_empty() {
+            return Ok(format!(
+                "Configuration is already at version {}",
+                plan.to_version()
+            ));
+        }
+
+        let summary = Self::describe_plan(&plan);
+
+        if dry_run {
+            return Ok(format!("{summary}\n\nDry run - no files were changed"));
+        }
+
+        plan.apply().map_err(operation_failed)?;
+
+        let backups: Vec<String> = plan
+            .files
+            .iter()
+            .map(|file| format!("  {}", file.backup_path().display()))
+            .collect();
+
+        Ok(format!(
+            "{summary}\n\nMigrated {} file(s). Backups:\n{}",
+            plan.files.len(),
+            backups.join("\n")
+        ))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "migrate".to_string(),
+            description: "Migrate config files to the current schema version".to_string(),
+            category: "config".to_string(),
+            args: vec![CommandArg {
+                name: DRY_RUN_FLAG.to_string(),
+                description: "Show the changes without writing any files".to_string(),
+                required: false,
+                value_type: ArgType::Boolean,
+            }],
+            examples: vec![
+                "wayle config migrate --dry-run".to_string(),
+                "wayle config migrate".to_string(),
+            ],
+        }
+    }
+}
@@ -1,11 +1,19 @@
 //! Configuration management commands.
+mod explain;
 mod get;
+mod migrate;
+mod profile;
+mod schema;
 mod set;
 mod watch;
 
 use std::sync::Arc;
 
+pub use explain::ExplainCommand;
 pub use get::GetCommand;
+pub use migrate::MigrateCommand;
+pub use profile::ProfileCommand;
+pub use schema::SchemaCommand;
 pub use set::SetCommand;
 pub use watch::WatchCommand;
 
@@ -14,7 +22,9 @@ use crate::{cli::CommandRegistry, config_runtime::ConfigRuntime};
 /// Registers all configuration-related commands with the command registry.
 ///
 /// Registers commands in the "config" category for configuration management
-/// operations like getting, setting, and watching configuration values.
+/// operations like getting, setting, watching and explaining configuration values,
+/// as well as switching between configuration profiles and migrating
+/// configuration files to the current schema version or exporting its JSON Schema.
 ///
 /// # Arguments
 ///
@@ -37,4 +47,17 @@ pub fn register_commands(registry: &mut CommandRegistry, config_runtime: Arc<Con
         CATEGORY_NAME,
         Box::new(WatchCommand::new(Arc::clone(&config_runtime))),
     );
+
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(ExplainCommand::new(Arc::clone(&config_runtime))),
+    );
+
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(ProfileCommand::new(Arc::clone(&config_runtime))),
+    );
+
+    registry.register_command(CATEGORY_NAME, Box::new(MigrateCommand::new()));
+    registry.register_command(CATEGORY_NAME, Box::new(SchemaCommand::new()));
 }
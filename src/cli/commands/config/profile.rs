@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::{ConfigError, ConfigRuntime, DEFAULT_PROFILE},
+};
+use async_trait::async_trait;
+
+/// Command for listing, inspecting and switching configuration profiles.
+///
+/// Profiles are TOML overlays stored in the `profiles` directory next to the
+/// main configuration file. The active profile is persisted in the runtime
+/// state so running instances pick it up automatically.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle config profile list
+/// wayle config profile current
+/// wayle config profile use docked
+/// ```
+pub struct ProfileCommand {
+    /// Shared reference to the configuration store.
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl ProfileCommand {
+    /// Creates a new ProfileCommand with the provided config store.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Shared reference to the configuration store
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+
+    fn list(&self) -> CommandResult {
+        let profiles = self
+            .config_runtime
+            .list_profiles()
+            .map_err(|e| Self::operation_failed("list", e))?;
+
+        if profiles.is_empty() {
+            return Ok("No profiles found".to_string());
+        }
+
+        let active = self.config_runtime.active_profile();
+        let lines: Vec<String> = profiles
+            .iter()
+            .map(|name| {
+                let marker = if active.as_deref() == Some(name.as_str()) {
+                    "*"
+                } else {
+                    " "
+                };
+                format!("{marker} {name}")
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    fn current(&self) -> CommandResult {
+        let profile = self
+            .config_runtime
+            .active_profile()
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+
+        Ok(format!("Active profile: {profile}"))
+    }
+
+    async fn use_profile(&self, name: Option<&String>) -> CommandResult {
+        let name = name.ok_or_else(|| CliError::MissingArgument {
+            arg: "name".to_string(),
+            command: "config profile use".to_string(),
+        })?;
+
+        self.config_runtime
+            .switch_profile(Some(name.clone()))
+            .await
+            .map_err(|e| match e {
+                ConfigError::ProfileNotFound(_) => CliError::InvalidArgument {
+                    arg: "name".to_string(),
+                    reason: format!("Profile '{name}' not found"),
+                },
+                _ => Self::operation_failed("switch", e),
+            })?;
+
+        Ok(format!("Switched to profile '{name}'"))
+    }
+
+    fn operation_failed(operation: &str, error: ConfigError) -> CliError {
+        CliError::ConfigOperationFailed {
+            operation: format!("{operation} profile"),
+            path: "profiles".to_string(),
+            details: error.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Command for ProfileCommand {
+    /// Lists profiles, shows the active one, or switches to another.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - An action (`list`, `current`, `use`) and, for `use`, a profile name
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::InvalidArgument` - If the action is unknown or the profile doesn't exist
+    /// * `CliError::MissingArgument` - If `use` is called without a profile name
+    /// * `CliError::ConfigOperationFailed` - If the profile cannot be listed or applied
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let action = args.first().map(|s| s.as_str()).unwrap_or("current");
+
+        match action {
+            "list" => self.list(),
+            "current" => self.current(),
+            "use" => self.use_profile(args.get(1)).await,
+            _ => Err(CliError::InvalidArgument {
+                arg: "action".to_string(),
+                reason: format!("Unknown action '{action}'. Expected list, current or use"),
+            }),
+        }
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "profile".to_string(),
+            description: "List, show or switch configuration profiles".to_string(),
+            category: "config".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "action".to_string(),
+                    description: "One of: list, current, use (defaults to current)".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+                CommandArg {
+                    name: "name".to_string(),
+                    description: format!(
+                        "Profile to activate with 'use' ('{DEFAULT_PROFILE}' for the base config)"
+                    ),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+            ],
+            examples: vec![
+                "wayle config profile list".to_string(),
+                "wayle config profile current".to_string(),
+                "wayle config profile use docked".to_string(),
+                format!("wayle config profile use {DEFAULT_PROFILE}"),
+            ],
+        }
+    }
+}
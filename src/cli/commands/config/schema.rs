@@ -0,0 +1,85 @@
+use std::{fs, path::PathBuf};
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config::{Config, SCHEMA_DIRECTIVE},
+};
+
+/// Command for exporting the JSON Schema of the configuration.
+///
+/// The schema covers the whole merged configuration, including `imports`,
+/// `[[when]]` blocks and array merge directives. Editors using taplo (e.g.
+/// Even Better TOML) pick it up through a `#:schema` directive.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle config schema
+/// wayle config schema ~/.config/wayle/schema.json
+/// ```
+pub struct SchemaCommand {}
+
+impl SchemaCommand {
+    /// Creates a new SchemaCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Command for SchemaCommand {
+    /// Prints the config schema, or writes it to the given file.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional output file path
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::ConfigOperationFailed` - If the schema cannot be serialized
+    /// * `CliError::Io` - If the output file cannot be written
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let schema = serde_json::to_string_pretty(&Config::schema()).map_err(|e| {
+            CliError::ConfigOperationFailed {
+                operation: "schema".to_string(),
+                path: String::new(),
+                details: e.to_string(),
+            }
+        })?;
+
+        let Some(output) = args.first() else {
+            return Ok(schema);
+        };
+
+        let output = PathBuf::from(output);
+        fs::write(&output, schema)?;
+
+        Ok(format!(
+            "Schema written to {}\nReference it from TOML files with: {SCHEMA_DIRECTIVE}",
+            output.display()
+        ))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "schema".to_string(),
+            description: "Print the JSON Schema for config files".to_string(),
+            category: "config".to_string(),
+            args: vec![CommandArg {
+                name: "output".to_string(),
+                description: "File to write the schema to instead of printing it".to_string(),
+                required: false,
+                value_type: ArgType::Path,
+            }],
+            examples: vec![
+                "wayle config schema".to_string(),
+                "wayle config schema ~/.config/wayle/schema.json".to_string(),
+            ],
+        }
+    }
+}
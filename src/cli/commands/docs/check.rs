@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+
+use super::docs_error;
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    docs::{DocsGenerator, StaleKind},
+};
+
+/// Command for verifying that generated documentation is up to date.
+///
+/// Renders every page in memory and compares it with the files on disk.
+/// Fails with a diff when any page is missing or stale, or when the
+/// directory holds pages the generator no longer produces, which makes it
+/// suitable for CI.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle docs check
+/// wayle docs check /tmp/wayle-docs
+/// ```
+pub struct CheckCommand {}
+
+impl CheckCommand {
+    /// Creates a new CheckCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Command for CheckCommand {
+    /// Compares generated documentation with the committed files.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional docs directory (default: docs/config/modules)
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::DocsOutOfDate` - If any page is missing, differs or is orphaned
+    /// * `CliError::DocsGenerationFailed` - If a page cannot be rendered
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let mut generator = DocsGenerator::new();
+        if let Some(dir) = args.first() {
+            generator = generator.with_output_dir(dir.as_str());
+        }
+
+        let stale = generator.check().map_err(docs_error)?;
+
+        if stale.is_empty() {
+            return Ok(format!(
+                "Documentation in {} is up to date",
+                generator.output_dir().display()
+            ));
+        }
+
+        let diff: Vec<String> = stale
+            .iter()
+            .map(|doc| {
+                let status = match doc.kind {
+                    StaleKind::Outdated => "",
+                    StaleKind::Missing => " (missing)",
+                    StaleKind::Orphaned => " (no longer generated)",
+                };
+                let lines: Vec<String> = doc.diff.iter().map(|line| format!("  {line}")).collect();
+                format!("{}{status}\n{}", doc.path.display(), lines.join("\n"))
+            })
+            .collect();
+
+        Err(CliError::DocsOutOfDate {
+            count: stale.len(),
+            diff: diff.join("\n\n"),
+        })
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "check".to_string(),
+            description: "Fail if generated documentation is out of date".to_string(),
+            category: "docs".to_string(),
+            args: vec![CommandArg {
+                name: "dir".to_string(),
+                description: "Docs directory to check (default: docs/config/modules)".to_string(),
+                required: false,
+                value_type: ArgType::Path,
+            }],
+            examples: vec![
+                "wayle docs check".to_string(),
+                "wayle docs check /tmp/wayle-docs".to_string(),
+            ],
+        }
+    }
+}
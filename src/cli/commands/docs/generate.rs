@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+
+use super::docs_error;
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    docs::DocsGenerator,
+};
+
+const OUTPUT_FLAG: &str = "--output";
+
+/// Command for writing the config reference markdown pages.
+///
+/// Generates every page plus the index by default, or a single section when
+/// a name is given. Pages go to `docs/config/modules` unless `--output` points
+/// elsewhere.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle docs generate
+/// wayle docs generate clock
+/// wayle docs generate --output /tmp/wayle-docs
+/// ```
+pub struct GenerateCommand {}
+
+impl GenerateCommand {
+    /// Creates a new GenerateCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn parse_args(args: &[String]) -> Result<(Option<&str>, Option<&str>), CliError> {
+        let mut module = None;
+        let mut output = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if arg == OUTPUT_FLAG {
+                let dir = args.next().ok_or_else(|| CliError::MissingArgument {
+                    arg: "dir".to_string(),
+                    command: format!("docs generate {OUTPUT_FLAG}"),
+                })?;
+                output = Some(dir.as_str());
+            } else if module.is_none() {
+                module = Some(arg.as_str());
+            } else {
+                return Err(CliError::InvalidArgument {
+                    arg: arg.clone(),
+                    reason: format!("expected a single module name or {OUTPUT_FLAG} <dir>"),
+                });
+            }
+        }
+
+        Ok((module, output))
+    }
+}
+
+#[async_trait]
+impl Command for GenerateCommand {
+    /// Generates documentation pages on disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional module name, optionally followed by `--output <dir>`
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::InvalidArgument` - If more than one module name is given
+    /// * `CliError::MissingArgument` - If `--output` has no directory
+    /// * `CliError::DocsGenerationFailed` - If the module is unknown or a page cannot be written
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let (module, output) = Self::parse_args(args)?;
+
+        let mut generator = DocsGenerator::new();
+        if let Some(output) = output {
+            generator = generator.with_output_dir(output);
+        }
+
+        let written = match module {
+            Some(module) => vec![generator.generate_module_by_name(module).map_err(docs_error)?],
+            None => generator.generate_all().map_err(docs_error)?,
+        };
+
+        let lines: Vec<String> = written
+            .iter()
+            .map(|path| format!("Generated {}", path.display()))
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "generate".to_string(),
+            description: "Generate config reference markdown pages".to_string(),
+            category: "docs".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "module".to_string(),
+                    description: "Only generate the page for this section".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+                CommandArg {
+                    name: OUTPUT_FLAG.to_string(),
+                    description: "Output directory (default: docs/config/modules)".to_string(),
+                    required: false,
+                    value_type: ArgType::Path,
+                },
+            ],
+            examples: vec![
+                "wayle docs generate".to_string(),
+                "wayle docs generate clock".to_string(),
+                "wayle docs generate --output /tmp/wayle-docs".to_string(),
+            ],
+        }
+    }
+}
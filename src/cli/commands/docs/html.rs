@@ -0,0 +1,75 @@
+use std::{fs, path::PathBuf};
+
+use async_trait::async_trait;
+
+use super::docs_error;
+use crate::{
+    cli::{
+        Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    docs::{ModuleRegistry, generate_html_reference},
+};
+
+/// Command for rendering the config reference as a single HTML page.
+///
+/// The page inlines its styles and search script, so it works offline.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle docs html
+/// wayle docs html /tmp/wayle-config.html
+/// ```
+pub struct HtmlCommand {}
+
+impl HtmlCommand {
+    /// Creates a new HtmlCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Command for HtmlCommand {
+    /// Prints the HTML reference, or writes it to the given file.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional output file path
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::DocsGenerationFailed` - If a config schema cannot be rendered
+    /// * `CliError::Io` - If the output file cannot be written
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let page = generate_html_reference(&ModuleRegistry::get_all()).map_err(docs_error)?;
+
+        let Some(output) = args.first() else {
+            return Ok(page);
+        };
+
+        let output = PathBuf::from(output);
+        fs::write(&output, page)?;
+
+        Ok(format!("Generated {}", output.display()))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "html".to_string(),
+            description: "Generate a single-page HTML config reference".to_string(),
+            category: "docs".to_string(),
+            args: vec![CommandArg {
+                name: "output".to_string(),
+                description: "File to write the page to instead of printing it".to_string(),
+                required: false,
+                value_type: ArgType::Path,
+            }],
+            examples: vec![
+                "wayle docs html".to_string(),
+                "wayle docs html /tmp/wayle-config.html".to_string(),
+            ],
+        }
+    }
+}
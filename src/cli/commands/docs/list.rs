@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+
+use crate::{
+    cli::{Command, CommandResult, types::CommandMetadata},
+    docs::ModuleRegistry,
+};
+
+/// Command to list every documented config section.
+///
+/// Shows the section name accepted by `wayle docs generate` together with
+/// its config path.
+pub struct ListCommand {}
+
+impl ListCommand {
+    /// Creates a new ListCommand.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Command for ListCommand {
+    /// Lists documented sections with their config paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - No arguments used
+    async fn execute(&self, _args: &[String]) -> CommandResult {
+        let modules = ModuleRegistry::get_all();
+        let width = modules
+            .iter()
+            .map(|module| module.name.len())
+            .max()
+            .unwrap_or_default();
+
+        let lines: Vec<String> = modules
+            .iter()
+            .map(|module| {
+                format!(
+                    "{} {:<width$}  [{}]",
+                    module.icon, module.name, module.config_path
+                )
+            })
+            .collect();
+
+        Ok(lines.join("\n"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "list".to_string(),
+            description: "List documented config sections".to_string(),
+            category: "docs".to_string(),
+            args: vec![],
+            examples: vec!["wayle docs list".to_string()],
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fs, path::PathBuf, sync::Arc};
+
+use async_trait::async_trait;
+
+use super::docs_error;
+use crate::{
+    cli::{
+        Command, CommandRegistry, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+    docs::{ModuleRegistry, generate_cli_man_page, generate_config_man_page},
+};
+
+const DEFAULT_OUTPUT_DIR: &str = "docs/man";
+
+/// Command for rendering the `wayle(1)` and `wayle-config(5)` man pages.
+///
+/// `wayle(1)` is built from the metadata of every registered CLI command and
+/// `wayle-config(5)` from the config schemas.
+///
+/// # Example Usage
+///
+/// ```bash
+/// wayle docs man
+/// wayle docs man ~/.local/share/man
+/// ```
+pub struct ManCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl ManCommand {
+    /// Creates a new ManCommand.
+    ///
+    /// The configuration store is only used to build a command registry
+    /// whose metadata is rendered into `wayle(1)`.
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for ManCommand {
+    /// Writes `man1/wayle.1` and `man5/wayle-config.5` below the output directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional output directory (default: docs/man)
+    ///
+    /// # Errors
+    ///
+    /// * `CliError::DocsGenerationFailed` - If a config schema cannot be rendered
+    /// * `CliError::Io` - If the pages cannot be written
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let output_dir = PathBuf::from(
+            args.first()
+                .map(String::as_str)
+                .unwrap_or(DEFAULT_OUTPUT_DIR),
+        );
+
+        let mut registry = CommandRegistry::new(Arc::clone(&self.config_runtime));
+        registry.register_all_commands();
+
+        let config_page =
+            generate_config_man_page(&ModuleRegistry::get_all()).map_err(docs_error)?;
+        let cli_page = generate_cli_man_page(&registry.all_command_metadata());
+
+        let pages = [
+            (output_dir.join("man1").join("wayle.1"), cli_page),
+            (output_dir.join("man5").join("wayle-config.5"), config_page),
+        ];
+
+        let mut written = Vec::new();
+        for (path, content) in pages {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, content)?;
+            written.push(format!("Generated {}", path.display()));
+        }
+
+        Ok(written.join("\n"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "man".to_string(),
+            description: "Generate the wayle(1) and wayle-config(5) man pages".to_string(),
+            category: "docs".to_string(),
+            args: vec![CommandArg {
+                name: "dir".to_string(),
+                description: format!(
+                    "Man page root to write man1/ and man5/ into (default: {DEFAULT_OUTPUT_DIR})"
+                ),
+                required: false,
+                value_type: ArgType::Path,
+            }],
+            examples: vec![
+                "wayle docs man".to_string(),
+                "wayle docs man ~/.local/share/man".to_string(),
+            ],
+        }
+    }
+}
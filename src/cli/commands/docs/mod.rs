@@ -0,0 +1,47 @@
+//! Documentation generation commands.
+mod check;
+mod generate;
+mod html;
+mod list;
+mod man;
+
+pub use check::CheckCommand;
+pub use generate::GenerateCommand;
+pub use html::HtmlCommand;
+pub use list::ListCommand;
+pub use man::ManCommand;
+
+use std::sync::Arc;
+
+use crate::{
+    cli::{CliError, CommandRegistry},
+    config_runtime::ConfigRuntime,
+    docs::DocsError,
+};
+
+/// Registers all documentation commands with the command registry.
+///
+/// Registers commands in the "docs" category for generating the config
+/// reference pages, listing documented sections and checking that the
+/// committed pages match the current config schemas, as well as rendering
+/// man pages and a single-page HTML reference.
+///
+/// # Arguments
+///
+/// * `registry` - Mutable reference to the command registry
+/// * `config_runtime` - Configuration store, used to enumerate CLI commands for `wayle(1)`
+pub fn register_commands(registry: &mut CommandRegistry, config_runtime: Arc<ConfigRuntime>) {
+    const CATEGORY_NAME: &str = "docs";
+
+    registry.register_command(CATEGORY_NAME, Box::new(GenerateCommand::new()));
+    registry.register_command(CATEGORY_NAME, Box::new(ListCommand::new()));
+    registry.register_command(CATEGORY_NAME, Box::new(CheckCommand::new()));
+    registry.register_command(CATEGORY_NAME, Box::new(ManCommand::new(config_runtime)));
+    registry.register_command(CATEGORY_NAME, Box::new(HtmlCommand::new()));
+}
+
+fn docs_error(error: DocsError) -> CliError {
+    CliError::DocsGenerationFailed {
+        details: error.to_string(),
+    }
+}
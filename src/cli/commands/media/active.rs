@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use crate::{
@@ -5,22 +7,32 @@ use crate::{
         CliError, Command, CommandResult,
         types::{ArgType, CommandArg, CommandMetadata},
     },
+    config_runtime::ConfigRuntime,
     runtime_state::RuntimeState,
-    services::media::{Config, MediaService},
 };
 
-use super::utils::find_player_by_identifier;
+use super::utils::{find_player_by_identifier, start_media_service};
+
+/// Argument that removes the pin and returns to automatic selection
+const AUTO_ARG: &str = "auto";
 
 /// Command to get or set the active media player
 ///
 /// Without arguments, shows the current active player.
-/// With an argument, sets the specified player as active.
-pub struct ActiveCommand {}
+/// With an argument, pins the specified player as active; `auto` removes
+/// the pin so the active player follows playback again.
+pub struct ActiveCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
 
 impl ActiveCommand {
     /// Creates a new ActiveCommand
-    pub fn new() -> Self {
-        Self {}
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
     }
 }
 
@@ -36,14 +48,26 @@ impl Command for ActiveCommand {
     ///
     /// Returns CliError if media service fails or player not found
     async fn execute(&self, args: &[String]) -> CommandResult {
-        let media_service = MediaService::start(Config {
-            ignored_players: vec![],
-        })
-        .await
-        .map_err(|e| CliError::ServiceError {
-            service: "Media".to_string(),
-            details: e.to_string(),
-        })?;
+        let media_service = start_media_service(&self.config_runtime).await?;
+
+        if args.first().is_some_and(|arg| arg == AUTO_ARG) {
+            media_service
+                .set_active_player(None)
+                .await
+                .map_err(|e| CliError::ServiceError {
+                    service: "Media".to_string(),
+                    details: e.to_string(),
+                })?;
+
+            RuntimeState::set_active_player(None)
+                .await
+                .map_err(|e| CliError::ServiceError {
+                    service: "Media".to_string(),
+                    details: format!("Failed to clear active player: {e}"),
+                })?;
+
+            return Ok("Active player follows playback".to_string());
+        }
 
         if let Some(identifier) = args.first() {
             let player_id = find_player_by_identifier(&media_service, identifier)?;
@@ -72,9 +96,14 @@ impl Command for ActiveCommand {
                     details: format!("Failed to save active player: {e}"),
                 })?;
 
-            Ok(format!("Set active player to: {player_name}"))
+            Ok(format!("Pinned active player to: {player_name}"))
         } else {
+            let pinned = media_service.pinned_player().await;
             match media_service.active_player() {
+                Some(player) if pinned.as_ref() == Some(&player.id) => {
+                    let player_name = player.identity.get();
+                    Ok(format!("Active player: {player_name} (pinned)"))
+                }
                 Some(player) => {
                     let player_name = player.identity.get();
                     Ok(format!("Active player: {player_name}"))
@@ -87,11 +116,11 @@ impl Command for ActiveCommand {
     fn metadata(&self) -> CommandMetadata {
         CommandMetadata {
             name: "active".to_string(),
-            description: "Get or set the active media player".to_string(),
+            description: "Get or pin the active media player".to_string(),
             category: "media".to_string(),
             args: vec![CommandArg {
                 name: "player-id".to_string(),
-                description: "Player to set as active - can be a number (1, 2, etc.) or partial name match. If not provided, shows current active player.".to_string(),
+                description: "Player to pin as active - can be a number (1, 2, etc.) or partial name match, or 'auto' to follow playback again. If not provided, shows current active player.".to_string(),
                 required: false,
                 value_type: ArgType::String,
             }],
@@ -99,6 +128,7 @@ impl Command for ActiveCommand {
                 "wayle media active".to_string(),
                 "wayle media active 1".to_string(),
                 "wayle media active spotify".to_string(),
+                "wayle media active auto".to_string(),
             ],
         }
     }
@@ -4,25 +4,30 @@ use async_trait::async_trait;
 
 use crate::{
     cli::{
-        CliError, Command, CommandResult,
+        Command, CommandResult,
         types::{ArgType, CommandArg, CommandMetadata},
     },
-    services::media::{
-        Config, LoopMode, MediaService, PlaybackState, ShuffleMode, UNKNOWN_METADATA, core::Player,
-    },
+    config_runtime::ConfigRuntime,
+    services::media::{LoopMode, PlaybackState, ShuffleMode, UNKNOWN_METADATA, core::Player},
 };
 
-use super::utils::get_player_id_or_active;
+use super::utils::{get_player_id_or_active, start_media_service};
 
 /// Command to show detailed information about a media player
 ///
 /// Displays current track, playback state, position, and player capabilities
-pub struct InfoCommand {}
+pub struct InfoCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
 
 impl InfoCommand {
     /// Creates a new InfoCommand
-    pub fn new() -> Self {
-        Self {}
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
     }
 
     fn format_duration(duration: Duration) -> String {
@@ -45,14 +50,7 @@ impl Command for InfoCommand {
     ///
     /// Returns CliError if media service fails or player not found
     async fn execute(&self, args: &[String]) -> CommandResult {
-        let media_service = MediaService::start(Config {
-            ignored_players: vec![],
-        })
-        .await
-        .map_err(|e| CliError::ServiceError {
-            service: "Media".to_string(),
-            details: e.to_string(),
-        })?;
+        let media_service = start_media_service(&self.config_runtime).await?;
         let player = get_player_id_or_active(&media_service, args.first()).await?;
         let mut output = String::new();
 
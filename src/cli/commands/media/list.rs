@@ -1,19 +1,30 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use crate::{
-    cli::{CliError, Command, CommandResult, types::CommandMetadata},
-    services::media::{Config, MediaService, PlaybackState, UNKNOWN_METADATA},
+    cli::{Command, CommandResult, types::CommandMetadata},
+    config_runtime::ConfigRuntime,
+    services::media::{PlaybackState, UNKNOWN_METADATA},
 };
 
+use super::utils::start_media_service;
+
 /// Command to list all available media players
 ///
 /// Shows player index, name, and current playback state
-pub struct ListCommand {}
+pub struct ListCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
 
 impl ListCommand {
     /// Creates a new ListCommand
-    pub fn new() -> Self {
-        Self {}
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
     }
 }
 
@@ -29,14 +40,7 @@ impl Command for ListCommand {
     ///
     /// Returns CliError if media service initialization fails
     async fn execute(&self, _args: &[String]) -> CommandResult {
-        let media_service = MediaService::start(Config {
-            ignored_players: vec![],
-        })
-        .await
-        .map_err(|e| CliError::ServiceError {
-            service: "Media".to_string(),
-            details: e.to_string(),
-        })?;
+        let media_service = start_media_service(&self.config_runtime).await?;
         let players = media_service.players();
 
         if players.is_empty() {
@@ -4,23 +4,37 @@ mod info;
 mod list;
 mod loop_mode;
 mod next;
+mod open;
 mod play_pause;
+mod playlist;
 mod previous;
+mod queue;
+mod raise;
+mod rate;
 mod seek;
 mod shuffle;
+mod stop;
 mod utils;
 
+use std::sync::Arc;
+
 pub use active::ActiveCommand;
 pub use info::InfoCommand;
 pub use list::ListCommand;
 pub use loop_mode::LoopCommand;
 pub use next::NextCommand;
+pub use open::OpenCommand;
 pub use play_pause::PlayPauseCommand;
+pub use playlist::PlaylistCommand;
 pub use previous::PreviousCommand;
+pub use queue::QueueCommand;
+pub use raise::RaiseCommand;
+pub use rate::RateCommand;
 pub use seek::SeekCommand;
 pub use shuffle::ShuffleCommand;
+pub use stop::StopCommand;
 
-use crate::cli::CommandRegistry;
+use crate::{cli::CommandRegistry, config_runtime::ConfigRuntime};
 
 /// Registers all media-related commands with the command registry
 ///
@@ -30,16 +44,68 @@ use crate::cli::CommandRegistry;
 /// # Arguments
 ///
 /// * `registry` - Mutable reference to the command registry
-pub fn register_commands(registry: &mut CommandRegistry) {
+/// * `config_runtime` - Shared configuration store providing the media settings
+pub fn register_commands(registry: &mut CommandRegistry, config_runtime: Arc<ConfigRuntime>) {
     const CATEGORY_NAME: &str = "media";
 
-    registry.register_command(CATEGORY_NAME, Box::new(ListCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(PlayPauseCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(NextCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(PreviousCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(SeekCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(ShuffleCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(LoopCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(ActiveCommand::new()));
-    registry.register_command(CATEGORY_NAME, Box::new(InfoCommand::new()));
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(ListCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(PlayPauseCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(StopCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(NextCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(PreviousCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(SeekCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(RateCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(ShuffleCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(LoopCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(ActiveCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(OpenCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(RaiseCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(QueueCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(PlaylistCommand::new(Arc::clone(&config_runtime))),
+    );
+    registry.register_command(
+        CATEGORY_NAME,
+        Box::new(InfoCommand::new(Arc::clone(&config_runtime))),
+    );
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use crate::{
@@ -5,20 +7,26 @@ use crate::{
         CliError, Command, CommandResult,
         types::{ArgType, CommandArg, CommandMetadata},
     },
-    services::media::{Config, MediaService},
+    config_runtime::ConfigRuntime,
 };
 
-use super::utils::get_player_id_or_active;
+use super::utils::{get_player_id_or_active, start_media_service};
 
 /// Command to skip to the next track
 ///
 /// Controls the active player by default, or a specific player if provided.
-pub struct NextCommand {}
+pub struct NextCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
 
 impl NextCommand {
     /// Creates a new NextCommand
-    pub fn new() -> Self {
-        Self {}
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
     }
 }
 
@@ -34,14 +42,7 @@ impl Command for NextCommand {
     ///
     /// Returns CliError if media service fails or player not found
     async fn execute(&self, args: &[String]) -> CommandResult {
-        let media_service = MediaService::start(Config {
-            ignored_players: vec![],
-        })
-        .await
-        .map_err(|e| CliError::ServiceError {
-            service: "Media".to_string(),
-            details: e.to_string(),
-        })?;
+        let media_service = start_media_service(&self.config_runtime).await?;
         let player = get_player_id_or_active(&media_service, args.first()).await?;
         let player_name = player.identity.get();
 
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+};
+
+use super::utils::{get_player_id_or_active, start_media_service};
+
+/// Command to open a URI in a media player
+///
+/// Opens in the active player by default, or a specific player if provided.
+pub struct OpenCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl OpenCommand {
+    /// Creates a new OpenCommand
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for OpenCommand {
+    /// Open a URI and start playing it
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - URI (required) and optional player identifier
+    ///
+    /// # Errors
+    ///
+    /// Returns CliError if media service fails, player not found, or the URI is unsupported
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let Some(uri) = args.first() else {
+            return Err(CliError::MissingArgument {
+                arg: "uri".to_string(),
+                command: "open".to_string(),
+            });
+        };
+
+        let media_service = start_media_service(&self.config_runtime).await?;
+        let player = get_player_id_or_active(&media_service, args.get(1)).await?;
+        let player_name = player.identity.get();
+
+        player
+            .open_uri(uri)
+            .await
+            .map_err(|e| CliError::ServiceError {
+                service: "Media".to_string(),
+                details: e.to_string(),
+            })?;
+
+        Ok(format!("Opened {uri} on: {player_name}"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "open".to_string(),
+            description: "Open a URI and start playing it".to_string(),
+            category: "media".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "uri".to_string(),
+                    description: "URI to open, e.g. file:///home/user/song.mp3. Its scheme must be supported by the player.".to_string(),
+                    required: true,
+                    value_type: ArgType::String,
+                },
+                CommandArg {
+                    name: "player-id".to_string(),
+                    description: "Player identifier - can be a number (1, 2, etc.) or partial name match. Uses active player if not specified.".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+            ],
+            examples: vec![
+                "wayle media open file:///home/user/Music/song.flac".to_string(),
+                "wayle media open https://example.com/stream.mp3 mpv".to_string(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+    services::media::Playlist,
+};
+
+use super::utils::{get_player_id_or_active, start_media_service};
+
+/// Command to list or switch a player's playlists
+///
+/// Without arguments, lists the active player's playlists.
+/// With a playlist argument, starts playing that playlist.
+/// Only players implementing the MPRIS Playlists interface expose playlists.
+pub struct PlaylistCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl PlaylistCommand {
+    /// Creates a new PlaylistCommand
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+
+    fn find_playlist<'a>(
+        playlists: &'a [Playlist],
+        identifier: &str,
+    ) -> Result<&'a Playlist, CliError> {
+        if let Ok(index) = identifier.parse::<usize>() {
+            return index
+                .checked_sub(1)
+                .and_then(|index| playlists.get(index))
+                .ok_or_else(|| CliError::InvalidArgument {
+                    arg: "playlist".to_string(),
+                    reason: format!("Invalid playlist index. Valid range: 1-{}", playlists.len()),
+                });
+        }
+
+        let identifier_lower = identifier.to_lowercase();
+        let matches: Vec<&Playlist> = playlists
+            .iter()
+            .filter(|playlist| playlist.name.to_lowercase().contains(&identifier_lower))
+            .collect();
+
+        match matches.as_slice() {
+            [] => Err(CliError::InvalidArgument {
+                arg: "playlist".to_string(),
+                reason: format!("No playlist found matching '{identifier}'"),
+            }),
+            [playlist] => Ok(playlist),
+            _ => {
+                let names: Vec<&str> = matches.iter().map(|p| p.name.as_str()).collect();
+                Err(CliError::InvalidArgument {
+                    arg: "playlist".to_string(),
+                    reason: format!(
+                        "Multiple playlists match '{}': {}. Please be more specific.",
+                        identifier,
+                        names.join(", ")
+                    ),
+                })
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Command for PlaylistCommand {
+    /// List playlists or switch to one
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional playlist (index or partial name) and optional player identifier
+    ///
+    /// # Errors
+    ///
+    /// Returns CliError if media service fails, player or playlist not found,
+    /// or the player has no playlists
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let media_service = start_media_service(&self.config_runtime).await?;
+        let player = get_player_id_or_active(&media_service, args.get(1)).await?;
+        let player_name = player.identity.get();
+
+        let Some(playlists) = &player.playlists else {
+            return Err(CliError::ServiceError {
+                service: "Media".to_string(),
+                details: format!("{player_name} doesn't expose playlists"),
+            });
+        };
+        let available = playlists.playlists.get();
+
+        if let Some(identifier) = args.first() {
+            let playlist = Self::find_playlist(&available, identifier)?;
+
+            playlists
+                .activate(&playlist.id)
+                .await
+                .map_err(|e| CliError::ServiceError {
+                    service: "Media".to_string(),
+                    details: e.to_string(),
+                })?;
+
+            return Ok(format!(
+                "Switched to playlist {} on: {player_name}",
+                playlist.name
+            ));
+        }
+
+        if available.is_empty() {
+            return Ok(format!("No playlists on {player_name}"));
+        }
+
+        let active_id = playlists.active.get().map(|playlist| playlist.id);
+        let mut output = format!("Playlists on {player_name}:\n\n");
+
+        for (index, playlist) in available.iter().enumerate() {
+            let playlist_num = index + 1;
+            let active_marker = if active_id.as_ref() == Some(&playlist.id) {
+                " (active)"
+            } else {
+                ""
+            };
+
+            output.push_str(&format!(
+                "{playlist_num:2}. {}{active_marker}\n",
+                playlist.name
+            ));
+        }
+
+        output.push_str("\nUse playlist number or partial name to switch.");
+        Ok(output)
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "playlist".to_string(),
+            description: "List playlists or switch to one".to_string(),
+            category: "media".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "playlist".to_string(),
+                    description: "Playlist to play - can be a number (1, 2, etc.) or partial name match. If not provided, lists playlists.".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+                CommandArg {
+                    name: "player-id".to_string(),
+                    description: "Player identifier - can be a number (1, 2, etc.) or partial name match. Uses active player if not specified.".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+            ],
+            examples: vec![
+                "wayle media playlist".to_string(),
+                "wayle media playlist 2".to_string(),
+                "wayle media playlist favorites rhythmbox".to_string(),
+            ],
+        }
+    }
+}
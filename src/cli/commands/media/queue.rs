@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+    services::media::UNKNOWN_METADATA,
+};
+
+use super::utils::{get_player_id_or_active, start_media_service};
+
+/// Command to show a player's play queue
+///
+/// Shows the active player's queue by default, or a specific player's if provided.
+/// Only players implementing the MPRIS TrackList interface expose a queue.
+pub struct QueueCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl QueueCommand {
+    /// Creates a new QueueCommand
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for QueueCommand {
+    /// Show the play queue
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional player identifier (partial name match or index)
+    ///
+    /// # Errors
+    ///
+    /// Returns CliError if media service fails, player not found, or the player has no queue
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let media_service = start_media_service(&self.config_runtime).await?;
+        let player = get_player_id_or_active(&media_service, args.first()).await?;
+        let player_name = player.identity.get();
+
+        let Some(track_list) = &player.track_list else {
+            return Err(CliError::ServiceError {
+                service: "Media".to_string(),
+                details: format!("{player_name} doesn't expose a play queue"),
+            });
+        };
+
+        let tracks = track_list.tracks.get();
+        if tracks.is_empty() {
+            return Ok(format!("Queue on {player_name} is empty"));
+        }
+
+        let current_id = player.metadata.track_id.get();
+        let mut output = format!("Queue on {player_name} ({} tracks):\n\n", tracks.len());
+
+        for (index, track) in tracks.iter().enumerate() {
+            let track_num = index + 1;
+            let current_marker = if current_id.as_deref() == Some(track.id.as_str()) {
+                "▶"
+            } else {
+                " "
+            };
+
+            let title = track.metadata.title.get();
+            let artist = track.metadata.artist.get();
+            let title = if title.is_empty() {
+                UNKNOWN_METADATA.to_string()
+            } else {
+                title
+            };
+            let track_info = if artist.is_empty() || artist == UNKNOWN_METADATA {
+                title
+            } else {
+                format!("{title} by {artist}")
+            };
+
+            output.push_str(&format!("{current_marker} {track_num:3}. {track_info}\n"));
+        }
+
+        Ok(output.trim_end().to_string())
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "queue".to_string(),
+            description: "Show the play queue".to_string(),
+            category: "media".to_string(),
+            args: vec![CommandArg {
+                name: "player-id".to_string(),
+                description: "Player identifier - can be a number (1, 2, etc.) or partial name match (e.g., 'mpv', 'vlc'). Uses active player if not specified.".to_string(),
+                required: false,
+                value_type: ArgType::String,
+            }],
+            examples: vec![
+                "wayle media queue".to_string(),
+                "wayle media queue mpv".to_string(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+};
+
+use super::utils::{get_player_id_or_active, start_media_service};
+
+/// Command to bring a player window to the foreground
+///
+/// Raises the active player by default, or a specific player if provided.
+pub struct RaiseCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl RaiseCommand {
+    /// Creates a new RaiseCommand
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for RaiseCommand {
+    /// Bring the player window to the foreground
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Optional player identifier (partial name match or index)
+    ///
+    /// # Errors
+    ///
+    /// Returns CliError if media service fails or player not found
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let media_service = start_media_service(&self.config_runtime).await?;
+        let player = get_player_id_or_active(&media_service, args.first()).await?;
+        let player_name = player.identity.get();
+
+        player.raise().await.map_err(|e| CliError::ServiceError {
+            service: "Media".to_string(),
+            details: e.to_string(),
+        })?;
+
+        Ok(format!("Raised: {player_name}"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "raise".to_string(),
+            description: "Bring the player window to the foreground".to_string(),
+            category: "media".to_string(),
+            args: vec![CommandArg {
+                name: "player-id".to_string(),
+                description: "Player identifier - can be a number (1, 2, etc.) or partial name match (e.g., 'spotify', 'firefox'). Uses active player if not specified.".to_string(),
+                required: false,
+                value_type: ArgType::String,
+            }],
+            examples: vec![
+                "wayle media raise".to_string(),
+                "wayle media raise 1".to_string(),
+                "wayle media raise spotify".to_string(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    cli::{
+        CliError, Command, CommandResult,
+        types::{ArgType, CommandArg, CommandMetadata},
+    },
+    config_runtime::ConfigRuntime,
+};
+
+use super::utils::{get_player_id_or_active, start_media_service};
+
+/// Command to set the playback rate
+///
+/// Controls the active player by default, or a specific player if provided.
+pub struct RateCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
+
+impl RateCommand {
+    /// Creates a new RateCommand
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
+    }
+}
+
+#[async_trait]
+impl Command for RateCommand {
+    /// Set the playback rate
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Rate (required) and optional player identifier
+    ///
+    /// # Errors
+    ///
+    /// Returns CliError if media service fails, player not found, or the rate is invalid
+    async fn execute(&self, args: &[String]) -> CommandResult {
+        let Some(rate_str) = args.first() else {
+            return Err(CliError::MissingArgument {
+                arg: "rate".to_string(),
+                command: "rate".to_string(),
+            });
+        };
+
+        let rate = rate_str
+            .parse::<f64>()
+            .map_err(|_| CliError::InvalidArgument {
+                arg: "rate".to_string(),
+                reason: "Rate must be a number, e.g. 1.5".to_string(),
+            })?;
+
+        let media_service = start_media_service(&self.config_runtime).await?;
+        let player = get_player_id_or_active(&media_service, args.get(1)).await?;
+        let player_name = player.identity.get();
+
+        player
+            .set_rate(rate)
+            .await
+            .map_err(|e| CliError::ServiceError {
+                service: "Media".to_string(),
+                details: e.to_string(),
+            })?;
+
+        Ok(format!("Set playback rate to {rate}x on: {player_name}"))
+    }
+
+    fn metadata(&self) -> CommandMetadata {
+        CommandMetadata {
+            name: "rate".to_string(),
+            description: "Set the playback rate".to_string(),
+            category: "media".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "rate".to_string(),
+                    description: "Playback rate, where 1.0 is normal speed. Must be within the player's supported range.".to_string(),
+                    required: true,
+                    value_type: ArgType::Number,
+                },
+                CommandArg {
+                    name: "player-id".to_string(),
+                    description: "Player identifier - can be a number (1, 2, etc.) or partial name match. Uses active player if not specified.".to_string(),
+                    required: false,
+                    value_type: ArgType::String,
+                },
+            ],
+            examples: vec![
+                "wayle media rate 1.5".to_string(),
+                "wayle media rate 1".to_string(),
+                "wayle media rate 0.75 mpv".to_string(),
+            ],
+        }
+    }
+}
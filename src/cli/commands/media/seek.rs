@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 
@@ -7,20 +7,26 @@ use crate::{
         CliError, Command, CommandResult,
         types::{ArgType, CommandArg, CommandMetadata},
     },
-    services::media::{Config, MediaService},
+    config_runtime::ConfigRuntime,
 };
 
-use super::utils::get_player_id_or_active;
+use super::utils::{get_player_id_or_active, start_media_service};
 
 /// Command to seek to a specific position in the current track
 ///
 /// Supports various time formats like seconds, mm:ss, or percentage
-pub struct SeekCommand {}
+pub struct SeekCommand {
+    config_runtime: Arc<ConfigRuntime>,
+}
 
 impl SeekCommand {
     /// Creates a new SeekCommand
-    pub fn new() -> Self {
-        Self {}
+    ///
+    /// # Arguments
+    ///
+    /// * `config_runtime` - Configuration runtime providing the media settings
+    pub fn new(config_runtime: Arc<ConfigRuntime>) -> Self {
+        Self { config_runtime }
     }
 
     fn parse_position(
@@ -142,14 +148,7 @@ impl Command for SeekCommand {
         let position_str = &args[0];
         let player_arg = args.get(1);
 
-        let media_service = MediaService::start(Config {
-            ignored_players: vec![],
-        })
-        .await
-        .map_err(|e| CliError::ServiceError {
-            service: "Media".to_string(),
-            details: e.to_string(),
-        })?;
+        let media_service = start_media_service(&self.config_runtime).await?;
         let player = get_player_id_or_active(&media_service, player_arg).await?;
         let player_name = player.identity.get();
 
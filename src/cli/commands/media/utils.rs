@@ -1,10 +1,46 @@
 use std::sync::Arc;
 
+use tracing::warn;
+
 use crate::{
     cli::CliError,
-    services::media::{MediaService, Player, PlayerId},
+    config_runtime::ConfigRuntime,
+    services::media::{Config, MediaService, Player, PlayerId},
 };
 
+/// Starts the media service configured from the `media` config section
+///
+/// Applies the ignored player patterns, active player policy and album art
+/// cache settings of the currently loaded configuration. Album art is left
+/// unresolved when its cache directory cannot be determined.
+///
+/// # Arguments
+///
+/// * `config_runtime` - The configuration runtime to read media settings from
+///
+/// # Errors
+///
+/// Returns CliError if the media service fails to start
+pub async fn start_media_service(config_runtime: &ConfigRuntime) -> Result<MediaService, CliError> {
+    let media = config_runtime.get_current().media;
+    let art = media
+        .art_settings()
+        .inspect_err(|e| warn!("Album art cache unavailable: {e}"))
+        .ok();
+    let config = Config {
+        ignored_players: media.ignored_players,
+        active_player_policy: media.active_player_policy,
+        art,
+    };
+
+    MediaService::start(config)
+        .await
+        .map_err(|e| CliError::ServiceError {
+            service: "Media".to_string(),
+            details: e.to_string(),
+        })
+}
+
 /// Finds a player by identifier (index or partial name match)
 ///
 /// Supports:
@@ -1,3 +1,4 @@
 //! CLI command implementations organized by category.
 pub mod config;
+pub mod docs;
 pub mod media;
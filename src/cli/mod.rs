@@ -17,4 +17,4 @@ mod tests;
 pub use commands::config::GetCommand;
 pub use registry::CommandRegistry;
 pub use service::CliService;
-pub use types::{CliError, Command, CommandResult};
+pub use types::{ArgType, CliError, Command, CommandArg, CommandMetadata, CommandResult};
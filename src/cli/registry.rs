@@ -7,8 +7,8 @@ use crate::config_runtime::ConfigRuntime;
 
 use super::{
     CliError, Command,
-    commands::{config, media},
-    types::CommandMetadata,
+    commands::{config, docs, media},
+    types::{CommandArg, CommandMetadata},
 };
 
 /// Registry for CLI commands organized by category.
@@ -193,18 +193,34 @@ impl CommandRegistry {
             .map(|cmd| cmd.metadata())
     }
 
+    /// Retrieves metadata for every registered command.
+    ///
+    /// Commands are sorted by category and then by name, matching
+    /// [`list_commands`](Self::list_commands).
+    pub fn all_command_metadata(&self) -> Vec<CommandMetadata> {
+        self.list_commands()
+            .into_iter()
+            .flat_map(|(category, commands)| {
+                commands
+                    .into_iter()
+                    .filter_map(move |command| self.get_command_metadata(&category, &command))
+            })
+            .collect()
+    }
+
     /// Registers all available CLI commands in their respective categories.
     ///
     /// This function serves as the central registration point for all CLI commands,
     /// delegating to individual modules to register their commands.
     pub fn register_all_commands(&mut self) {
         config::register_commands(self, Arc::clone(&self.config_runtime));
-        media::register_commands(self);
+        docs::register_commands(self, Arc::clone(&self.config_runtime));
+        media::register_commands(self, Arc::clone(&self.config_runtime));
     }
 
     fn validate_args(metadata: &CommandMetadata, args: &[String]) -> Result<(), CliError> {
         let required_count = metadata.args.iter().filter(|arg| arg.required).count();
-        let total_count = metadata.args.len();
+        let total_count: usize = metadata.args.iter().map(CommandArg::token_count).sum();
 
         if args.len() < required_count {
             let missing_args: Vec<String> = metadata
@@ -212,16 +228,12 @@ impl CommandRegistry {
                 .iter()
                 .skip(args.len())
                 .filter(|arg| arg.required)
-                .map(|arg| format!("<{}>", arg.name.to_uppercase()))
+                .map(CommandArg::usage)
                 .collect();
 
             let mut usage = format!("wayle {} {}", metadata.category, metadata.name);
             for arg in &metadata.args {
-                if arg.required {
-                    usage.push_str(&format!(" <{}>", arg.name.to_uppercase()));
-                } else {
-                    usage.push_str(&format!(" [{}]", arg.name.to_uppercase()));
-                }
+                usage.push_str(&format!(" {}", arg.usage()));
             }
 
             return Err(CliError::MissingArguments {
@@ -188,15 +188,9 @@ impl CliService {
 
         for arg in &metadata.args {
             if arg.required {
-                help.push_str(&format!(
-                    " {}",
-                    format_usage(&format!("<{}>", arg.name.to_uppercase()))
-                ));
+                help.push_str(&format!(" {}", format_usage(&arg.usage())));
             } else {
-                help.push_str(&format!(
-                    " {}",
-                    format_description(&format!("[{}]", arg.name.to_uppercase()))
-                ));
+                help.push_str(&format!(" {}", format_description(&arg.usage())));
             }
         }
         help.push_str("\n\n");
@@ -204,11 +198,7 @@ impl CliService {
         if !metadata.args.is_empty() {
             help.push_str(&format!("{}\n", format_subheader("ARGUMENTS")));
             for arg in &metadata.args {
-                let arg_display = if arg.required {
-                    format!("<{}>", arg.name.to_uppercase())
-                } else {
-                    format!("[{}]", arg.name.to_uppercase())
-                };
+                let arg_display = arg.usage();
                 let required_suffix = if arg.required { "" } else { " (optional)" };
 
                 help.push_str(&format!(
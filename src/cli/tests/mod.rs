@@ -3,7 +3,10 @@
 //! Tests command registry, formatting, and CLI utilities.
 //! No external dependencies or actual command execution.
 
-use crate::cli::{CommandRegistry, formatting::format_toml_value};
+use crate::cli::{
+    ArgType, CommandArg, CommandRegistry, commands::docs::GenerateCommand,
+    formatting::format_toml_value,
+};
 use crate::config_store::ConfigStore;
 use core::f64;
 use std::sync::Arc;
@@ -118,6 +121,43 @@ async fn command_registry_categories() {
     assert!(categories.is_empty());
 }
 
+#[test]
+fn value_flag_usage_includes_its_value() {
+    let output = CommandArg {
+        name: "--output".to_string(),
+        description: String::new(),
+        required: false,
+        value_type: ArgType::Path,
+    };
+    let dry_run = CommandArg {
+        name: "--dry-run".to_string(),
+        description: String::new(),
+        required: false,
+        value_type: ArgType::Boolean,
+    };
+
+    assert_eq!(output.usage(), "[--OUTPUT <PATH>]");
+    assert_eq!(output.token_count(), 2);
+    assert_eq!(dry_run.usage(), "[--DRY-RUN]");
+    assert_eq!(dry_run.token_count(), 1);
+}
+
+#[tokio::test]
+async fn command_registry_counts_flag_values_as_arguments() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().to_string_lossy().to_string();
+    let config_store = Arc::new(ConfigStore::with_defaults());
+    let mut registry = CommandRegistry::new(config_store);
+    registry.register_command("docs", Box::new(GenerateCommand::new()));
+
+    let args = ["clock".to_string(), "--output".to_string(), output];
+    registry.execute("docs", "generate", &args).await.unwrap();
+    assert!(dir.path().join("clock.md").exists());
+
+    let extra = [args.as_slice(), &["extra".to_string()]].concat();
+    assert!(registry.execute("docs", "generate", &extra).await.is_err());
+}
+
 #[test]
 fn format_toml_value_edge_cases() {
     let value = Value::Integer(i64::MAX);
@@ -116,6 +116,24 @@ pub enum CliError {
         command: String,
     },
 
+    /// Documentation generation failed
+    #[error("failed to generate documentation: {details}")]
+    DocsGenerationFailed {
+        /// Error details from the docs generator
+        details: String,
+    },
+
+    /// Generated documentation on disk is outdated
+    #[error(
+        "{count} documentation file(s) are out of date:\n{diff}\nRun `wayle docs generate` to update them"
+    )]
+    DocsOutOfDate {
+        /// Number of stale files
+        count: usize,
+        /// Per-file diff of the expected changes
+        diff: String,
+    },
+
     /// I/O operation failed
     #[error(transparent)]
     Io(#[from] std::io::Error),
@@ -148,6 +166,32 @@ pub struct CommandArg {
     pub value_type: ArgType,
 }
 
+impl CommandArg {
+    /// Whether this is a `--flag` followed by a value, e.g. `--output <dir>`.
+    ///
+    /// Flags with a boolean type are switches and take no value.
+    pub fn takes_value(&self) -> bool {
+        self.name.starts_with("--") && !matches!(self.value_type, ArgType::Boolean)
+    }
+
+    /// Number of command-line tokens this argument occupies when given.
+    pub fn token_count(&self) -> usize {
+        if self.takes_value() { 2 } else { 1 }
+    }
+
+    /// How this argument is shown in usage lines, e.g. `<PATH>`, `[MODULE]`
+    /// or `[--OUTPUT <PATH>]`.
+    pub fn usage(&self) -> String {
+        let name = self.name.to_uppercase();
+        match (self.takes_value(), self.required) {
+            (true, true) => format!("{name} <{}>", self.value_type.placeholder()),
+            (true, false) => format!("[{name} <{}>]", self.value_type.placeholder()),
+            (false, true) => format!("<{name}>"),
+            (false, false) => format!("[{name}]"),
+        }
+    }
+}
+
 /// Type classification for command arguments.
 ///
 /// This enum helps with argument validation and provides hints
@@ -168,6 +212,18 @@ pub enum ArgType {
     Path,
 }
 
+impl ArgType {
+    /// Placeholder shown for a flag's value in usage lines.
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            ArgType::String => "VALUE",
+            ArgType::Number => "NUMBER",
+            ArgType::Boolean => "BOOL",
+            ArgType::Path => "PATH",
+        }
+    }
+}
+
 /// Complete metadata for a CLI command.
 ///
 /// This struct serves as the single source of truth for everything about
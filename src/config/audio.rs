@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    docs::{ModuleInfo, ModuleInfoProvider},
+    services::audio::{
+        DEFAULT_MAX_VOLUME, DevicePriority, PortSwitchPolicy, StreamRule, StreamRules, VolumeScale,
+        VolumeSettings,
+    },
+};
+
+/// Audio service configuration
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AudioConfig {
+    /// Scale volume levels and steps are measured on: `linear`, `cubic` (as in pavucontrol)
+    /// or `decibel`.
+    pub volume_scale: VolumeScale,
+
+    /// Highest volume that setting or stepping a volume, stream rules and restored
+    /// application volumes may reach, where 1.0 is 100%.
+    pub max_volume: f64,
+
+    /// Restore each application's last volume when its streams appear and no rule sets one.
+    pub remember_app_volume: bool,
+
+    /// Per-application rules applied when a matching stream appears, keyed by rule name.
+    /// Rules are tried in name order, e.g.
+    /// `[audio.rules.spotify]` with `application = "Spotify"`, `volume = 0.6` and
+    /// `device = "Headphones"`.
+    pub rules: BTreeMap<String, StreamRule>,
+
+    /// Output device name patterns, most preferred first, e.g. `["bluez_output.*"]`.
+    /// A newly connected device ranking above the current default becomes the default,
+    /// and the previous default returns when it disconnects.
+    pub output_priority: Vec<String>,
+
+    /// Input device name patterns, most preferred first, e.g. `["alsa_input.usb-*"]`.
+    pub input_priority: Vec<String>,
+
+    /// What a device does when one of its ports becomes available: `manual` keeps the
+    /// active port, `prefer_higher_priority` switches to the port when it outranks the
+    /// active one, e.g. when headphones are plugged in.
+    pub port_switch: PortSwitchPolicy,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            volume_scale: VolumeScale::default(),
+            max_volume: DEFAULT_MAX_VOLUME,
+            remember_app_volume: true,
+            rules: BTreeMap::new(),
+            output_priority: Vec::new(),
+            input_priority: Vec::new(),
+            port_switch: PortSwitchPolicy::default(),
+        }
+    }
+}
+
+impl AudioConfig {
+    /// Volume level and step settings for the audio service
+    pub fn volume_settings(&self) -> VolumeSettings {
+        VolumeSettings {
+            scale: self.volume_scale,
+            max: self.max_volume,
+        }
+    }
+
+    /// Stream rules for the audio service
+    pub fn stream_rules(&self) -> StreamRules {
+        StreamRules {
+            rules: self.rules.values().cloned().collect(),
+            remember_volume: self.remember_app_volume,
+        }
+    }
+
+    /// Default device priority for the audio service
+    pub fn device_priority(&self) -> DevicePriority {
+        DevicePriority {
+            output: self.output_priority.clone(),
+            input: self.input_priority.clone(),
+        }
+    }
+}
+
+impl ModuleInfoProvider for AudioConfig {
+    fn module_info() -> ModuleInfo {
+        ModuleInfo {
+            name: "audio".to_string(),
+            icon: "󰕾".to_string(),
+            description: "Controls audio volume, stream rules and default devices".to_string(),
+            config_path: "audio".to_string(),
+            behavior_configs: vec![(String::new(), || schema_for!(AudioConfig))],
+            styling_configs: vec![],
+        }
+    }
+}
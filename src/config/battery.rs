@@ -1,6 +1,8 @@
-use schemars::JsonSchema;
+use schemars::{JsonSchema, schema_for};
 use serde::{Deserialize, Serialize};
 
+use crate::docs::{ModuleInfo, ModuleInfoProvider};
+
 /// Configuration for the battery status module.
 ///
 /// Controls the display and behavior of battery information in the status bar,
@@ -27,3 +29,16 @@ impl Default for BatteryConfig {
         }
     }
 }
+
+impl ModuleInfoProvider for BatteryConfig {
+    fn module_info() -> ModuleInfo {
+        ModuleInfo {
+            name: "battery".to_string(),
+            icon: "󰁹".to_string(),
+            description: "Shows the battery level and warns when it runs low".to_string(),
+            config_path: "modules.battery".to_string(),
+            behavior_configs: vec![(String::new(), || schema_for!(BatteryConfig))],
+            styling_configs: vec![],
+        }
+    }
+}
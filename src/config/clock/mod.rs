@@ -52,6 +52,7 @@ impl ModuleInfoProvider for ClockConfig {
             name: "clock".to_string(),
             icon: "󰥔".to_string(),
             description: "Controls the clock display and calendar settings".to_string(),
+            config_path: "modules.clock".to_string(),
             behavior_configs,
             styling_configs,
         }
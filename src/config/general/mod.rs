@@ -1,6 +1,8 @@
-use schemars::JsonSchema;
+use schemars::{JsonSchema, schema_for};
 use serde::{Deserialize, Serialize};
 
+use crate::docs::{ModuleInfo, ModuleInfoProvider};
+
 /// General configuration settings for the Wayle application.
 ///
 /// Contains global settings that affect the overall behavior of the application.
@@ -10,3 +12,16 @@ pub struct GeneralConfig {
     #[serde(skip)]
     _reserved: (),
 }
+
+impl ModuleInfoProvider for GeneralConfig {
+    fn module_info() -> ModuleInfo {
+        ModuleInfo {
+            name: "general".to_string(),
+            icon: "󰒓".to_string(),
+            description: "Global settings that affect the whole application".to_string(),
+            config_path: "general".to_string(),
+            behavior_configs: vec![(String::new(), || schema_for!(GeneralConfig))],
+            styling_configs: vec![],
+        }
+    }
+}
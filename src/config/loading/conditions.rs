@@ -0,0 +1,249 @@
+use crate::{Result, WayleError};
+use std::{collections::HashMap, env, fs, path::Path};
+use toml::{Value, map::Map};
+
+/// Key holding the array of conditional blocks in a config file
+const WHEN_KEY: &str = "when";
+const HOSTNAME_KEY: &str = "hostname";
+const OUTPUT_KEY: &str = "output";
+const ENV_KEY: &str = "env";
+
+/// Sysfs directory listing DRM connectors and their connection status
+const DRM_CLASS_DIR: &str = "/sys/class/drm";
+
+/// Facts about the current machine that `[[when]]` blocks are matched against
+///
+/// A block such as
+///
+/// ```toml
+/// [[when]]
+/// hostname = "work-laptop"
+/// env.XDG_CURRENT_DESKTOP = "Hyprland"
+///
+/// [when.modules.battery]
+/// enabled = true
+/// ```
+///
+/// applies only when every condition matches. Condition values may be a
+/// single string or an array of alternatives.
+#[derive(Debug, Clone, Default)]
+pub struct ConditionContext {
+    /// Hostname of the machine
+    pub hostname: Option<String>,
+
+    /// Names of connected outputs (e.g. `eDP-1`, `HDMI-A-1`)
+    pub outputs: Vec<String>,
+
+    /// Environment variables visible to the process
+    pub env: HashMap<String, String>,
+}
+
+impl ConditionContext {
+    /// Collects hostname, connected outputs and environment of this machine
+    pub fn detect() -> Self {
+        Self {
+            hostname: Self::detect_hostname(),
+            outputs: Self::detect_outputs(),
+            env: env::vars().collect(),
+        }
+    }
+
+    fn detect_hostname() -> Option<String> {
+        ["/proc/sys/kernel/hostname", "/etc/hostname"]
+            .iter()
+            .find_map(|path| fs::read_to_string(path).ok())
+            .or_else(|| env::var("HOSTNAME").ok())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+
+    fn detect_outputs() -> Vec<String> {
+        let Ok(entries) = fs::read_dir(DRM_CLASS_DIR) else {
+            return Vec::new();
+        };
+
+        let mut outputs: Vec<String> = entries
+            .flatten()
+            .filter(|entry| {
+                fs::read_to_string(entry.path().join("status"))
+                    .is_ok_and(|status| status.trim() == "connected")
+            })
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                name.split_once('-').map(|(_, connector)| connector.to_string())
+            })
+            .collect();
+
+        outputs.sort();
+        outputs
+    }
+}
+
+/// A single requirement of a `[[when]]` block
+#[derive(Debug, Clone)]
+enum Condition {
+    Hostname(Vec<String>),
+    Output(Vec<String>),
+    Env { name: String, values: Vec<String> },
+}
+
+impl Condition {
+    fn matches(&self, context: &ConditionContext) -> bool {
+        match self {
+            Condition::Hostname(values) => context
+                .hostname
+                .as_ref()
+                .is_some_and(|hostname| values.contains(hostname)),
+            Condition::Output(values) => context
+                .outputs
+                .iter()
+                .any(|output| values.contains(output)),
+            Condition::Env { name, values } => context
+                .env
+                .get(name)
+                .is_some_and(|value| values.contains(value)),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Condition::Hostname(values) => describe_values(HOSTNAME_KEY, values),
+            Condition::Output(values) => describe_values(OUTPUT_KEY, values),
+            Condition::Env { name, values } => describe_values(&format!("env.{name}"), values),
+        }
+    }
+}
+
+fn describe_values(key: &str, values: &[String]) -> String {
+    match values {
+        [value] => format!("{key} = \"{value}\""),
+        _ => {
+            let quoted: Vec<String> = values.iter().map(|v| format!("\"{v}\"")).collect();
+            format!("{key} in [{}]", quoted.join(", "))
+        }
+    }
+}
+
+/// Configuration values that only apply when all conditions hold
+#[derive(Debug, Clone)]
+pub struct ConditionalBlock {
+    conditions: Vec<Condition>,
+    /// Configuration values contributed by the block
+    pub value: Value,
+}
+
+impl ConditionalBlock {
+    /// Whether every condition of the block holds in the given context
+    pub fn matches(&self, context: &ConditionContext) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(context))
+    }
+
+    /// Human readable summary of the block's conditions
+    pub fn describe(&self) -> String {
+        if self.conditions.is_empty() {
+            return "always".to_string();
+        }
+
+        self.conditions
+            .iter()
+            .map(Condition::describe)
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+}
+
+/// Removes `[[when]]` blocks from a parsed config file
+///
+/// Returns the unconditional part of the file and the conditional blocks in
+/// declaration order.
+pub fn split_conditional_blocks(
+    value: Value,
+    path: &Path,
+) -> Result<(Value, Vec<ConditionalBlock>)> {
+    let Value::Table(mut table) = value else {
+        return Ok((value, Vec::new()));
+    };
+
+    let Some(when) = table.remove(WHEN_KEY) else {
+        return Ok((Value::Table(table), Vec::new()));
+    };
+
+    let Value::Array(entries) = when else {
+        return Err(invalid_block(path, "`when` must be an array of tables ([[when]])"));
+    };
+
+    let blocks = entries
+        .into_iter()
+        .map(|entry| match entry {
+            Value::Table(entry) => parse_block(entry, path),
+            _ => Err(invalid_block(path, "`when` entries must be tables")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((Value::Table(table), blocks))
+}
+
+fn parse_block(mut entry: Map<String, Value>, path: &Path) -> Result<ConditionalBlock> {
+    let mut conditions = Vec::new();
+
+    if let Some(value) = entry.remove(HOSTNAME_KEY) {
+        conditions.push(Condition::Hostname(condition_values(
+            HOSTNAME_KEY,
+            value,
+            path,
+        )?));
+    }
+
+    if let Some(value) = entry.remove(OUTPUT_KEY) {
+        conditions.push(Condition::Output(condition_values(OUTPUT_KEY, value, path)?));
+    }
+
+    if let Some(value) = entry.remove(ENV_KEY) {
+        let Value::Table(vars) = value else {
+            return Err(invalid_block(
+                path,
+                "`env` must be a table of variable names to values",
+            ));
+        };
+
+        for (name, value) in vars {
+            let values = condition_values(&format!("env.{name}"), value, path)?;
+            conditions.push(Condition::Env { name, values });
+        }
+    }
+
+    Ok(ConditionalBlock {
+        conditions,
+        value: Value::Table(entry),
+    })
+}
+
+fn condition_values(key: &str, value: Value, path: &Path) -> Result<Vec<String>> {
+    match value {
+        Value::String(value) => Ok(vec![value]),
+        Value::Array(values) => values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(value) => Ok(value),
+                _ => Err(invalid_block(
+                    path,
+                    &format!("`{key}` alternatives must be strings"),
+                )),
+            })
+            .collect(),
+        _ => Err(invalid_block(
+            path,
+            &format!("`{key}` must be a string or an array of strings"),
+        )),
+    }
+}
+
+fn invalid_block(path: &Path, reason: &str) -> WayleError {
+    WayleError::InvalidConfigField {
+        field: WHEN_KEY.to_string(),
+        component: path.display().to_string(),
+        reason: reason.to_string(),
+    }
+}
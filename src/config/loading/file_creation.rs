@@ -1,16 +1,28 @@
-use crate::{Result, WayleError};
+use crate::{
+    Result, WayleError,
+    config::{CURRENT_VERSION, Config, SCHEMA_DIRECTIVE},
+};
 use std::{fs, path::Path};
 
 /// Creates a default configuration file if it doesn't exist
+///
+/// The JSON Schema is written alongside it and referenced through a
+/// `#:schema` directive for editor completion.
 pub fn create_default_config_file(path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).map_err(|e| WayleError::IoError {
             path: parent.to_path_buf(),
             details: format!("Failed to create config directory: {e}"),
         })?;
+
+        Config::write_schema(parent)?;
     }
 
-    fs::write(path, "# Wayle configuration file\n").map_err(|e| WayleError::IoError {
+    let content = format!(
+        "{SCHEMA_DIRECTIVE}\n# Wayle configuration file\nversion = {CURRENT_VERSION}\n"
+    );
+
+    fs::write(path, content).map_err(|e| WayleError::IoError {
         path: path.to_path_buf(),
         details: format!("Failed to create config file: {e}"),
     })?;
@@ -0,0 +1,206 @@
+use crate::{Result, WayleError, config::ConfigPaths};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Prefix marking an import that must resolve to at least one file
+const REQUIRED_PREFIX: &str = "@";
+
+/// Prefix marking an import that is skipped when nothing matches
+const OPTIONAL_PREFIX: &str = "?@";
+
+/// Subdirectory searched in each XDG base directory
+const APP_DIR: &str = "wayle";
+
+const DEFAULT_XDG_CONFIG_DIRS: &str = "/etc/xdg";
+const DEFAULT_XDG_DATA_DIRS: &str = "/usr/local/share:/usr/share";
+
+/// A single entry of a config file's `imports` array
+///
+/// Supported forms:
+/// - `@themes/dark` - relative to the importing file, `.toml` is implied
+/// - `@themes/*.toml` - glob, matches are imported in sorted order
+/// - `?@local` - optional, silently skipped when no file matches
+/// - `@/etc/wayle/base` and `@~/dotfiles/wayle` - absolute and home paths
+///
+/// Relative imports that match nothing next to the importing file are looked
+/// up in the XDG config and data directories (`$XDG_CONFIG_HOME/wayle`,
+/// `$XDG_CONFIG_DIRS/wayle`, `$XDG_DATA_DIRS/wayle`), in that order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSpec {
+    /// Path or glob pattern, without the import prefix
+    pub pattern: String,
+
+    /// Whether the import may match no files
+    pub optional: bool,
+}
+
+impl ImportSpec {
+    /// Parses an `imports` entry, returning `None` for non-import strings
+    pub fn parse(entry: &str) -> Option<Self> {
+        if let Some(pattern) = entry.strip_prefix(OPTIONAL_PREFIX) {
+            return Some(Self {
+                pattern: pattern.to_owned(),
+                optional: true,
+            });
+        }
+
+        entry.strip_prefix(REQUIRED_PREFIX).map(|pattern| Self {
+            pattern: pattern.to_owned(),
+            optional: false,
+        })
+    }
+
+    /// Resolves the import to canonical file paths in merge order
+    ///
+    /// # Errors
+    /// Returns an error if a required import matches no files, the glob
+    /// pattern is invalid, or a matched file cannot be resolved.
+    pub fn resolve(&self, base_path: &Path) -> Result<Vec<PathBuf>> {
+        let files = self.matching_files(base_path)?;
+
+        if files.is_empty() && !self.optional {
+            let (home, pattern) = self.pattern_path();
+            let expected = match home {
+                Some(home) => home.join(pattern),
+                None => Self::base_dir(base_path)?.join(pattern),
+            };
+            return Err(WayleError::import("no file matches this import", &expected));
+        }
+
+        Ok(files)
+    }
+
+    /// Returns the canonical paths of all files matching the import
+    ///
+    /// Unlike [`ImportSpec::resolve`], a required import matching nothing is
+    /// not an error.
+    ///
+    /// # Errors
+    /// Returns an error if the glob pattern is invalid or a matched file
+    /// cannot be resolved.
+    pub fn matching_files(&self, base_path: &Path) -> Result<Vec<PathBuf>> {
+        let (home, pattern) = self.pattern_path();
+
+        if let Some(home) = home {
+            return expand(&home, &pattern);
+        }
+
+        if pattern.is_absolute() {
+            return expand(Path::new(""), &pattern);
+        }
+
+        let mut search_dirs = vec![Self::base_dir(base_path)?.to_path_buf()];
+        for dir in xdg_search_dirs() {
+            if !search_dirs.contains(&dir) {
+                search_dirs.push(dir);
+            }
+        }
+
+        for dir in search_dirs {
+            let files = expand(&dir, &pattern)?;
+            if !files.is_empty() {
+                return Ok(files);
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    /// Splits the import into the home directory it starts from, if any,
+    /// and the path or pattern to match
+    fn pattern_path(&self) -> (Option<PathBuf>, PathBuf) {
+        let (home, mut path) = expand_home(&self.pattern);
+        if path.extension().is_none() {
+            path.set_extension("toml");
+        }
+        (home, path)
+    }
+
+    fn base_dir(base_path: &Path) -> Result<&Path> {
+        base_path.parent().ok_or_else(|| WayleError::ImportError {
+            path: base_path.to_path_buf(),
+            details: "Invalid base path - no parent directory".to_string(),
+        })
+    }
+}
+
+fn expand_home(pattern: &str) -> (Option<PathBuf>, PathBuf) {
+    let Ok(home) = env::var("HOME") else {
+        return (None, PathBuf::from(pattern));
+    };
+
+    if pattern == "~" {
+        return (None, PathBuf::from(home));
+    }
+
+    match pattern.strip_prefix("~/") {
+        Some(rest) => (Some(PathBuf::from(home)), PathBuf::from(rest)),
+        None => (None, PathBuf::from(pattern)),
+    }
+}
+
+fn is_glob(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Expands `pattern` below `dir`
+///
+/// Only `pattern` is treated as a glob; `dir` is matched literally even if
+/// it contains glob characters.
+fn expand(dir: &Path, pattern: &Path) -> Result<Vec<PathBuf>> {
+    let path = dir.join(pattern);
+
+    if !is_glob(pattern) {
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+        return Ok(vec![canonicalize(&path)?]);
+    }
+
+    let escaped_dir = PathBuf::from(glob::Pattern::escape(&dir.to_string_lossy()));
+    let glob_pattern = escaped_dir.join(pattern);
+    let entries =
+        glob::glob(&glob_pattern.to_string_lossy()).map_err(|e| WayleError::import(e, &path))?;
+
+    let mut files = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.is_file())
+        .map(|entry| canonicalize(&entry))
+        .collect::<Result<Vec<_>>>()?;
+
+    files.sort();
+    Ok(files)
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf> {
+    path.canonicalize().map_err(|e| WayleError::import(e, path))
+}
+
+fn xdg_search_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Ok(config_dir) = ConfigPaths::config_dir() {
+        dirs.push(config_dir);
+    }
+
+    for (var, default) in [
+        ("XDG_CONFIG_DIRS", DEFAULT_XDG_CONFIG_DIRS),
+        ("XDG_DATA_DIRS", DEFAULT_XDG_DATA_DIRS),
+    ] {
+        let value = env::var(var)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| default.to_string());
+
+        dirs.extend(
+            value
+                .split(':')
+                .filter(|dir| !dir.is_empty())
+                .map(|dir| PathBuf::from(dir).join(APP_DIR)),
+        );
+    }
+
+    dirs
+}
@@ -1,14 +1,24 @@
 use toml::{Value, map::Map};
 
-/// Merges multiple TOML configs with the main configuration taking precedence
-pub fn merge_toml_configs(imports: Vec<Value>, main: Value) -> Value {
-    let mut accumulated = Value::Table(Map::new());
+/// Directive key adding items after the inherited array
+const APPEND: &str = "append";
+/// Directive key adding items before the inherited array
+const PREPEND: &str = "prepend";
+/// Directive key dropping items from the inherited array
+const REMOVE: &str = "remove";
 
-    for import in imports {
-        accumulated = merge_two_toml_configs(accumulated, import);
-    }
+const DIRECTIVE_KEYS: [&str; 3] = [APPEND, PREPEND, REMOVE];
+
+/// Merges TOML layers in order, with later layers taking precedence
+///
+/// Array merge directives left over after merging (because no earlier layer
+/// defined the array) are applied to an empty array.
+pub fn merge_toml_configs(layers: impl IntoIterator<Item = Value>) -> Value {
+    let merged = layers
+        .into_iter()
+        .fold(Value::Table(Map::new()), merge_two_toml_configs);
 
-    merge_two_toml_configs(accumulated, main)
+    resolve_array_directives(merged)
 }
 
 /// Deep merges two TOML configs while preserving precedence
@@ -17,7 +27,29 @@ pub fn merge_toml_configs(imports: Vec<Value>, main: Value) -> Value {
 /// keys from base. This ensures overlay values always win, but we don't lose
 /// base values that aren't being overridden. For non-table values, overlay
 /// completely replaces base (no attempt to merge primitives).
+///
+/// Arrays can instead be modified by an overlay table made only of merge
+/// directives, e.g. `ignored_players = { append = ["firefox"] }`:
+/// - `remove` drops matching items from the inherited array
+/// - `prepend` inserts items before the remaining inherited items
+/// - `append` inserts items after them
 pub fn merge_two_toml_configs(base: Value, overlay: Value) -> Value {
+    let base = if is_array_directive(&base) {
+        resolve_array_directives(base)
+    } else {
+        base
+    };
+
+    if let Value::Table(directive) = &overlay
+        && is_directive_table(directive)
+    {
+        let inherited = match base {
+            Value::Array(items) => items,
+            _ => Vec::new(),
+        };
+        return Value::Array(apply_array_directive(inherited, directive));
+    }
+
     match (base, overlay) {
         (Value::Table(base_table), Value::Table(overlay_table)) => {
             let mut merged_table = overlay_table;
@@ -39,3 +71,50 @@ pub fn merge_two_toml_configs(base: Value, overlay: Value) -> Value {
         (_, overlay) => overlay,
     }
 }
+
+/// Whether a value is an array merge directive rather than a plain table
+///
+/// A directive is a non-empty table whose keys are all `append`, `prepend`
+/// or `remove`, each holding an array.
+pub fn is_array_directive(value: &Value) -> bool {
+    value.as_table().is_some_and(is_directive_table)
+}
+
+fn is_directive_table(table: &Map<String, Value>) -> bool {
+    !table.is_empty()
+        && table
+            .iter()
+            .all(|(key, value)| DIRECTIVE_KEYS.contains(&key.as_str()) && value.is_array())
+}
+
+/// Replaces every remaining merge directive with the array it produces
+pub fn resolve_array_directives(value: Value) -> Value {
+    match value {
+        Value::Table(table) if is_directive_table(&table) => {
+            Value::Array(apply_array_directive(Vec::new(), &table))
+        }
+        Value::Table(table) => Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, resolve_array_directives(value)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn apply_array_directive(inherited: Vec<Value>, directive: &Map<String, Value>) -> Vec<Value> {
+    let items = |key: &str| {
+        directive
+            .get(key)
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    let removed = items(REMOVE);
+    let mut result = items(PREPEND);
+    result.extend(inherited.into_iter().filter(|item| !removed.contains(item)));
+    result.extend(items(APPEND));
+    result
+}
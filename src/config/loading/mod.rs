@@ -1,10 +1,17 @@
 mod circular_detection;
+mod conditions;
 mod file_creation;
+mod imports;
 mod merging;
+mod provenance;
 
-use super::Config;
+use super::{
+    Config,
+    migration::{self, INITIAL_VERSION},
+};
 use crate::{Result, WayleError};
 use circular_detection::CircularDetector;
+use conditions::split_conditional_blocks;
 use file_creation::create_default_config_file;
 use merging::merge_toml_configs;
 use std::{
@@ -14,14 +21,27 @@ use std::{
 };
 use toml::Value;
 
+pub use conditions::ConditionContext;
+pub use imports::ImportSpec;
+pub use provenance::{ConfigLayer, ConfigSource, ValueOrigin};
+
+/// Directory (relative to the main config file) holding profile overlays
+pub(super) const PROFILES_DIR: &str = "profiles";
+
 impl Config {
     /// Loads a configuration file with support for importing other TOML files
     ///
-    /// Import paths are specified using the `@` prefix in the TOML file.
-    /// Imported configurations are merged with the main configuration,
+    /// Import paths are specified using the `@` prefix in the TOML file, or
+    /// `?@` for imports that may be absent. Globs, absolute, `~` and
+    /// XDG-relative paths are supported (see [`ImportSpec`]).
+    /// Imported configurations are merged in order with the main configuration,
     /// with the main configuration taking precedence in case of conflicts.
     /// Also checks for circular imports.
     ///
+    /// Each file may contain `[[when]]` blocks that only apply on matching
+    /// machines (see [`ConditionContext`]). Matching blocks override the
+    /// rest of the file they are declared in.
+    ///
     /// # Arguments
     ///
     /// * `path` - Path to the main configuration file
@@ -32,6 +52,7 @@ impl Config {
     /// - The configuration file cannot be read
     /// - The TOML content is invalid
     /// - Any imported files cannot be loaded
+    /// - A `[[when]]` block is malformed
     /// - The merged configuration is invalid
     /// - Circular imports are detected
     ///
@@ -47,6 +68,66 @@ impl Config {
     /// # }
     /// ```
     pub fn load_with_imports(path: &Path) -> Result<Config> {
+        Self::load_with_profile(path, None)
+    }
+
+    /// Loads a configuration file and layers a named profile on top of it
+    ///
+    /// The profile is read from `profiles/<name>.toml` next to the main
+    /// configuration file. Profiles support imports just like the main file,
+    /// and their values take precedence over everything in the main config.
+    /// Passing `None` is equivalent to [`Config::load_with_imports`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the main configuration file
+    /// * `profile` - Name of the profile to apply, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the main configuration cannot be loaded, if the
+    /// profile file does not exist or is invalid, or if the merged result
+    /// fails validation.
+    pub fn load_with_profile(path: &Path, profile: Option<&str>) -> Result<Config> {
+        let layers = Self::load_layers(path, profile, &ConditionContext::detect())?;
+        let defaults = Self::default_value()?;
+        let merged = merge_toml_configs(
+            std::iter::once(defaults).chain(layers.into_iter().map(|layer| layer.value)),
+        );
+
+        Self::value_into_config(merged)
+    }
+
+    /// Reports every source that sets the value at a dotted config path
+    ///
+    /// Origins are ordered from highest to lowest precedence, so the first
+    /// entry is the one that determines the effective value. An empty result
+    /// means the value comes from the built-in defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be loaded.
+    pub fn explain(path: &Path, profile: Option<&str>, key: &str) -> Result<Vec<ValueOrigin>> {
+        let layers = Self::load_layers(path, profile, &ConditionContext::detect())?;
+        Ok(provenance::trace_path(&layers, key))
+    }
+
+    /// Loads every configuration source as a separate layer
+    ///
+    /// Layers are returned in ascending precedence: imports before the file
+    /// importing them, a file before its matching `[[when]]` blocks, and the
+    /// profile (if any) last. Merging the layers in order yields the final
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any file cannot be read or parsed, a circular
+    /// import is detected, or the profile cannot be found.
+    pub fn load_layers(
+        path: &Path,
+        profile: Option<&str>,
+        context: &ConditionContext,
+    ) -> Result<Vec<ConfigLayer>> {
         if !path.exists() {
             create_default_config_file(path)?;
         }
@@ -57,7 +138,56 @@ impl Config {
         })?;
 
         let mut detector = CircularDetector::new();
-        Self::load_config_with_tracking(&canonical_path, &mut detector)
+        let mut layers = Vec::new();
+        let version = Self::collect_layers(
+            &canonical_path,
+            INITIAL_VERSION,
+            context,
+            &mut detector,
+            &mut layers,
+        )?;
+
+        if let Some(profile) = profile {
+            let profile_path = Self::resolve_profile_path(&canonical_path, profile)?;
+            let canonical_profile = profile_path.canonicalize().map_err(|e| {
+                WayleError::import(format!("profile '{profile}': {e}"), &profile_path)
+            })?;
+
+            Self::collect_layers(
+                &canonical_profile,
+                version,
+                context,
+                &mut detector,
+                &mut layers,
+            )?;
+        }
+
+        Ok(layers)
+    }
+
+    /// Returns the path of a named profile relative to the main config file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile name is empty or contains path
+    /// separators, or if the main config path has no parent directory.
+    pub fn resolve_profile_path(main_path: &Path, profile: &str) -> Result<PathBuf> {
+        if profile.is_empty() || profile.contains(['/', '\\']) || profile.starts_with('.') {
+            return Err(WayleError::InvalidConfigField {
+                field: "profile".to_string(),
+                component: "profiles".to_string(),
+                reason: format!("'{profile}' is not a valid profile name"),
+            });
+        }
+
+        let parent_dir = main_path.parent().ok_or_else(|| WayleError::ImportError {
+            path: main_path.to_path_buf(),
+            details: "Invalid base path - no parent directory".to_string(),
+        })?;
+
+        Ok(parent_dir
+            .join(PROFILES_DIR)
+            .join(format!("{profile}.toml")))
     }
 
     /// Recursively collects all configuration files involved in imports.
@@ -82,107 +212,115 @@ impl Config {
         Ok(files)
     }
 
-    fn load_config_with_tracking(path: &Path, detector: &mut CircularDetector) -> Result<Config> {
+    /// Collects the layers of a file and its imports, returning its schema version
+    ///
+    /// Files without a `version` key inherit `inherited_version` from the
+    /// file importing them, and are migrated in memory before merging.
+    fn collect_layers(
+        path: &Path,
+        inherited_version: u32,
+        context: &ConditionContext,
+        detector: &mut CircularDetector,
+        layers: &mut Vec<ConfigLayer>,
+    ) -> Result<u32> {
         detector.detect_circular_import(path)?;
         detector.push_to_chain(path);
 
-        let result = Self::load_main_config(path, detector);
+        let result = Self::collect_file_layers(path, inherited_version, context, detector, layers);
         detector.pop_from_chain();
         result
     }
 
-    fn load_main_config(path: &Path, detector: &mut CircularDetector) -> Result<Config> {
-        let main_config_content = fs::read_to_string(path)?;
-        let import_paths = Self::extract_import_paths(&main_config_content)?;
-        let imported_configs = Self::load_all_imports(path, &import_paths, detector)?;
-
-        let main_config: Value = toml::from_str(&main_config_content)
-            .map_err(|e| WayleError::toml_parse(e, Some(path)))?;
-
-        let merged_config = merge_toml_configs(imported_configs, main_config);
-        merged_config
-            .try_into()
-            .map_err(|e| WayleError::ConfigValidation {
-                component: "config parsing".to_string(),
-                details: format!("Configuration validation failed: {e}"),
-            })
-    }
-
-    fn load_all_imports(
-        base_path: &Path,
-        import_paths: &[String],
-        detector: &mut CircularDetector,
-    ) -> Result<Vec<Value>> {
-        import_paths
-            .iter()
-            .map(|import_path| {
-                let resolved_path = Self::resolve_import_path(base_path, import_path)?;
-                let canonical_import = resolved_path
-                    .canonicalize()
-                    .map_err(|e| WayleError::import(e, &resolved_path))?;
-
-                Self::load_imported_file_with_tracking(&canonical_import, detector)
-            })
-            .collect()
-    }
-
-    fn load_imported_file_with_tracking(
+    fn collect_file_layers(
         path: &Path,
+        inherited_version: u32,
+        context: &ConditionContext,
         detector: &mut CircularDetector,
-    ) -> Result<Value> {
-        detector.detect_circular_import(path)?;
-        detector.push_to_chain(path);
+        layers: &mut Vec<ConfigLayer>,
+    ) -> Result<u32> {
+        let content = fs::read_to_string(path).map_err(|e| WayleError::import(e, path))?;
+        let value: Value =
+            toml::from_str(&content).map_err(|e| WayleError::toml_parse(e, Some(path)))?;
 
-        let result = Self::load_toml_file_with_imports(path, detector);
-        detector.pop_from_chain();
-        result
-    }
+        let version = migration::declared_version(&value, path)?.unwrap_or(inherited_version);
+        let value = migration::migrate(value, version, path)?;
 
-    fn load_toml_file_with_imports(path: &Path, detector: &mut CircularDetector) -> Result<Value> {
-        let content = fs::read_to_string(path).map_err(|e| WayleError::import(e, path))?;
-        let import_paths = Self::extract_import_paths(&content)?;
-        let imported_configs = Self::load_all_imports(path, &import_paths, detector)?;
+        for import in Self::imports_from_value(&value) {
+            for import_path in import.resolve(path)? {
+                Self::collect_layers(&import_path, version, context, detector, layers)?;
+            }
+        }
 
-        let main_value: Value =
-            toml::from_str(&content).map_err(|e| WayleError::toml_parse(e, Some(path)))?;
+        let (base, blocks) = split_conditional_blocks(value, path)?;
+        layers.push(ConfigLayer {
+            source: ConfigSource::file(path),
+            value: base,
+        });
 
-        Ok(merge_toml_configs(imported_configs, main_value))
+        for block in blocks {
+            if block.matches(context) {
+                layers.push(ConfigLayer {
+                    source: ConfigSource::conditional(path, block.describe()),
+                    value: block.value,
+                });
+            }
+        }
+
+        Ok(version)
     }
 
-    fn extract_import_paths(config_content: &str) -> Result<Vec<String>> {
-        let value = toml::from_str(config_content).map_err(|e| WayleError::toml_parse(e, None))?;
+    /// Canonical paths of every profile next to a main config, sorted by name
+    pub(super) fn profile_files(main_path: &Path) -> Vec<PathBuf> {
+        let Some(profiles_dir) = main_path.parent().map(|dir| dir.join(PROFILES_DIR)) else {
+            return Vec::new();
+        };
 
-        let import_paths = if let Value::Table(table) = value {
-            if let Some(Value::Array(imports)) = table.get("imports") {
-                imports
-                    .iter()
-                    .filter_map(|v| v.as_str())
-                    .filter(|s| s.starts_with('@'))
-                    .map(|s| s.strip_prefix('@').unwrap_or(s).to_owned())
-                    .collect::<Vec<String>>()
-            } else {
-                Vec::new()
-            }
-        } else {
-            Vec::new()
+        let Ok(entries) = fs::read_dir(profiles_dir) else {
+            return Vec::new();
         };
 
-        Ok(import_paths)
+        let mut profiles: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+
+        profiles.sort();
+        profiles
     }
 
-    fn resolve_import_path(base_path: &Path, import_path: &str) -> Result<PathBuf> {
-        let parent_dir = base_path.parent().ok_or_else(|| WayleError::ImportError {
-            path: base_path.to_path_buf(),
-            details: "Invalid base path - no parent directory".to_string(),
-        })?;
+    /// Built-in defaults, used as the base that array merge directives apply to
+    fn default_value() -> Result<Value> {
+        Value::try_from(Config::default()).map_err(|e| WayleError::ConfigValidation {
+            component: "config defaults".to_string(),
+            details: format!("Failed to serialize default configuration: {e}"),
+        })
+    }
 
-        let mut import_path_buf = PathBuf::from(import_path);
-        if import_path_buf.extension().is_none() {
-            import_path_buf.set_extension("toml");
-        }
+    fn value_into_config(value: Value) -> Result<Config> {
+        value.try_into().map_err(|e| WayleError::ConfigValidation {
+            component: "config parsing".to_string(),
+            details: format!("Configuration validation failed: {e}"),
+        })
+    }
+
+    fn extract_imports(config_content: &str) -> Result<Vec<ImportSpec>> {
+        let value = toml::from_str(config_content).map_err(|e| WayleError::toml_parse(e, None))?;
+
+        Ok(Self::imports_from_value(&value))
+    }
+
+    pub(super) fn imports_from_value(value: &Value) -> Vec<ImportSpec> {
+        let Some(Value::Array(imports)) = value.get("imports") else {
+            return Vec::new();
+        };
 
-        let resolved_path = parent_dir.join(import_path_buf);
-        Ok(resolved_path)
+        imports
+            .iter()
+            .filter_map(|v| v.as_str())
+            .filter_map(ImportSpec::parse)
+            .collect()
     }
 
     fn collect_config_files(
@@ -201,11 +339,12 @@ impl Config {
 
         if path.exists() {
             let content = fs::read_to_string(path)?;
-            let import_paths = Self::extract_import_paths(&content)?;
+            let imports = Self::extract_imports(&content)?;
 
-            for import_path in import_paths {
-                let resolved = Self::resolve_import_path(path, &import_path)?;
-                Self::collect_config_files(&resolved, files, visited)?;
+            for import in imports {
+                for resolved in import.matching_files(path)? {
+                    Self::collect_config_files(&resolved, files, visited)?;
+                }
             }
         }
 
@@ -0,0 +1,93 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+use toml::Value;
+
+use super::merging::is_array_directive;
+
+/// Where a layer of configuration values was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSource {
+    /// File the values were declared in
+    pub path: PathBuf,
+
+    /// Conditions of the `[[when]]` block the values came from, if any
+    pub condition: Option<String>,
+}
+
+impl ConfigSource {
+    /// Source for the unconditional part of a file
+    pub fn file(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            condition: None,
+        }
+    }
+
+    /// Source for a matching `[[when]]` block within a file
+    pub fn conditional(path: &Path, condition: String) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            condition: Some(condition),
+        }
+    }
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.condition {
+            Some(condition) => write!(f, "{} [when {condition}]", self.path.display()),
+            None => write!(f, "{}", self.path.display()),
+        }
+    }
+}
+
+/// Raw values contributed by a single configuration source
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    /// Where the values were read from
+    pub source: ConfigSource,
+
+    /// Values as declared in the source, before merging
+    pub value: Value,
+}
+
+/// A source that sets a particular configuration path
+#[derive(Debug, Clone)]
+pub struct ValueOrigin {
+    /// Where the value was declared
+    pub source: ConfigSource,
+
+    /// Value declared by the source
+    pub value: Value,
+}
+
+impl ValueOrigin {
+    /// Whether the source modifies an inherited array instead of replacing it
+    ///
+    /// Such origins (e.g. `{ append = ["firefox"] }`) only contribute part of
+    /// the effective value, so lower precedence sources still apply.
+    pub fn is_merge_directive(&self) -> bool {
+        is_array_directive(&self.value)
+    }
+}
+
+/// Finds every layer declaring `path`, highest precedence first
+pub fn trace_path(layers: &[ConfigLayer], path: &str) -> Vec<ValueOrigin> {
+    layers
+        .iter()
+        .rev()
+        .filter_map(|layer| {
+            lookup(&layer.value, path).map(|value| ValueOrigin {
+                source: layer.source.clone(),
+                value: value.clone(),
+            })
+        })
+        .collect()
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| current.as_table()?.get(segment))
+}
@@ -1,6 +1,17 @@
-use schemars::JsonSchema;
+use std::io::Error;
+
+use schemars::{JsonSchema, schema_for};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    config::ConfigPaths,
+    docs::{ModuleInfo, ModuleInfoProvider},
+    services::media::{
+        ART_CACHE_DIR_NAME, ActivePlayerPolicy, ArtSettings, DEFAULT_ART_CACHE_ENTRIES,
+        DEFAULT_ART_SIZE,
+    },
+};
+
 /// Media service configuration
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
@@ -8,6 +19,19 @@ pub struct MediaConfig {
     /// List of player bus name patterns to ignore during discovery
     pub ignored_players: Vec<String>,
 
+    /// How the active player is chosen when none is pinned with `media active`:
+    /// `recent` follows playback, `manual` only switches when the player exits.
+    pub active_player_policy: ActivePlayerPolicy,
+
+    /// Number of album art images kept in the on-disk cache; at least one is always kept.
+    pub art_cache_size: usize,
+
+    /// Longest side, in pixels, of cached album art; larger art is scaled down.
+    pub art_size: u32,
+
+    /// Whether album art is downloaded from http(s) URLs.
+    pub art_fetch_http: bool,
+
     /// Whether the media module is displayed in the status bar.
     pub enabled: bool,
 }
@@ -16,7 +40,39 @@ impl Default for MediaConfig {
     fn default() -> Self {
         Self {
             ignored_players: Vec::new(),
+            active_player_policy: ActivePlayerPolicy::default(),
+            art_cache_size: DEFAULT_ART_CACHE_ENTRIES,
+            art_size: DEFAULT_ART_SIZE,
+            art_fetch_http: true,
             enabled: true,
         }
     }
 }
+
+impl MediaConfig {
+    /// Album art cache settings, caching under the app data directory
+    ///
+    /// # Errors
+    /// Returns error if the app data directory cannot be determined or created
+    pub fn art_settings(&self) -> Result<ArtSettings, Error> {
+        Ok(ArtSettings {
+            cache_dir: ConfigPaths::app_data_dir()?.join(ART_CACHE_DIR_NAME),
+            max_entries: self.art_cache_size,
+            thumbnail_size: self.art_size,
+            fetch_http: self.art_fetch_http,
+        })
+    }
+}
+
+impl ModuleInfoProvider for MediaConfig {
+    fn module_info() -> ModuleInfo {
+        ModuleInfo {
+            name: "media".to_string(),
+            icon: "󰝚".to_string(),
+            description: "Controls media player discovery and the media module".to_string(),
+            config_path: "media".to_string(),
+            behavior_configs: vec![(String::new(), || schema_for!(MediaConfig))],
+            styling_configs: vec![],
+        }
+    }
+}
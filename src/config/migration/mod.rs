@@ -0,0 +1,121 @@
+mod plan;
+mod steps;
+
+use crate::{Result, WayleError};
+use std::path::Path;
+use toml::Value;
+
+pub use plan::{FileMigration, MigrationPlan};
+
+/// Schema version written to new configuration files
+pub const CURRENT_VERSION: u32 = 2;
+
+/// Version assumed for files that predate the `version` field
+pub const INITIAL_VERSION: u32 = 1;
+
+/// Top-level key holding a file's schema version
+const VERSION_KEY: &str = "version";
+
+/// Key holding conditional blocks, whose contents are migrated too
+const WHEN_KEY: &str = "when";
+
+/// Rewrites a config tree from one schema version to the next, failing
+/// with a description when the tree cannot be migrated without losing data
+type MigrationFn = fn(&mut toml::Table) -> std::result::Result<(), String>;
+
+/// A single step in the migration registry
+struct Migration {
+    /// Version the step migrates from; it produces `from + 1`
+    from: u32,
+    /// Summary shown in `wayle config migrate` output
+    description: &'static str,
+    apply: MigrationFn,
+}
+
+/// All migrations, ordered by the version they migrate from
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    description: "Move clock settings into general, button and dropdown sections",
+    apply: steps::split_clock_sections,
+}];
+
+/// Reads the schema version declared by a config file, if any
+///
+/// # Errors
+/// Returns an error if `version` is not a non-negative integer.
+pub fn declared_version(value: &Value, path: &Path) -> Result<Option<u32>> {
+    let Some(version) = value.get(VERSION_KEY) else {
+        return Ok(None);
+    };
+
+    version
+        .as_integer()
+        .and_then(|version| u32::try_from(version).ok())
+        .map(Some)
+        .ok_or_else(|| WayleError::InvalidConfigField {
+            field: VERSION_KEY.to_string(),
+            component: path.display().to_string(),
+            reason: format!("expected a schema version number, found {version}"),
+        })
+}
+
+/// Descriptions of the steps needed to bring `from` up to date
+pub fn pending_steps(from: u32) -> Vec<(u32, &'static str)> {
+    MIGRATIONS
+        .iter()
+        .filter(|migration| migration.from >= from)
+        .map(|migration| (migration.from, migration.description))
+        .collect()
+}
+
+/// Applies every migration from `from` to [`CURRENT_VERSION`]
+///
+/// Migrations rewrite both the top level of the file and the contents of
+/// its `[[when]]` blocks. The `version` key itself is left untouched.
+///
+/// # Errors
+/// Returns an error if the file was written by a newer version of Wayle, or
+/// a migration step cannot rewrite it without dropping settings.
+pub fn migrate(mut value: Value, from: u32, path: &Path) -> Result<Value> {
+    if from > CURRENT_VERSION {
+        return Err(WayleError::ConfigValidation {
+            component: "config migration".to_string(),
+            details: format!(
+                "{} uses schema version {from}, but only versions up to {CURRENT_VERSION} \
+                 are supported",
+                path.display()
+            ),
+        });
+    }
+
+    let Value::Table(table) = &mut value else {
+        return Ok(value);
+    };
+
+    let failed = |details: String| WayleError::ConfigValidation {
+        component: "config migration".to_string(),
+        details: format!("{}: {details}", path.display()),
+    };
+
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= from) {
+        (migration.apply)(table).map_err(failed)?;
+
+        if let Some(Value::Array(blocks)) = table.get_mut(WHEN_KEY) {
+            for block in blocks.iter_mut().filter_map(Value::as_table_mut) {
+                (migration.apply)(block).map_err(failed)?;
+            }
+        }
+    }
+
+    Ok(value)
+}
+
+/// Sets the `version` key of a config file to [`CURRENT_VERSION`]
+pub fn stamp_current_version(value: &mut Value) {
+    if let Value::Table(table) = value {
+        table.insert(
+            VERSION_KEY.to_string(),
+            Value::Integer(i64::from(CURRENT_VERSION)),
+        );
+    }
+}
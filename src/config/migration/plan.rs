@@ -0,0 +1,361 @@
+use super::{
+    CURRENT_VERSION, INITIAL_VERSION, declared_version, migrate, pending_steps,
+    stamp_current_version,
+};
+use crate::{Result, WayleError, config::Config};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+use toml::Value;
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, TableLike};
+
+/// Changes a migration makes to a single config file
+#[derive(Debug, Clone)]
+pub struct FileMigration {
+    /// File being migrated
+    pub path: PathBuf,
+
+    /// Schema version the file is migrated from
+    pub from_version: u32,
+
+    /// File contents before migration
+    pub original: Value,
+
+    /// File contents after migration
+    pub migrated: Value,
+}
+
+impl FileMigration {
+    /// Where the original file is copied before it is rewritten
+    ///
+    /// The backup sits next to the file, e.g. `config.toml.v1.bak`.
+    pub fn backup_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".v{}.bak", self.from_version));
+        self.path.with_file_name(name)
+    }
+
+    /// Lists changed values as `- path = old` / `+ path = new` lines
+    pub fn diff(&self) -> Vec<String> {
+        let mut before = BTreeMap::new();
+        let mut after = BTreeMap::new();
+        flatten(&self.original, "", &mut before);
+        flatten(&self.migrated, "", &mut after);
+
+        let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+        let mut lines = Vec::new();
+
+        for key in keys {
+            match (before.get(key), after.get(key)) {
+                (Some(old), Some(new)) if old == new => {}
+                (old, new) => {
+                    if let Some(old) = old {
+                        lines.push(format!("- {key} = {old}"));
+                    }
+                    if let Some(new) = new {
+                        lines.push(format!("+ {key} = {new}"));
+                    }
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+/// Pending migrations for a configuration tree
+#[derive(Debug, Clone)]
+pub struct MigrationPlan {
+    /// Schema version of the main config file
+    pub from_version: u32,
+
+    /// Files whose contents change, in discovery order
+    pub files: Vec<FileMigration>,
+}
+
+impl MigrationPlan {
+    /// Whether every file is already up to date
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Migration steps between the main file's version and the current one
+    pub fn steps(&self) -> Vec<(u32, &'static str)> {
+        pending_steps(self.from_version)
+    }
+
+    /// Version the files are migrated to
+    pub fn to_version(&self) -> u32 {
+        CURRENT_VERSION
+    }
+
+    /// Backs up every affected file, then writes the migrated contents
+    ///
+    /// Only the keys a migration changed are rewritten; comments, key order
+    /// and formatting elsewhere in each file are preserved.
+    ///
+    /// # Errors
+    /// Returns an error if a backup cannot be written or a file cannot be
+    /// rewritten. No file is rewritten unless all backups succeeded.
+    pub fn apply(&self) -> Result<()> {
+        for file in &self.files {
+            let backup = file.backup_path();
+            fs::copy(&file.path, &backup).map_err(|e| WayleError::IoError {
+                path: backup.clone(),
+                details: format!("Failed to back up config before migration: {e}"),
+            })?;
+        }
+
+        for file in &self.files {
+            let existing = fs::read_to_string(&file.path).map_err(|e| WayleError::IoError {
+                path: file.path.clone(),
+                details: format!("Failed to read config before migration: {e}"),
+            })?;
+            let mut document =
+                existing
+                    .parse::<DocumentMut>()
+                    .map_err(|e| WayleError::ConfigValidation {
+                        component: "config migration".to_string(),
+                        details: format!("Failed to parse {}: {e}", file.path.display()),
+                    })?;
+
+            if let (Value::Table(original), Value::Table(migrated)) =
+                (&file.original, &file.migrated)
+            {
+                sync_table(document.as_table_mut(), original, migrated).map_err(|details| {
+                    WayleError::ConfigValidation {
+                        component: "config migration".to_string(),
+                        details: format!("Failed to update {}: {details}", file.path.display()),
+                    }
+                })?;
+            }
+
+            fs::write(&file.path, document.to_string()).map_err(|e| WayleError::IoError {
+                path: file.path.clone(),
+                details: format!("Failed to write migrated config: {e}"),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Works out which files under a main config need migrating
+    ///
+    /// Covers the main file, everything it imports and all profiles. Files
+    /// without a `version` key inherit the version of the file importing them
+    /// (profiles inherit from the main file); a main file without one is
+    /// treated as version 1. Nothing is written to disk.
+    ///
+    /// # Errors
+    /// Returns an error if a file cannot be read or parsed, or declares a
+    /// version newer than this build supports.
+    pub fn plan_migration(path: &Path) -> Result<MigrationPlan> {
+        let mut plan = MigrationPlan {
+            from_version: CURRENT_VERSION,
+            files: Vec::new(),
+        };
+
+        if !path.exists() {
+            return Ok(plan);
+        }
+
+        let canonical_path = path.canonicalize().map_err(|e| WayleError::IoError {
+            path: path.to_path_buf(),
+            details: format!("Failed to resolve path: {e}"),
+        })?;
+
+        let mut visited = HashSet::new();
+        plan.from_version = Self::plan_file_migration(
+            &canonical_path,
+            INITIAL_VERSION,
+            true,
+            &mut visited,
+            &mut plan.files,
+        )?;
+
+        for profile in Self::profile_files(&canonical_path) {
+            Self::plan_file_migration(
+                &profile,
+                plan.from_version,
+                true,
+                &mut visited,
+                &mut plan.files,
+            )?;
+        }
+
+        Ok(plan)
+    }
+
+    /// Migrates all config files under a main config on disk
+    ///
+    /// Each affected file is backed up next to itself before being
+    /// rewritten (see [`FileMigration::backup_path`]). Returns the plan that
+    /// was applied, which is empty when everything was up to date.
+    ///
+    /// # Errors
+    /// Returns an error if planning fails or the files cannot be written.
+    pub fn migrate_files(path: &Path) -> Result<MigrationPlan> {
+        let plan = Self::plan_migration(path)?;
+        if !plan.is_empty() {
+            plan.apply()?;
+        }
+        Ok(plan)
+    }
+
+    fn plan_file_migration(
+        path: &Path,
+        inherited_version: u32,
+        is_root: bool,
+        visited: &mut HashSet<PathBuf>,
+        files: &mut Vec<FileMigration>,
+    ) -> Result<u32> {
+        if !visited.insert(path.to_path_buf()) {
+            return Ok(inherited_version);
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| WayleError::import(e, path))?;
+        let original: Value =
+            toml::from_str(&content).map_err(|e| WayleError::toml_parse(e, Some(path)))?;
+
+        let declared = declared_version(&original, path)?;
+        let version = declared.unwrap_or(inherited_version);
+
+        let mut migrated = migrate(original.clone(), version, path)?;
+        if is_root || declared.is_some() {
+            stamp_current_version(&mut migrated);
+        }
+
+        for import in Self::imports_from_value(&original) {
+            for import_path in import.matching_files(path)? {
+                Self::plan_file_migration(&import_path, version, false, visited, files)?;
+            }
+        }
+
+        if migrated != original {
+            files.push(FileMigration {
+                path: path.to_path_buf(),
+                from_version: version,
+                original,
+                migrated,
+            });
+        }
+
+        Ok(version)
+    }
+}
+
+fn flatten(value: &Value, prefix: &str, out: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(value, &path, out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_table) => {
+            for (index, item) in items.iter().enumerate() {
+                flatten(item, &format!("{prefix}[{index}]"), out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_string(), value.to_string());
+        }
+    }
+}
+
+/// Updates `table` in place so it matches `migrated`, touching only the
+/// keys that differ from `original`
+fn sync_table(
+    table: &mut dyn TableLike,
+    original: &toml::Table,
+    migrated: &toml::Table,
+) -> std::result::Result<(), String> {
+    for key in original.keys() {
+        if !migrated.contains_key(key) {
+            table.remove(key);
+        }
+    }
+
+    for (key, new) in migrated {
+        let old = original.get(key);
+        if old == Some(new) {
+            continue;
+        }
+
+        match (old, new, table.get_mut(key)) {
+            (Some(Value::Table(old)), Value::Table(new), Some(item)) if item.is_table_like() => {
+                if let Some(nested) = item.as_table_like_mut() {
+                    sync_table(nested, old, new)?;
+                }
+            }
+            (Some(Value::Array(old)), Value::Array(new), Some(Item::ArrayOfTables(tables)))
+                if old.len() == new.len()
+                    && old.len() == tables.len()
+                    && new.iter().all(Value::is_table) =>
+            {
+                for ((old, new), nested) in old.iter().zip(new).zip(tables.iter_mut()) {
+                    if let (Value::Table(old), Value::Table(new)) = (old, new) {
+                        sync_table(nested, old, new)?;
+                    }
+                }
+            }
+            (_, _, Some(item)) => replace_item(item, to_item(new)?),
+            (_, _, None) => {
+                table.insert(key, to_item(new)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces an item in place, keeping the comments and whitespace around
+/// an existing value
+fn replace_item(existing: &mut Item, item: Item) {
+    match existing {
+        Item::Value(old) => {
+            if let Ok(mut value) = item.into_value() {
+                *value.decor_mut() = old.decor().clone();
+                *old = value;
+            }
+        }
+        _ => *existing = item,
+    }
+}
+
+/// Converts a migrated value into an item, using standard table headers for
+/// tables and arrays of tables
+fn to_item(value: &Value) -> std::result::Result<Item, String> {
+    match value {
+        Value::Table(entries) => {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            for (key, value) in entries {
+                table.insert(key, to_item(value)?);
+            }
+            Ok(Item::Table(table))
+        }
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_table) => {
+            let mut tables = ArrayOfTables::new();
+            for item in items {
+                if let Item::Table(table) = to_item(item)? {
+                    tables.push(table);
+                }
+            }
+            Ok(Item::ArrayOfTables(tables))
+        }
+        _ => value
+            .to_string()
+            .parse::<toml_edit::Value>()
+            .map(Item::Value)
+            .map_err(|e| e.to_string()),
+    }
+}
@@ -0,0 +1,54 @@
+use toml::{Table, Value};
+
+/// v1 -> v2: flat `[modules.clock]` keys move into their sections
+///
+/// Before the clock module was split, `format`, `show_icon` and
+/// `show_calendar` lived directly under `[modules.clock]`. Values already
+/// present in the new sections win over the legacy keys.
+///
+/// # Errors
+/// Returns an error if a legacy key is set but its target section exists
+/// and is not a table, rather than dropping the legacy value.
+pub fn split_clock_sections(config: &mut Table) -> Result<(), String> {
+    const MOVES: [(&str, &str); 3] = [
+        ("format", "general"),
+        ("show_icon", "button"),
+        ("show_calendar", "dropdown"),
+    ];
+
+    let Some(Value::Table(clock)) = config
+        .get_mut("modules")
+        .and_then(|modules| modules.get_mut("clock"))
+    else {
+        return Ok(());
+    };
+
+    for (key, section) in MOVES {
+        if !clock.contains_key(key) {
+            continue;
+        }
+
+        if clock
+            .get(section)
+            .is_some_and(|existing| !existing.is_table())
+        {
+            return Err(format!(
+                "cannot move modules.clock.{key} into modules.clock.{section}, \
+                 which is not a table"
+            ));
+        }
+
+        let Some(value) = clock.remove(key) else {
+            continue;
+        };
+
+        if let Value::Table(section) = clock
+            .entry(section)
+            .or_insert_with(|| Value::Table(Table::new()))
+        {
+            section.entry(key).or_insert(value);
+        }
+    }
+
+    Ok(())
+}
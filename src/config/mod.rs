@@ -4,36 +4,50 @@
 //! general settings and module-specific configurations. All configurations
 //! are serializable to/from TOML format.
 
+mod audio;
 mod battery;
 mod clock;
 mod error;
 mod general;
 mod loading;
 mod media;
+mod migration;
 mod modules;
 mod paths;
+mod schema;
 mod styling;
 
 #[cfg(test)]
 mod tests;
 
+pub use audio::AudioConfig;
+pub use battery::BatteryConfig;
 pub use clock::ClockConfig;
 pub use error::{Result, WayleError};
+pub use loading::{ConditionContext, ConfigLayer, ConfigSource, ImportSpec, ValueOrigin};
+pub use general::GeneralConfig;
 pub use media::MediaConfig;
+pub use migration::{CURRENT_VERSION, FileMigration, MigrationPlan};
+pub use modules::ModulesConfig;
 pub use paths::ConfigPaths;
+pub use schema::{SCHEMA_DIRECTIVE, SCHEMA_FILE_NAME, with_schema_directive};
 pub use styling::*;
 
-use general::GeneralConfig;
-use modules::ModulesConfig;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Main configuration structure for Wayle.
 ///
 /// Represents the complete configuration schema that can be loaded
 /// from TOML files. All fields have sensible defaults.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct Config {
+    /// Schema version of the configuration.
+    ///
+    /// Older configurations are migrated to [`CURRENT_VERSION`] on load.
+    pub version: u32,
+
     /// General application settings.
     pub general: GeneralConfig,
 
@@ -42,4 +56,19 @@ pub struct Config {
 
     /// Media service configuration.
     pub media: MediaConfig,
+
+    /// Audio service configuration.
+    pub audio: AudioConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            general: GeneralConfig::default(),
+            modules: ModulesConfig::default(),
+            media: MediaConfig::default(),
+            audio: AudioConfig::default(),
+        }
+    }
 }
@@ -84,6 +84,47 @@ impl ConfigPaths {
         }
     }
 
+    /// Returns the directory holding named configuration profiles
+    ///
+    /// Each profile is a TOML overlay named `<profile>.toml` that is merged
+    /// on top of the main configuration when the profile is active.
+    ///
+    /// # Errors
+    /// Returns an error if neither `XDG_CONFIG_HOME` nor `HOME` environment variables are set
+    pub fn profiles_dir() -> Result<PathBuf, Error> {
+        Ok(Self::config_dir()?.join("profiles"))
+    }
+
+    /// Lists the names of all available configuration profiles
+    ///
+    /// Names are derived from the `.toml` files in the profiles directory
+    /// and returned in sorted order. A missing directory yields no profiles.
+    ///
+    /// # Errors
+    /// Returns an error if the config directory cannot be determined or the
+    /// profiles directory cannot be read
+    pub fn list_profiles() -> Result<Vec<String>, Error> {
+        let profiles_dir = Self::profiles_dir()?;
+
+        if !profiles_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut profiles: Vec<String> = fs::read_dir(&profiles_dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .filter_map(|path| {
+                path.file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .map(str::to_owned)
+            })
+            .collect();
+
+        profiles.sort();
+        Ok(profiles)
+    }
+
     /// Returns the path to the GUI configuration file
     ///
     /// # Panics
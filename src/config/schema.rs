@@ -0,0 +1,163 @@
+use std::{fs, path::Path};
+
+use schemars::schema_for;
+use serde_json::{Value, json};
+
+use super::{Config, Result, WayleError};
+
+/// File name of the schema written next to the main config file
+pub const SCHEMA_FILE_NAME: &str = "schema.json";
+
+/// Directive pointing taplo / Even Better TOML at the schema file
+pub const SCHEMA_DIRECTIVE: &str = "#:schema ./schema.json";
+
+impl Config {
+    /// Returns a JSON Schema describing every key accepted in config files
+    ///
+    /// Besides the [`Config`] structure itself, the schema covers the
+    /// loader-level keys: `imports`, `[[when]]` blocks and array merge
+    /// directives (`{ append = [...] }` in place of any array).
+    pub fn schema() -> Value {
+        let mut schema = schema_for!(Config).to_value();
+        allow_array_directives(&mut schema);
+
+        if let Some(properties) = schema
+            .get_mut("properties")
+            .and_then(Value::as_object_mut)
+        {
+            properties.insert("imports".to_string(), imports_schema());
+            properties.insert("when".to_string(), when_schema());
+        }
+
+        schema
+    }
+
+    /// Writes the config schema to `schema.json` in the given directory
+    ///
+    /// The file is only rewritten when its contents change, so file watchers
+    /// are not triggered needlessly.
+    ///
+    /// # Errors
+    /// Returns an error if the schema cannot be serialized or written.
+    pub fn write_schema(config_dir: &Path) -> Result<()> {
+        let path = config_dir.join(SCHEMA_FILE_NAME);
+        let content = serde_json::to_string_pretty(&Self::schema()).map_err(|e| {
+            WayleError::ConfigValidation {
+                component: "config schema".to_string(),
+                details: format!("Failed to serialize schema: {e}"),
+            }
+        })?;
+
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == content) {
+            return Ok(());
+        }
+
+        fs::write(&path, content).map_err(|e| WayleError::IoError {
+            path,
+            details: format!("Failed to write config schema: {e}"),
+        })
+    }
+}
+
+/// Prepends the `#:schema` directive to TOML content that lacks it
+///
+/// Used whenever Wayle writes the main config file, since serializing a TOML
+/// tree drops comments.
+pub fn with_schema_directive(content: &str) -> String {
+    if content.starts_with(SCHEMA_DIRECTIVE) {
+        return content.to_string();
+    }
+
+    format!("{SCHEMA_DIRECTIVE}\n{content}")
+}
+
+fn imports_schema() -> Value {
+    json!({
+        "description": "Files merged before this one. Entries start with `@` (or `?@` when \
+            the file may be absent) followed by a path relative to this file, an absolute or \
+            `~/` path, or a glob such as `@themes/*.toml`. `.toml` is implied.",
+        "type": "array",
+        "items": {
+            "type": "string",
+            "pattern": "^\\??@"
+        }
+    })
+}
+
+fn when_schema() -> Value {
+    let string_or_list = json!({
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } }
+        ]
+    });
+
+    json!({
+        "description": "Blocks of settings applied only when every condition matches. \
+            Conditions accept a single value or a list of alternatives; all other keys are \
+            regular config settings.",
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                "hostname": string_or_list,
+                "output": string_or_list,
+                "env": {
+                    "description": "Environment variables that must have the given value",
+                    "type": "object",
+                    "additionalProperties": string_or_list
+                }
+            }
+        }
+    })
+}
+
+fn directive_schema() -> Value {
+    let items = json!({ "type": "array" });
+
+    json!({
+        "description": "Modify the inherited array instead of replacing it",
+        "type": "object",
+        "properties": {
+            "append": items,
+            "prepend": items,
+            "remove": items
+        },
+        "additionalProperties": false,
+        "minProperties": 1
+    })
+}
+
+/// Lets every array-typed property also accept a merge directive table
+fn allow_array_directives(schema: &mut Value) {
+    match schema {
+        Value::Object(map) => map.values_mut().for_each(allow_array_directives),
+        Value::Array(items) => items.iter_mut().for_each(allow_array_directives),
+        _ => return,
+    }
+
+    let Some(properties) = schema
+        .get_mut("properties")
+        .and_then(Value::as_object_mut)
+    else {
+        return;
+    };
+
+    for property in properties.values_mut() {
+        if property.get("type").and_then(Value::as_str) != Some("array") {
+            continue;
+        }
+
+        let mut array = property.take();
+        let description = array
+            .as_object_mut()
+            .and_then(|array| array.remove("description"));
+
+        let mut wrapped = json!({ "anyOf": [array, directive_schema()] });
+        if let (Some(description), Some(wrapped)) = (description, wrapped.as_object_mut()) {
+            wrapped.insert("description".to_string(), description);
+        }
+
+        *property = wrapped;
+    }
+}
@@ -1,7 +1,7 @@
 //! Unit tests for config module
 //!
 //! Tests configuration types, defaults, and serialization.
-//! No filesystem dependencies - all in-memory.
+//! Loader tests use temporary directories; everything else is in-memory.
 
 #![allow(clippy::panic)]
 
@@ -167,3 +167,242 @@ fn config_unknown_fields() {
     let config: Config = toml::from_str(toml_with_unknown).unwrap();
     assert!(!format!("{config:?}").is_empty());
 }
+
+#[test]
+fn profile_path_resolves_next_to_main_config() {
+    let main = std::path::Path::new("/home/user/.config/wayle/config.toml");
+
+    let path = Config::resolve_profile_path(main, "docked").unwrap();
+
+    assert_eq!(
+        path,
+        std::path::Path::new("/home/user/.config/wayle/profiles/docked.toml")
+    );
+}
+
+#[test]
+fn profile_path_rejects_invalid_names() {
+    let main = std::path::Path::new("/home/user/.config/wayle/config.toml");
+
+    assert!(Config::resolve_profile_path(main, "").is_err());
+    assert!(Config::resolve_profile_path(main, "../escape").is_err());
+    assert!(Config::resolve_profile_path(main, ".hidden").is_err());
+}
+
+#[test]
+fn when_blocks_apply_only_on_matching_hosts() {
+    use crate::config::ConditionContext;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+        [modules.battery]
+        enabled = false
+
+        [[when]]
+        hostname = "work-laptop"
+        env.XDG_CURRENT_DESKTOP = ["Hyprland", "sway"]
+
+        [when.modules.battery]
+        enabled = true
+
+        [[when]]
+        hostname = "desktop"
+
+        [when.modules.battery]
+        enabled = false
+        "#,
+    )
+    .unwrap();
+
+    let context = ConditionContext {
+        hostname: Some("work-laptop".to_string()),
+        outputs: Vec::new(),
+        env: [("XDG_CURRENT_DESKTOP".to_string(), "sway".to_string())].into(),
+    };
+
+    let layers = Config::load_layers(&path, None, &context).unwrap();
+
+    assert_eq!(layers.len(), 2);
+    assert_eq!(
+        layers[1].source.condition.as_deref(),
+        Some(r#"hostname = "work-laptop" and env.XDG_CURRENT_DESKTOP in ["Hyprland", "sway"]"#)
+    );
+    assert!(layers[0].value.get("when").is_none());
+}
+
+#[test]
+fn glob_imports_load_in_sorted_order_and_optional_imports_may_be_absent() {
+    use crate::config::ConditionContext;
+
+    let dir = tempfile::tempdir().unwrap();
+    let themes = dir.path().join("themes");
+    std::fs::create_dir(&themes).unwrap();
+    std::fs::write(themes.join("b.toml"), "[general]\nlog_level = \"warn\"\n").unwrap();
+    std::fs::write(themes.join("a.toml"), "[general]\nlog_level = \"debug\"\n").unwrap();
+
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "imports = [\"@themes/*.toml\", \"?@local\"]\n").unwrap();
+
+    let layers = Config::load_layers(&path, None, &ConditionContext::default()).unwrap();
+    let names: Vec<_> = layers
+        .iter()
+        .filter_map(|layer| layer.source.path.file_name())
+        .collect();
+
+    assert_eq!(names, ["a.toml", "b.toml", "config.toml"]);
+    assert_eq!(Config::get_all_config_files(&path).unwrap().len(), 3);
+}
+
+#[test]
+fn imports_from_directories_with_glob_characters_match_literally() {
+    use crate::config::ImportSpec;
+
+    let dir = tempfile::tempdir().unwrap();
+    let config_dir = dir.path().join("wayle [laptop]*");
+    let themes = config_dir.join("themes");
+    std::fs::create_dir_all(&themes).unwrap();
+    std::fs::write(themes.join("dark.toml"), "").unwrap();
+    std::fs::write(themes.join("light.toml"), "").unwrap();
+    let path = config_dir.join("config.toml");
+
+    let single = ImportSpec::parse("@themes/dark").unwrap();
+    let glob = ImportSpec::parse("@themes/*.toml").unwrap();
+
+    assert_eq!(single.resolve(&path).unwrap().len(), 1);
+    let names: Vec<_> = glob
+        .resolve(&path)
+        .unwrap()
+        .into_iter()
+        .filter_map(|file| file.file_name().map(ToOwned::to_owned))
+        .collect();
+    assert_eq!(names, ["dark.toml", "light.toml"]);
+}
+
+#[test]
+fn import_spec_parses_required_and_optional_entries() {
+    use crate::config::ImportSpec;
+
+    let required = ImportSpec::parse("@themes/dark").unwrap();
+    let optional = ImportSpec::parse("?@local").unwrap();
+
+    assert!(!required.optional);
+    assert_eq!(required.pattern, "themes/dark");
+    assert!(optional.optional);
+    assert_eq!(optional.pattern, "local");
+    assert!(ImportSpec::parse("themes/dark").is_none());
+}
+
+#[test]
+fn array_directives_modify_inherited_arrays() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("base.toml"),
+        "[media]\nignored_players = [\"chromium\", \"kdeconnect\"]\n",
+    )
+    .unwrap();
+
+    let path = dir.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+        imports = ["@base"]
+
+        [media.ignored_players]
+        prepend = ["vlc"]
+        remove = ["kdeconnect"]
+        append = ["firefox"]
+        "#,
+    )
+    .unwrap();
+
+    let config = Config::load_with_imports(&path).unwrap();
+    assert_eq!(config.media.ignored_players, ["vlc", "chromium", "firefox"]);
+
+    let origins = Config::explain(&path, None, "media.ignored_players").unwrap();
+    assert_eq!(origins.len(), 2);
+    assert!(origins[0].is_merge_directive());
+    assert!(!origins[1].is_merge_directive());
+}
+
+#[test]
+fn legacy_clock_settings_migrate_into_sections() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let legacy =
+        "# My clock\n[modules.clock]\nformat = \"%I:%M %p\" # 12-hour\nshow_calendar = false\n";
+    std::fs::write(&path, legacy).unwrap();
+
+    let config = Config::load_with_imports(&path).unwrap();
+    let clock = config.modules.clock.unwrap();
+    assert_eq!(clock.general.format, "%I:%M %p");
+    assert!(!clock.dropdown.show_calendar);
+
+    let plan = Config::plan_migration(&path).unwrap();
+    assert_eq!(plan.from_version, 1);
+    assert!(
+        plan.files[0]
+            .diff()
+            .contains(&"+ modules.clock.general.format = \"%I:%M %p\"".to_string())
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), legacy);
+
+    plan.apply().unwrap();
+    assert!(plan.files[0].backup_path().exists());
+    assert!(Config::plan_migration(&path).unwrap().is_empty());
+
+    let migrated = std::fs::read_to_string(&path).unwrap();
+    assert!(migrated.contains("# My clock\n[modules.clock]\n"));
+    assert!(migrated.contains("[modules.clock.general]"));
+}
+
+#[test]
+fn migration_refuses_to_drop_legacy_clock_settings() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let legacy = "[modules.clock]\nformat = \"%H:%M\"\ngeneral = \"compact\"\n";
+    std::fs::write(&path, legacy).unwrap();
+
+    let error = Config::load_with_imports(&path).unwrap_err();
+    assert!(error.to_string().contains("modules.clock.format"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), legacy);
+}
+
+#[test]
+fn migration_preserves_comments_outside_changed_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let legacy = r#"# Top comment
+version = 1
+
+[media]
+# keep me
+ignored_players = ["firefox"]
+
+[modules.clock]
+format = "%H:%M"
+"#;
+    std::fs::write(&path, legacy).unwrap();
+
+    Config::migrate_files(&path).unwrap();
+
+    let migrated = std::fs::read_to_string(&path).unwrap();
+    let media = migrated.find("[media]").unwrap();
+    let clock = migrated.find("[modules.clock.general]").unwrap();
+    assert!(migrated.starts_with("# Top comment\n"));
+    assert!(migrated.contains("# keep me\nignored_players = [\"firefox\"]"));
+    assert!(media < clock);
+    assert!(!migrated.contains("version = 1"));
+}
+
+#[test]
+fn config_schema_covers_all_sections_and_loader_keys() {
+    let schema = Config::schema();
+    let properties = schema["properties"].as_object().unwrap();
+
+    for key in ["version", "general", "modules", "media", "audio", "imports", "when"] {
+        assert!(properties.contains_key(key), "schema is missing '{key}'");
+    }
+}
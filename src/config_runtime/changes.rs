@@ -130,6 +130,10 @@ pub enum ConfigError {
         details: String,
     },
 
+    /// The requested configuration profile does not exist
+    #[error("config profile '{0}' not found")]
+    ProfileNotFound(String),
+
     /// A required service is unavailable
     #[error("{service} service unavailable: {details}")]
     ServiceUnavailable {
@@ -1,5 +1,8 @@
 use super::{ConfigChange, ConfigError, ConfigRuntime, diff};
-use crate::config::{Config, ConfigPaths};
+use crate::{
+    config::{Config, ConfigPaths},
+    runtime_state::RuntimeState,
+};
 
 use notify::{
     EventKind, RecursiveMode, Watcher,
@@ -8,6 +11,7 @@ use notify::{
 };
 use std::{
     error::Error,
+    fs,
     time::{Duration, Instant},
 };
 use tokio::{
@@ -68,6 +72,22 @@ impl ConfigRuntime {
                 details: format!("Failed to watch config directory: {e}"),
             })?;
 
+        let profiles_dir =
+            ConfigPaths::profiles_dir().map_err(|e| ConfigError::FileWatcherInitError {
+                details: format!("Failed to get profiles directory: {e}"),
+            })?;
+
+        // Created up front so profiles added later are picked up without a restart
+        fs::create_dir_all(&profiles_dir).map_err(|e| ConfigError::FileWatcherInitError {
+            details: format!("Failed to create profiles directory: {e}"),
+        })?;
+
+        watcher
+            .watch(&profiles_dir, RecursiveMode::NonRecursive)
+            .map_err(|e| ConfigError::FileWatcherInitError {
+                details: format!("Failed to watch profiles directory: {e}"),
+            })?;
+
         let store = self.clone();
 
         let handle = tokio::spawn(async move {
@@ -80,14 +100,23 @@ impl ConfigRuntime {
         })
     }
 
-    fn reload_from_files(&self) -> Result<(), ConfigError> {
+    /// Whether the active profile recorded in the runtime state differs
+    /// from the one currently loaded.
+    async fn active_profile_changed(&self) -> bool {
+        Self::load_active_profile().await != self.active_profile()
+    }
+
+    async fn reload_from_files(&self) -> Result<(), ConfigError> {
+        let active_profile = Self::load_active_profile().await;
+        let new_config = Self::load_config_for_profile(active_profile.as_deref())?;
+
+        self.set_active_profile(active_profile)?;
+        self.replace_config(new_config)
+    }
+
+    /// Replaces the current configuration, broadcasting only the changed paths.
+    pub(super) fn replace_config(&self, new_config: Config) -> Result<(), ConfigError> {
         let old_config = self.get_current();
-        let new_config = Config::load_with_imports(&ConfigPaths::main_config()).map_err(|e| {
-            ConfigError::ProcessingError {
-                operation: "reload config".to_string(),
-                details: e.to_string(),
-            }
-        })?;
 
         let changes = self.diff_configs(&old_config, &new_config).map_err(|e| {
             ConfigError::ProcessingError {
@@ -120,8 +149,18 @@ impl ConfigRuntime {
     }
 }
 
+/// Configuration file touched by a watched event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ChangedFile {
+    /// A TOML configuration or profile file
+    Config,
+    /// The runtime state file, which is also written for unrelated state
+    RuntimeState,
+}
+
 async fn file_watch_loop(event_rx: &mut Receiver<notify::Event>, store: ConfigRuntime) {
-    let mut pending_changes = false;
+    let mut pending_config = false;
+    let mut pending_state = false;
     let mut last_change = Instant::now();
     let debounce_duration = Duration::from_millis(100);
 
@@ -129,29 +168,37 @@ async fn file_watch_loop(event_rx: &mut Receiver<notify::Event>, store: ConfigRu
         let timeout_result = timeout(debounce_duration, event_rx.recv()).await;
 
         match timeout_result {
-            Ok(Some(event)) => {
-                if is_relevant_event(&event) {
-                    pending_changes = true;
+            Ok(Some(event)) => match changed_file(&event) {
+                Some(ChangedFile::Config) => {
+                    pending_config = true;
                     last_change = Instant::now();
                 }
-            }
+                Some(ChangedFile::RuntimeState) => {
+                    pending_state = true;
+                    last_change = Instant::now();
+                }
+                None => {}
+            },
             Ok(None) => {
                 debug!("File watching terminated");
                 break;
             }
             Err(_) => {
+                let pending_changes = pending_config || pending_state;
                 if pending_changes && last_change.elapsed() >= debounce_duration {
-                    if let Err(e) = store.reload_from_files() {
+                    let needs_reload = pending_config || store.active_profile_changed().await;
+                    if needs_reload && let Err(e) = store.reload_from_files().await {
                         error!("Failed to reload config: {e}");
                     }
-                    pending_changes = false;
+                    pending_config = false;
+                    pending_state = false;
                 }
             }
         }
     }
 }
 
-fn is_relevant_event(event: &notify::Event) -> bool {
+pub(super) fn changed_file(event: &notify::Event) -> Option<ChangedFile> {
     let is_write_event = matches!(
         event.kind,
         EventKind::Modify(_)
@@ -160,10 +207,22 @@ fn is_relevant_event(event: &notify::Event) -> bool {
             | EventKind::Access(AccessKind::Close(AccessMode::Write))
     );
 
-    is_write_event
-        && event.paths.iter().any(|path| {
-            path.file_name()
-                .and_then(|n| n.to_str())
-                .is_some_and(|name| name.ends_with(".toml"))
-        })
+    if !is_write_event {
+        return None;
+    }
+
+    let names = || {
+        event
+            .paths
+            .iter()
+            .filter_map(|path| path.file_name().and_then(|n| n.to_str()))
+    };
+
+    if names().any(|name| name.ends_with(".toml")) {
+        Some(ChangedFile::Config)
+    } else if names().any(|name| name == RuntimeState::state_file_name()) {
+        Some(ChangedFile::RuntimeState)
+    } else {
+        None
+    }
 }
@@ -8,6 +8,7 @@ mod changes;
 mod diff;
 mod file_watching;
 mod path_ops;
+mod profiles;
 mod runtime;
 
 #[cfg(test)]
@@ -16,4 +17,5 @@ mod tests;
 pub use broadcast::Subscription;
 pub use changes::{ConfigChange, ConfigError};
 pub use file_watching::FileWatcher;
+pub use profiles::DEFAULT_PROFILE;
 pub use runtime::ConfigRuntime;
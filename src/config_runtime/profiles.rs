@@ -0,0 +1,124 @@
+use tracing::{info, instrument, warn};
+
+use crate::{
+    config::{Config, ConfigPaths},
+    runtime_state::RuntimeState,
+};
+
+use super::{ConfigError, ConfigRuntime};
+
+/// Profile name that refers to the base configuration without any overlay.
+pub const DEFAULT_PROFILE: &str = "default";
+
+impl ConfigRuntime {
+    /// Returns the name of the currently active configuration profile.
+    ///
+    /// Returns `None` when only the base configuration is in effect.
+    pub fn active_profile(&self) -> Option<String> {
+        match self.active_profile.read() {
+            Ok(guard) => guard.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Lists all profiles available in the profiles directory.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::IoError` if the profiles directory cannot be read.
+    pub fn list_profiles(&self) -> Result<Vec<String>, ConfigError> {
+        ConfigPaths::list_profiles().map_err(|e| ConfigError::IoError {
+            path: ConfigPaths::profiles_dir().unwrap_or_default(),
+            details: format!("Failed to list profiles: {e}"),
+        })
+    }
+
+    /// Switches to a different configuration profile.
+    ///
+    /// The new profile is validated and loaded before anything is persisted.
+    /// The resulting configuration is diffed against the current one, and only
+    /// the changed paths are broadcast to subscribers. Passing `None` (or the
+    /// `default` profile name) returns to the base configuration.
+    ///
+    /// # Errors
+    /// * `ConfigError::ProfileNotFound` - If the profile file does not exist
+    /// * `ConfigError::ProcessingError` - If the profiled configuration cannot be loaded
+    /// * `ConfigError::PersistenceError` - If the active profile cannot be saved
+    #[instrument(skip(self))]
+    pub async fn switch_profile(&self, profile: Option<String>) -> Result<(), ConfigError> {
+        let profile = profile.filter(|name| name != DEFAULT_PROFILE);
+
+        if let Some(name) = &profile {
+            let profile_path = Config::resolve_profile_path(&ConfigPaths::main_config(), name)
+                .map_err(|e| ConfigError::ProcessingError {
+                    operation: "resolve profile".to_string(),
+                    details: e.to_string(),
+                })?;
+
+            if !profile_path.exists() {
+                return Err(ConfigError::ProfileNotFound(name.clone()));
+            }
+        }
+
+        let new_config = Self::load_config_for_profile(profile.as_deref())?;
+
+        RuntimeState::set_active_profile(profile.clone())
+            .await
+            .map_err(|e| ConfigError::PersistenceError {
+                path: ConfigPaths::config_dir().unwrap_or_default(),
+                details: format!("Failed to save active profile: {e}"),
+            })?;
+
+        info!(
+            "Switching to profile '{}'",
+            profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+        );
+        self.set_active_profile(profile)?;
+        self.replace_config(new_config)
+    }
+
+    pub(super) async fn load_active_profile() -> Option<String> {
+        match RuntimeState::get_active_profile().await {
+            Ok(profile) => profile,
+            Err(e) => {
+                warn!("Failed to read active profile, using base configuration: {e}");
+                None
+            }
+        }
+    }
+
+    pub(super) fn load_config_for_profile(profile: Option<&str>) -> Result<Config, ConfigError> {
+        let profile = Self::existing_profile(profile);
+
+        Config::load_with_profile(&ConfigPaths::main_config(), profile).map_err(|e| {
+            ConfigError::ProcessingError {
+                operation: "load config".to_string(),
+                details: e.to_string(),
+            }
+        })
+    }
+
+    pub(super) fn existing_profile(profile: Option<&str>) -> Option<&str> {
+        let main_config = ConfigPaths::main_config();
+
+        profile.filter(|name| {
+            let exists = Config::resolve_profile_path(&main_config, name)
+                .is_ok_and(|path| path.exists());
+            if !exists {
+                warn!("Active profile '{name}' no longer exists, using base configuration");
+            }
+            exists
+        })
+    }
+
+    pub(super) fn set_active_profile(&self, profile: Option<String>) -> Result<(), ConfigError> {
+        let mut guard = self
+            .active_profile
+            .write()
+            .map_err(|e| ConfigError::LockError {
+                lock_type: "write".to_string(),
+                details: format!("Failed to acquire write lock for active_profile: {e}"),
+            })?;
+        *guard = profile;
+        Ok(())
+    }
+}
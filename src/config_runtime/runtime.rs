@@ -14,7 +14,10 @@ pub type ConfigData = Arc<RwLock<Config>>;
 /// Thread-safe storage for runtime configuration values
 pub type RuntimeConfig = Arc<RwLock<HashMap<String, Value>>>;
 
-use crate::config::{Config, ConfigPaths};
+/// Thread-safe storage for the active configuration profile name
+pub type ActiveProfile = Arc<RwLock<Option<String>>>;
+
+use crate::config::{Config, ConfigPaths, ValueOrigin, with_schema_directive};
 
 use super::{
     ConfigChange, ConfigError, Subscription,
@@ -31,6 +34,7 @@ pub struct ConfigRuntime {
     config: ConfigData,
     broadcast_service: BroadcastService,
     runtime_config: RuntimeConfig,
+    pub(super) active_profile: ActiveProfile,
 }
 
 impl ConfigRuntime {
@@ -42,25 +46,67 @@ impl ConfigRuntime {
         Self {
             config: Arc::new(RwLock::new(config)),
             runtime_config: Arc::new(RwLock::new(HashMap::new())),
+            active_profile: Arc::new(RwLock::new(None)),
             broadcast_service,
         }
     }
 
     /// Loads a ConfigRuntime from the main configuration file.
     ///
+    /// Outdated configuration files are migrated to the current schema version
+    /// on disk first, keeping a backup of each rewritten file, and the JSON
+    /// Schema next to the config is refreshed. The active
+    /// profile persisted in the runtime state, if any, is applied on top of
+    /// the main configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::ProcessingError` if the configuration cannot be
+    /// migrated or loaded.
+    #[instrument]
+    pub async fn load() -> Result<Self, ConfigError> {
+        let main_config = ConfigPaths::main_config();
+        let plan = Config::migrate_files(&main_config).map_err(|e| {
+            ConfigError::ProcessingError {
+                operation: "migrate config".to_string(),
+                details: e.to_string(),
+            }
+        })?;
+
+        if !plan.is_empty() {
+            info!(
+                "Migrated {} config file(s) from version {} to {}",
+                plan.files.len(),
+                plan.from_version,
+                plan.to_version()
+            );
+        }
+
+        if let Ok(config_dir) = ConfigPaths::config_dir()
+            && let Err(e) = Config::write_schema(&config_dir)
+        {
+            warn!("Failed to update config schema: {e}");
+        }
+
+        Self::load_without_migrations().await
+    }
+
+    /// Loads a ConfigRuntime without rewriting outdated configuration files.
+    ///
+    /// Outdated files are still migrated in memory, so the resulting
+    /// configuration is identical to [`ConfigRuntime::load`]. Used by
+    /// `wayle config migrate`, which previews and applies migrations itself.
+    ///
     /// # Errors
     ///
     /// Returns `ConfigError::ProcessingError` if the configuration file cannot be loaded.
     #[instrument]
-    pub fn load() -> Result<Self, ConfigError> {
+    pub async fn load_without_migrations() -> Result<Self, ConfigError> {
         let main_config = ConfigPaths::main_config();
         info!("Loading configuration from {}", main_config.display());
 
-        let config =
-            Config::load_with_imports(&main_config).map_err(|e| ConfigError::ProcessingError {
-                operation: "load config".to_string(),
-                details: e.to_string(),
-            })?;
+        let active_profile = Self::load_active_profile().await;
+        let config = Self::load_config_for_profile(active_profile.as_deref())?;
         let broadcast_service = BroadcastService::new();
 
         debug!("Loading runtime configuration");
@@ -70,6 +116,7 @@ impl ConfigRuntime {
         Ok(Self {
             config: Arc::new(RwLock::new(config)),
             runtime_config: Arc::new(RwLock::new(runtime_config)),
+            active_profile: Arc::new(RwLock::new(active_profile)),
             broadcast_service,
         })
     }
@@ -136,6 +183,29 @@ impl ConfigRuntime {
         Self::get_config_field(&config, path)
     }
 
+    /// Lists the config sources that set the value at the specified path
+    ///
+    /// Sources are ordered from highest to lowest precedence, and include the
+    /// `[[when]]` conditions that contributed each value. An empty list means
+    /// the value comes from the built-in defaults.
+    ///
+    /// # Arguments
+    /// * `path` - Dot-separated path to the configuration field (e.g., "server.port")
+    ///
+    /// # Errors
+    /// * `ConfigError::ProcessingError` - If the configuration files cannot be loaded
+    pub fn explain(&self, path: &str) -> Result<Vec<ValueOrigin>, ConfigError> {
+        let active_profile = self.active_profile();
+        let profile = Self::existing_profile(active_profile.as_deref());
+
+        Config::explain(&ConfigPaths::main_config(), profile, path).map_err(|e| {
+            ConfigError::ProcessingError {
+                operation: "explain config".to_string(),
+                details: e.to_string(),
+            }
+        })
+    }
+
     /// Returns a clone of the current configuration, handling poisoned locks gracefully
     pub fn get_current(&self) -> Config {
         match self.config.read() {
@@ -297,7 +367,9 @@ impl ConfigRuntime {
             }
         }
 
-        toml::to_string(&doc).unwrap_or_else(|_| config.to_string())
+        toml::to_string(&doc)
+            .map(|content| with_schema_directive(&content))
+            .unwrap_or_else(|_| config.to_string())
     }
 
     fn load_runtime_config() -> Result<HashMap<String, Value>, ConfigError> {
@@ -0,0 +1,41 @@
+//! Unit tests for the configuration runtime
+//!
+//! Covers classification of file watcher events.
+
+use std::path::PathBuf;
+
+use notify::{Event, EventKind, event::ModifyKind};
+
+use super::file_watching::{ChangedFile, changed_file};
+use crate::runtime_state::RuntimeState;
+
+fn modified(path: &str) -> Event {
+    Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(path))
+}
+
+#[test]
+fn toml_writes_are_config_changes() {
+    let event = modified("/home/user/.config/wayle/profiles/work.toml");
+
+    assert_eq!(changed_file(&event), Some(ChangedFile::Config));
+}
+
+#[test]
+fn state_file_writes_are_not_config_changes() {
+    let path = format!("/home/user/.wayle/{}", RuntimeState::state_file_name());
+
+    assert_eq!(
+        changed_file(&modified(&path)),
+        Some(ChangedFile::RuntimeState)
+    );
+}
+
+#[test]
+fn unrelated_files_and_reads_are_ignored() {
+    let unrelated = modified("/home/user/.config/wayle/notes.txt");
+    let read = Event::new(EventKind::Access(notify::event::AccessKind::Read))
+        .add_path(PathBuf::from("/home/user/.config/wayle/config.toml"));
+
+    assert_eq!(changed_file(&unrelated), None);
+    assert_eq!(changed_file(&read), None);
+}
@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::docs::generate_module_page;
+use crate::docs::{generate_index_page, generate_module_page};
 
 use super::{ModuleInfo, ModuleRegistry};
 use thiserror::Error;
@@ -36,28 +36,25 @@ impl DocsGenerator {
         self
     }
 
+    /// Returns the directory generated documentation is written to.
+    pub fn output_dir(&self) -> &Path {
+        Path::new(&self.output_dir)
+    }
+
     /// Generates documentation for all registered modules.
     ///
     /// Creates markdown files for each module in the output directory,
-    /// documenting their configuration options and schemas.
+    /// documenting their configuration options and schemas, plus an
+    /// `index.md` page linking all of them. Returns the written paths.
     ///
     /// # Errors
     ///
     /// Returns `DocsError::FileWrite` if direction creation failes.
-    pub fn generate_all(&self) -> Result<(), DocsError> {
-        fs::create_dir_all(&self.output_dir).map_err(|err| DocsError::FileWriteError {
-            path: PathBuf::from(&self.output_dir),
-            details: format!("Failed to create output directory: {err}"),
-        })?;
-
-        let modules = ModuleRegistry::get_all();
-
-        for module in &modules {
-            self.generate_single_module(module)?;
-        }
-
-        println!("Generated documentation for {} modules", modules.len());
-        Ok(())
+    pub fn generate_all(&self) -> Result<Vec<PathBuf>, DocsError> {
+        self.render_all()?
+            .into_iter()
+            .map(|doc| self.write(doc))
+            .collect()
     }
 
     /// Generates documentation for a specific module by name.
@@ -65,14 +62,84 @@ impl DocsGenerator {
     /// # Errors
     ///
     /// Returns `DocsError::InvalidModuleName` if the module doesn't exist.
-    pub fn generate_module_by_name(&self, module_name: &str) -> Result<(), DocsError> {
+    pub fn generate_module_by_name(&self, module_name: &str) -> Result<PathBuf, DocsError> {
         let module = ModuleRegistry::get_module_by_name(module_name).ok_or_else(|| {
             DocsError::ModuleNotFound {
                 name: module_name.to_string(),
             }
         })?;
 
-        self.generate_single_module(&module)
+        let doc = self.render_module(&module)?;
+        self.write(doc)
+    }
+
+    /// Renders every documentation page in memory without touching the disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocsError::SchemaConversion` if a module schema cannot be rendered.
+    pub fn render_all(&self) -> Result<Vec<GeneratedDoc>, DocsError> {
+        let modules = ModuleRegistry::get_all();
+
+        let mut docs = modules
+            .iter()
+            .map(|module| self.render_module(module))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        docs.push(GeneratedDoc {
+            path: self.output_dir().join("index.md"),
+            content: generate_index_page(&modules),
+        });
+
+        Ok(docs)
+    }
+
+    /// Compares freshly rendered documentation against the files on disk.
+    ///
+    /// Returns one entry per page that is missing or differs from what the
+    /// generator would produce, and per markdown file in the output directory
+    /// that the generator no longer produces; an empty result means the docs
+    /// are current.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocsError::SchemaConversion` if a module schema cannot be rendered.
+    pub fn check(&self) -> Result<Vec<StaleDoc>, DocsError> {
+        let docs = self.render_all()?;
+        let expected: Vec<PathBuf> = docs.iter().map(|doc| doc.path.clone()).collect();
+
+        let mut stale: Vec<StaleDoc> = docs
+            .into_iter()
+            .filter_map(|doc| {
+                let existing = fs::read_to_string(&doc.path).ok();
+                if existing.as_deref() == Some(doc.content.as_str()) {
+                    return None;
+                }
+
+                Some(StaleDoc {
+                    diff: line_diff(existing.as_deref().unwrap_or_default(), &doc.content),
+                    kind: if existing.is_some() {
+                        StaleKind::Outdated
+                    } else {
+                        StaleKind::Missing
+                    },
+                    path: doc.path,
+                })
+            })
+            .collect();
+
+        stale.extend(
+            self.existing_pages()
+                .into_iter()
+                .filter(|path| !expected.contains(path))
+                .map(|path| StaleDoc {
+                    diff: line_diff(&fs::read_to_string(&path).unwrap_or_default(), ""),
+                    kind: StaleKind::Orphaned,
+                    path,
+                }),
+        );
+
+        Ok(stale)
     }
 
     /// Returns a list of all available module names.
@@ -80,21 +147,109 @@ impl DocsGenerator {
         ModuleRegistry::list_module_names()
     }
 
-    fn generate_single_module(&self, module: &ModuleInfo) -> Result<(), DocsError> {
-        let content = generate_module_page(module)?;
-        let filename = format!("{}.md", module.name);
-        let filepath = Path::new(&self.output_dir).join(filename);
+    /// Markdown files currently in the output directory, sorted by path
+    fn existing_pages(&self) -> Vec<PathBuf> {
+        let Ok(entries) = fs::read_dir(self.output_dir()) else {
+            return Vec::new();
+        };
+
+        let mut pages: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        pages.sort();
+        pages
+    }
+
+    fn render_module(&self, module: &ModuleInfo) -> Result<GeneratedDoc, DocsError> {
+        Ok(GeneratedDoc {
+            path: self.output_dir().join(format!("{}.md", module.name)),
+            content: generate_module_page(module)?,
+        })
+    }
+
+    fn write(&self, doc: GeneratedDoc) -> Result<PathBuf, DocsError> {
+        fs::create_dir_all(&self.output_dir).map_err(|err| DocsError::FileWriteError {
+            path: PathBuf::from(&self.output_dir),
+            details: format!("Failed to create output directory: {err}"),
+        })?;
 
-        fs::write(&filepath, content).map_err(|err| DocsError::FileWriteError {
-            path: filepath.clone(),
+        fs::write(&doc.path, doc.content).map_err(|err| DocsError::FileWriteError {
+            path: doc.path.clone(),
             details: err.to_string(),
         })?;
 
-        println!("Generated {}", filepath.display());
-        Ok(())
+        Ok(doc.path)
     }
 }
 
+/// A documentation page rendered in memory.
+#[derive(Debug, Clone)]
+pub struct GeneratedDoc {
+    /// Where the page is written
+    pub path: PathBuf,
+    /// Markdown content of the page
+    pub content: String,
+}
+
+/// A documentation page whose file on disk does not match the generator output.
+#[derive(Debug, Clone)]
+pub struct StaleDoc {
+    /// Path of the outdated page
+    pub path: PathBuf,
+    /// How the file on disk differs from the generator output
+    pub kind: StaleKind,
+    /// Changed lines as `- old` / `+ new` entries
+    pub diff: Vec<String>,
+}
+
+/// How a documentation page on disk is out of date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleKind {
+    /// The page exists but its content differs
+    Outdated,
+    /// The page does not exist on disk at all
+    Missing,
+    /// The file exists but the generator no longer produces it
+    Orphaned,
+}
+
+/// Lists the lines removed from `old` and added in `new`, in document order.
+fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || common[i][j + 1] >= common[i + 1][j]) {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        } else {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        }
+    }
+
+    diff
+}
+
 /// Errors that can occur during documentation generation.
 #[derive(Error, Debug)]
 pub enum DocsError {
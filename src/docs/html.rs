@@ -0,0 +1,137 @@
+//! Single-page HTML config reference.
+//!
+//! The page is self-contained: styles and the search script are inlined so
+//! it can be opened straight from disk.
+
+use super::{
+    ModuleInfo, PropertyInfo,
+    generator::DocsError,
+    sections::{display_name, example_toml, module_sections},
+};
+
+const STYLE: &str = "\
+body{font-family:sans-serif;max-width:960px;margin:0 auto;padding:1rem;line-height:1.5}\
+nav ul{columns:3;list-style:none;padding:0}\
+table{border-collapse:collapse;width:100%;margin-bottom:1rem}\
+th,td{border:1px solid #ccc;padding:.3rem .5rem;text-align:left;vertical-align:top}\
+code,pre{background:#f4f4f4}pre{padding:.5rem;overflow-x:auto}\
+#search{width:100%;padding:.4rem;font-size:1rem;margin-bottom:1rem}\
+.hidden{display:none}";
+
+const SEARCH_SCRIPT: &str = "\
+const input=document.getElementById('search');\
+input.addEventListener('input',()=>{\
+const query=input.value.trim().toLowerCase();\
+document.querySelectorAll('tr[data-search]').forEach(row=>{\
+row.classList.toggle('hidden',query!==''&&!row.dataset.search.includes(query));});\
+document.querySelectorAll('section.module').forEach(section=>{\
+const rows=section.querySelectorAll('tr[data-search]');\
+const visible=[...rows].some(row=>!row.classList.contains('hidden'));\
+section.classList.toggle('hidden',query!==''&&!visible);});});";
+
+/// Generates a self-contained HTML page documenting every config section.
+///
+/// Each module and property gets an anchor (`#clock` and
+/// `#modules.clock.general.format`), and a search box filters properties by
+/// path and description.
+///
+/// # Errors
+///
+/// Returns `DocsError::SchemaConversion` if schema serialization fails.
+pub fn generate_html_reference(modules: &[ModuleInfo]) -> Result<String, DocsError> {
+    let mut body = String::new();
+
+    for module in modules {
+        body.push_str(&format!(
+            "<section class=\"module\" id=\"{id}\">\n<h2><a href=\"#{id}\">{} {}</a></h2>\n<p>{}</p>\n",
+            escape(&module.icon),
+            escape(&display_name(&module.name)),
+            escape(&module.description),
+            id = escape(&module.name),
+        ));
+
+        let sections = module_sections(module)?;
+
+        for section in &sections {
+            body.push_str(&format!(
+                "<h3>{}</h3>\n<p>Config path: <code>[{}]</code></p>\n\
+                 <table>\n<tr><th>Property</th><th>Type</th><th>Description</th><th>Default</th></tr>\n",
+                escape(&section.title),
+                escape(&section.table_path)
+            ));
+
+            for property in &section.properties {
+                body.push_str(&property_row(&section.table_path, property));
+            }
+
+            body.push_str("</table>\n");
+        }
+
+        if let Some(example) = example_toml(module, &sections)? {
+            body.push_str(&format!(
+                "<h3>Example</h3>\n<pre><code>{}</code></pre>\n",
+                escape(&example)
+            ));
+        }
+
+        body.push_str("</section>\n");
+    }
+
+    let navigation: String = modules
+        .iter()
+        .map(|module| {
+            format!(
+                "<li><a href=\"#{}\">{} {}</a></li>\n",
+                escape(&module.name),
+                escape(&module.icon),
+                escape(&display_name(&module.name))
+            )
+        })
+        .collect();
+
+    Ok(format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Wayle Configuration Reference</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+         <h1>Wayle Configuration Reference</h1>\n\
+         <input id=\"search\" type=\"search\" placeholder=\"Search options\">\n\
+         <nav><ul>\n{navigation}</ul></nav>\n{body}\
+         <script>{SEARCH_SCRIPT}</script>\n</body>\n</html>\n"
+    ))
+}
+
+fn property_row(table_path: &str, property: &PropertyInfo) -> String {
+    let path = format!("{table_path}.{}", property.name);
+    let mut description = escape(&property.description);
+
+    if !property.variants.is_empty() {
+        let variants: String = property
+            .variants
+            .iter()
+            .map(|variant| match &variant.description {
+                Some(doc) => format!(
+                    "<li><code>{}</code>: {}</li>",
+                    escape(&variant.value),
+                    escape(doc)
+                ),
+                None => format!("<li><code>{}</code></li>", escape(&variant.value)),
+            })
+            .collect();
+        description.push_str(&format!("<ul>{variants}</ul>"));
+    }
+
+    format!(
+        "<tr id=\"{id}\" data-search=\"{search}\"><td><a href=\"#{id}\"><code>{id}</code></a></td>\
+         <td><code>{}</code></td><td>{description}</td><td><code>{}</code></td></tr>\n",
+        escape(&property.type_name),
+        escape(&property.default_value),
+        id = escape(&path),
+        search = escape(&format!("{path} {}", property.description).to_lowercase()),
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,5 +1,7 @@
 use super::{
-    ModuleInfo, PropertyInfo, extract_property_info, generator::DocsError, module::SchemeFn,
+    ModuleInfo, PropertyInfo,
+    generator::DocsError,
+    sections::{display_name, example_toml, is_bar_module, module_sections},
 };
 
 const TABLE_HEADER: &str =
@@ -8,10 +10,11 @@ const TABLE_HEADER: &str =
 /// Generates a markdown table documenting configuration properties.
 ///
 /// Creates a formatted table with property names, types, descriptions,
-/// and default values for display in documentation.
+/// and default values for display in documentation. Properties are listed by
+/// their full dotted path below `table_path`, as accepted by `wayle config set`.
 pub fn generate_property_table(
     section_title: &str,
-    config_path: &str,
+    table_path: &str,
     properties: Vec<PropertyInfo>,
 ) -> String {
     if properties.is_empty() {
@@ -22,15 +25,18 @@ pub fn generate_property_table(
         .iter()
         .map(|prop| {
             format!(
-                "| `{}` | `{}` | {} | `{}` |",
-                prop.name, prop.type_name, prop.description, prop.default_value
+                "| `{table_path}.{}` | `{}` | {} | `{}` |",
+                prop.name,
+                prop.type_name,
+                describe_property(prop),
+                prop.default_value
             )
         })
         .collect::<Vec<String>>()
         .join("\n");
 
     format!(
-        "## {section_title}\n**Config path:** `{config_path}`\n\n{TABLE_HEADER}\n{property_rows}\n"
+        "## {section_title}\n**Config path:** `[{table_path}]`\n\n{TABLE_HEADER}\n{property_rows}\n"
     )
 }
 
@@ -43,73 +49,90 @@ pub fn generate_property_table(
 ///
 /// Returns `DocsError::SchemaConversion` if schema serialization fails.
 pub fn generate_module_page(module: &ModuleInfo) -> Result<String, DocsError> {
-    let mut content = String::new();
+    let sections = module_sections(module)?;
+    let mut content = generate_header(module);
 
-    content.push_str(&generate_header(module));
-    content.push_str(&generate_behavior_sections(module)?);
-    content.push_str(&generate_styling_sections(module)?);
+    if sections.is_empty() {
+        content.push_str("This section has no configurable options yet.\n");
+    }
+
+    for section in &sections {
+        content.push_str(&generate_property_table(
+            &section.title,
+            &section.table_path,
+            section.properties.clone(),
+        ));
+        content.push('\n');
+    }
+
+    if let Some(example) = example_toml(module, &sections)? {
+        content.push_str(&format!("## Example\n\n```toml\n{example}```\n"));
+    }
 
     Ok(content)
 }
 
-fn generate_header(module: &ModuleInfo) -> String {
+/// Generates an index page linking the documentation page of every module.
+pub fn generate_index_page(modules: &[ModuleInfo]) -> String {
+    let rows = modules
+        .iter()
+        .map(|module| {
+            format!(
+                "| {} [{}]({}.md) | `[{}]` | {} |",
+                module.icon,
+                display_name(&module.name),
+                module.name,
+                module.config_path,
+                module.description
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
     format!(
-        "# {} {} Module\n\n{}\n\n",
-        module.icon,
-        title_case(&module.name),
-        module.description
+        "# Configuration Reference\n\n\
+         | Section | Config path | Description |\n\
+         |---------|-------------|-------------|\n\
+         {rows}\n"
     )
 }
 
-fn generate_behavior_sections(module: &ModuleInfo) -> Result<String, DocsError> {
-    generate_sections(&module.behavior_configs, &module.name, "Behavior", "")
-}
+fn generate_header(module: &ModuleInfo) -> String {
+    let suffix = if is_bar_module(module) {
+        " Module"
+    } else {
+        ""
+    };
 
-fn generate_styling_sections(module: &ModuleInfo) -> Result<String, DocsError> {
-    generate_sections(&module.styling_configs, &module.name, "Styling", ".styling")
+    format!(
+        "# {} {}{suffix}\n\n{}\n\n",
+        module.icon,
+        display_name(&module.name),
+        module.description
+    )
 }
 
-fn generate_sections(
-    configs: &[(String, SchemeFn)],
-    module_name: &str,
-    section_type: &str,
-    path_prefix: &str,
-) -> Result<String, DocsError> {
-    let mut content = String::new();
+fn describe_property(property: &PropertyInfo) -> String {
+    let mut description = escape_cell(&property.description);
 
-    for (config_name, schema_fn) in configs {
-        let schema_value =
-            serde_json::to_value(schema_fn()).map_err(|e| DocsError::SchemaConversionError {
-                module: module_name.to_string(),
-                details: format!("Failed to generate section for '{config_name}': {e}"),
-            })?;
+    if !property.variants.is_empty() {
+        description.push_str("<br>Values:");
 
-        let properties = extract_property_info(&schema_value);
-
-        if !properties.is_empty() {
-            let section_title = format!("{} {}", title_case(config_name), section_type);
-
-            let config_path = format!("[modules.{module_name}{path_prefix}.{config_name}]");
-
-            let generated_table = generate_property_table(&section_title, &config_path, properties);
-
-            content.push_str(&generated_table);
-            content.push('\n');
+        for variant in &property.variants {
+            let value = escape_cell(&variant.value);
+            match &variant.description {
+                Some(doc) => {
+                    description.push_str(&format!("<br>- `{value}`: {}", escape_cell(doc)));
+                }
+                None => description.push_str(&format!("<br>- `{value}`")),
+            }
         }
     }
 
-    Ok(content)
+    description
 }
 
-fn title_case(s: &str) -> String {
-    if s.is_empty() {
-        return String::new();
-    }
-    let mut chars = s.chars();
-    chars
-        .next()
-        .unwrap_or_default()
-        .to_uppercase()
-        .chain(chars.as_str().chars())
-        .collect()
+/// Escapes text so it stays inside a single markdown table cell
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
 }
@@ -1,18 +1,27 @@
 //! Documentation generation for configuration schemas.
 //!
-//! Automatically generates markdown documentation from Rust configuration
-//! structures using reflection and type information.
+//! Automatically generates documentation from Rust configuration structures
+//! using reflection and type information. Pages are rendered as markdown,
+//! man pages (`wayle-config(5)`, `wayle(1)`) or a single HTML reference.
 
 mod generator;
+mod html;
 mod markdown;
 mod module;
 mod registry;
+mod roff;
 mod schema;
+mod sections;
 
-pub use generator::DocsGenerator;
-pub use markdown::{generate_module_page, generate_property_table};
+#[cfg(test)]
+mod tests;
+
+pub use generator::{DocsError, DocsGenerator, GeneratedDoc, StaleDoc, StaleKind};
+pub use html::generate_html_reference;
+pub use markdown::{generate_index_page, generate_module_page, generate_property_table};
 pub use module::{
     BehaviorConfigs, ModuleInfo, ModuleInfoProvider, StylingConfigs, get_all_modules,
 };
 pub use registry::ModuleRegistry;
-pub use schema::{PropertyInfo, extract_property_info};
+pub use roff::{generate_cli_man_page, generate_config_man_page};
+pub use schema::{EnumVariant, PropertyInfo, extract_property_info};
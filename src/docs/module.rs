@@ -1,7 +1,8 @@
 use schemars::Schema;
 
-use crate::config::ClockConfig;
+use super::ModuleRegistry;
 
+/// Function producing the JSON Schema of a configuration section.
 pub type SchemeFn = fn() -> Schema;
 
 /// Collection of styling configuration schemas for a module.
@@ -33,6 +34,12 @@ pub struct ModuleInfo {
     pub icon: String,
     /// Human-readable description of the module's purpose and functionality.
     pub description: String,
+    /// Dotted TOML table path the module is configured under (e.g. "modules.clock").
+    ///
+    /// Behavior sections live at `<config_path>.<name>` and styling sections at
+    /// `<config_path>.styling.<name>`. A section with an empty name documents
+    /// the table itself.
+    pub config_path: String,
     /// Map of behavior component names to their schema generator functions.
     pub behavior_configs: BehaviorConfigs,
     /// Map of styling component names to their schema generator functions.
@@ -48,7 +55,5 @@ pub struct ModuleInfo {
 ///
 /// A vector containing `ModuleInfo` for each available module.
 pub fn get_all_modules() -> Vec<ModuleInfo> {
-    let clock_module = ClockConfig::module_info();
-
-    vec![clock_module]
+    ModuleRegistry::get_all()
 }
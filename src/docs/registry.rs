@@ -1,4 +1,7 @@
-use crate::{config::ClockConfig, docs::ModuleInfoProvider};
+use crate::{
+    config::{AudioConfig, BatteryConfig, ClockConfig, GeneralConfig, MediaConfig},
+    docs::ModuleInfoProvider,
+};
 
 use super::ModuleInfo;
 
@@ -13,9 +16,15 @@ impl ModuleRegistry {
     ///
     /// Collects and returns module metadata including names, descriptions,
     /// icons, and configuration schemas for every module in the system.
+    /// Besides bar modules, this covers the top-level config sections.
     pub fn get_all() -> Vec<ModuleInfo> {
-        let modules: Vec<ModuleInfo> = vec![Self::get_module_info::<ClockConfig>()];
-        modules
+        vec![
+            Self::get_module_info::<GeneralConfig>(),
+            Self::get_module_info::<BatteryConfig>(),
+            Self::get_module_info::<ClockConfig>(),
+            Self::get_module_info::<MediaConfig>(),
+            Self::get_module_info::<AudioConfig>(),
+        ]
     }
 
     /// Retrieves module information by its name.
@@ -0,0 +1,195 @@
+//! Man page rendering in roff format.
+//!
+//! Produces `wayle-config(5)` from module schemas and `wayle(1)` from CLI
+//! command metadata.
+
+use super::{
+    ModuleInfo, PropertyInfo,
+    generator::DocsError,
+    sections::{display_name, example_toml, module_sections},
+};
+use crate::cli::CommandMetadata;
+
+/// Generates the `wayle-config(5)` man page documenting every config section.
+///
+/// # Errors
+///
+/// Returns `DocsError::SchemaConversion` if schema serialization fails.
+pub fn generate_config_man_page(modules: &[ModuleInfo]) -> Result<String, DocsError> {
+    let mut page = title_header("WAYLE-CONFIG", 5, "File Formats Manual");
+
+    page.push_str(
+        ".SH NAME\n\
+         wayle-config \\- configuration file for wayle\n\
+         .SH SYNOPSIS\n\
+         \\fI$XDG_CONFIG_HOME/wayle/config.toml\\fR\n\
+         .SH DESCRIPTION\n\
+         Wayle reads its settings from a TOML file. Every option below is listed by\n\
+         its full dotted path, which can also be passed to \\fBwayle config set\\fR.\n\
+         Options that are not set keep the default shown.\n",
+    );
+
+    for module in modules {
+        page.push_str(&format!(
+            ".SH {}\n{}\n",
+            quote(&display_name(&module.name).to_uppercase()),
+            escape(&module.description)
+        ));
+
+        let sections = module_sections(module)?;
+
+        for section in &sections {
+            page.push_str(&format!(
+                ".SS {}\nTable \\fB[{}]\\fR\n",
+                quote(&section.title),
+                escape(&section.table_path)
+            ));
+
+            for property in &section.properties {
+                page.push_str(&property_entry(&section.table_path, property));
+            }
+        }
+
+        if let Some(example) = example_toml(module, &sections)? {
+            page.push_str(&format!(
+                ".SS Example\n.PP\n.nf\n.RS 4\n{}.RE\n.fi\n",
+                escape_block(&example)
+            ));
+        }
+    }
+
+    page.push_str(".SH SEE ALSO\n\\fBwayle\\fR(1)\n");
+
+    Ok(page)
+}
+
+/// Generates the `wayle(1)` man page from CLI command metadata.
+///
+/// Commands are grouped by category in the order given.
+pub fn generate_cli_man_page(commands: &[CommandMetadata]) -> String {
+    let mut page = title_header("WAYLE", 1, "User Commands");
+
+    page.push_str(
+        ".SH NAME\n\
+         wayle \\- Wayland desktop shell\n\
+         .SH SYNOPSIS\n\
+         \\fBwayle\\fR \\fIcategory\\fR \\fIcommand\\fR [\\fIargs\\fR...]\n\
+         .SH COMMANDS\n",
+    );
+
+    let mut current_category = None;
+
+    for command in commands {
+        if current_category != Some(command.category.as_str()) {
+            page.push_str(&format!(".SS {}\n", quote(&command.category)));
+            current_category = Some(command.category.as_str());
+        }
+
+        page.push_str(&format!(
+            ".TP\n{}\n{}\n",
+            command_synopsis(command),
+            escape(&command.description)
+        ));
+
+        if !command.args.is_empty() {
+            page.push_str(".RS\n");
+            for arg in &command.args {
+                page.push_str(&format!(
+                    ".TP\n\\fI{}\\fR{}\n{}\n",
+                    escape(&arg.name),
+                    if arg.required { "" } else { " (optional)" },
+                    escape(&arg.description)
+                ));
+            }
+            page.push_str(".RE\n");
+        }
+
+        if !command.examples.is_empty() {
+            let examples: String = command
+                .examples
+                .iter()
+                .map(|example| format!("{}\n", escape(example)))
+                .collect();
+            page.push_str(&format!(".IP\n.nf\n{examples}.fi\n"));
+        }
+    }
+
+    page.push_str(".SH SEE ALSO\n\\fBwayle-config\\fR(5)\n");
+
+    page
+}
+
+fn title_header(title: &str, section: u8, manual: &str) -> String {
+    format!(
+        ".TH {title} {section} \"\" \"wayle {}\" \"{manual}\"\n",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+fn property_entry(table_path: &str, property: &PropertyInfo) -> String {
+    let mut entry = format!(
+        ".TP\n\\fB{}.{}\\fR (\\fI{}\\fR)\n{}\n",
+        escape(table_path),
+        escape(&property.name),
+        escape(&property.type_name),
+        escape(&property.description)
+    );
+
+    if !property.variants.is_empty() {
+        entry.push_str(".RS\n");
+        for variant in &property.variants {
+            entry.push_str(&format!(".IP \\(bu 2\n\\fB{}\\fR", escape(&variant.value)));
+            if let Some(description) = &variant.description {
+                entry.push_str(&format!(": {}", escape(description)));
+            }
+            entry.push('\n');
+        }
+        entry.push_str(".RE\n");
+    }
+
+    entry.push_str(&format!(
+        ".IP\nDefault: {}\n",
+        escape(&property.default_value)
+    ));
+
+    entry
+}
+
+fn command_synopsis(command: &CommandMetadata) -> String {
+    let mut synopsis = format!(
+        "\\fBwayle {} {}\\fR",
+        escape(&command.category),
+        escape(&command.name)
+    );
+
+    for arg in &command.args {
+        let name = escape(&arg.name.to_uppercase());
+        if arg.required {
+            synopsis.push_str(&format!(" \\fI{name}\\fR"));
+        } else {
+            synopsis.push_str(&format!(" [\\fI{name}\\fR]"));
+        }
+    }
+
+    synopsis
+}
+
+/// Escapes text for use inside a roff line, folding newlines into spaces.
+fn escape(text: &str) -> String {
+    let escaped = text.replace('\\', "\\e").replace('-', "\\-").replace('\n', " ");
+
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        format!("\\&{escaped}")
+    } else {
+        escaped
+    }
+}
+
+/// Escapes preformatted text line by line, keeping line breaks.
+fn escape_block(text: &str) -> String {
+    text.lines().map(|line| format!("{}\n", escape(line))).collect()
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", escape(text).replace('"', "\\(dq"))
+}
@@ -11,7 +11,8 @@ use serde_json::{Map, Value};
 /// its name, type, description, and default value for documentation purposes.
 #[derive(Debug, Clone)]
 pub struct PropertyInfo {
-    /// The name of the property as defined in the schema.
+    /// Dotted path of the property relative to the schema root
+    /// (e.g. `"format"`, or `"button.icon"` for nested tables).
     pub name: String,
     /// The JSON Schema type of the property (e.g., "string", "number", "boolean").
     pub type_name: String,
@@ -19,12 +20,27 @@ pub struct PropertyInfo {
     pub description: String,
     /// String representation of the property's default value, or "-" if not specified.
     pub default_value: String,
+    /// The raw default value, if the schema specifies one.
+    pub default: Option<Value>,
+    /// Allowed values when the property is an enum, in declaration order.
+    pub variants: Vec<EnumVariant>,
+}
+
+/// A single allowed value of an enum property.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    /// The value as written in TOML.
+    pub value: String,
+    /// Doc comment of the variant, if any.
+    pub description: Option<String>,
 }
 
 /// Extracts property information from a JSON Schema document.
 ///
 /// Parses the "properties" object from a JSON Schema and transforms each property
 /// into a structured `PropertyInfo` representation suitable for documentation generation.
+/// `$ref`s are resolved against the schema's `$defs`, and nested tables are
+/// flattened into dotted property names.
 ///
 /// # Arguments
 ///
@@ -56,44 +72,213 @@ pub struct PropertyInfo {
 /// assert_eq!(properties[0].type_name, "string");
 /// ```
 pub fn extract_property_info(schema: &Value) -> Vec<PropertyInfo> {
-    schema
-        .get("properties")
-        .and_then(|props| props.as_object())
-        .map(build_properties)
-        .unwrap_or_default()
+    let mut properties = Vec::new();
+    collect_properties(schema, schema, "", None, &mut properties);
+    properties
+}
+
+fn collect_properties(
+    root: &Value,
+    schema: &Value,
+    prefix: &str,
+    parent_default: Option<&Value>,
+    properties: &mut Vec<PropertyInfo>,
+) {
+    let Some(props_obj) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+
+    for (name, property) in props_obj {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+
+        let resolved = resolve(root, non_null_variant(root, property));
+
+        let default = property
+            .get("default")
+            .or_else(|| parent_default.and_then(|parent| parent.get(name)))
+            .or_else(|| resolved.get("default"))
+            .cloned();
+
+        if resolved.get("properties").is_some() {
+            collect_properties(root, resolved, &path, default.as_ref(), properties);
+            continue;
+        }
+
+        properties.push(build_property(root, path, property, resolved, default));
+    }
+}
+
+fn build_property(
+    root: &Value,
+    path: String,
+    property: &Value,
+    resolved: &Value,
+    default: Option<Value>,
+) -> PropertyInfo {
+    let variants = get_variants(root, resolved);
+    let mut type_name = if variants.is_empty() {
+        get_type(root, resolved)
+    } else {
+        "enum".to_string()
+    };
+
+    if is_nullable(root, property) {
+        type_name.push_str(" (optional)");
+    }
+
+    PropertyInfo {
+        name: path,
+        type_name,
+        description: get_description(property, resolved),
+        default_value: format_default(default.as_ref()),
+        default,
+        variants,
+    }
+}
+
+/// Follows a local `$ref` (e.g. `#/$defs/ClockStyling`) to its definition.
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+        return schema;
+    };
+
+    reference
+        .strip_prefix("#/")
+        .and_then(|pointer| root.pointer(&format!("/{pointer}")))
+        .map(|target| resolve(root, target))
+        .unwrap_or(schema)
 }
 
-fn build_properties(props_obj: &Map<String, Value>) -> Vec<PropertyInfo> {
-    props_obj
+/// Unwraps `Option<T>` schemas (`anyOf: [T, null]`) to the schema of `T`.
+fn non_null_variant<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    let Some(alternatives) = schema.get("anyOf").and_then(Value::as_array) else {
+        return schema;
+    };
+
+    let non_null: Vec<&Value> = alternatives
         .iter()
-        .map(|(name, property)| PropertyInfo {
-            name: name.clone(),
-            type_name: get_type(property),
-            description: get_description(property),
-            default_value: get_default_value(property),
-        })
-        .collect()
+        .filter(|alternative| !is_null_type(resolve(root, alternative)))
+        .collect();
+
+    match non_null.as_slice() {
+        [single] => single,
+        _ => schema,
+    }
 }
 
-fn get_type(property: &Value) -> String {
-    property
+fn is_nullable(root: &Value, schema: &Value) -> bool {
+    let null_alternative = schema
+        .get("anyOf")
+        .and_then(Value::as_array)
+        .is_some_and(|alternatives| {
+            alternatives
+                .iter()
+                .any(|alternative| is_null_type(resolve(root, alternative)))
+        });
+
+    let null_type = schema
         .get("type")
-        .and_then(|type_of| type_of.as_str())
-        .unwrap_or("unknown")
-        .to_string()
+        .and_then(Value::as_array)
+        .is_some_and(|types| types.iter().any(|t| t == "null"));
+
+    null_alternative || null_type
 }
 
-fn get_description(property: &Value) -> String {
+fn is_null_type(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn get_type(root: &Value, property: &Value) -> String {
+    let type_name = match property.get("type") {
+        Some(Value::String(type_name)) => type_name.clone(),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|type_name| *type_name != "null")
+            .unwrap_or("unknown")
+            .to_string(),
+        _ => return "unknown".to_string(),
+    };
+
+    if type_name != "array" {
+        return type_name;
+    }
+
+    let item_type = property
+        .get("items")
+        .map(|items| {
+            let items = resolve(root, items);
+            if get_variants(root, items).is_empty() {
+                get_type(root, items)
+            } else {
+                "enum".to_string()
+            }
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+
+    format!("array<{item_type}>")
+}
+
+fn get_variants(root: &Value, schema: &Value) -> Vec<EnumVariant> {
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return values
+            .iter()
+            .map(|value| EnumVariant {
+                value: format_enum_value(value),
+                description: None,
+            })
+            .collect();
+    }
+
+    let Some(alternatives) = schema.get("oneOf").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    alternatives
+        .iter()
+        .map(|alternative| resolve(root, alternative))
+        .map(|alternative| {
+            let value = alternative.get("const").or_else(|| {
+                alternative
+                    .get("enum")
+                    .and_then(Value::as_array)
+                    .and_then(|values| values.first())
+            })?;
+
+            Some(EnumVariant {
+                value: format_enum_value(value),
+                description: alternative
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::to_owned),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+}
+
+fn format_enum_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("\"{s}\""),
+        other => other.to_string(),
+    }
+}
+
+fn get_description(property: &Value, resolved: &Value) -> String {
     property
         .get("description")
+        .or_else(|| resolved.get("description"))
         .and_then(|desc| desc.as_str())
         .unwrap_or("No description provided")
         .to_string()
 }
 
-fn get_default_value(property: &Value) -> String {
-    property
-        .get("default")
+fn format_default(default: Option<&Value>) -> String {
+    default
         .map(|def_val| match def_val {
             Value::String(s) => format!("\"{s}\""),
             Value::Bool(b) => b.to_string(),
@@ -102,3 +287,42 @@ fn get_default_value(property: &Value) -> String {
         })
         .unwrap_or("-".to_string())
 }
+
+/// Builds a nested TOML-compatible value from the defaults of the given properties.
+///
+/// Property names are treated as dotted paths below `base_path`. Properties
+/// without a default (or with a `null` default) are skipped.
+pub(super) fn defaults_to_value(base_path: &str, properties: &[PropertyInfo]) -> Map<String, Value> {
+    let mut root = Map::new();
+
+    for property in properties {
+        let Some(default) = property.default.as_ref().filter(|value| !value.is_null()) else {
+            continue;
+        };
+
+        let full_path = if base_path.is_empty() {
+            property.name.clone()
+        } else {
+            format!("{base_path}.{}", property.name)
+        };
+
+        insert_at_path(&mut root, &full_path, default.clone());
+    }
+
+    root
+}
+
+fn insert_at_path(table: &mut Map<String, Value>, path: &str, value: Value) {
+    let Some((head, rest)) = path.split_once('.') else {
+        table.insert(path.to_string(), value);
+        return;
+    };
+
+    let entry = table
+        .entry(head.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+
+    if let Value::Object(child) = entry {
+        insert_at_path(child, rest, value);
+    }
+}
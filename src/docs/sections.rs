@@ -0,0 +1,153 @@
+//! Format-independent view of a module's documentation.
+//!
+//! Every renderer (markdown, man page, HTML) walks the same sections so the
+//! formats never disagree about what is documented.
+
+use serde_json::{Map, Value};
+
+use super::{
+    ModuleInfo, PropertyInfo, extract_property_info, generator::DocsError, module::SchemeFn,
+    schema::defaults_to_value,
+};
+
+/// A documented config table and its properties.
+pub(super) struct DocSection {
+    /// Heading of the section, e.g. "Button Styling".
+    pub title: String,
+    /// Dotted TOML path of the table, e.g. "modules.clock.styling.button".
+    pub table_path: String,
+    /// Properties of the table, with names relative to `table_path`.
+    pub properties: Vec<PropertyInfo>,
+}
+
+/// Collects the behavior sections followed by the styling sections of a module.
+///
+/// Sections without properties are skipped.
+///
+/// # Errors
+///
+/// Returns `DocsError::SchemaConversion` if schema serialization fails.
+pub(super) fn module_sections(module: &ModuleInfo) -> Result<Vec<DocSection>, DocsError> {
+    let styling_path = format!("{}.styling", module.config_path);
+
+    let mut sections = collect_sections(
+        &module.behavior_configs,
+        &module.name,
+        "Behavior",
+        &module.config_path,
+    )?;
+    sections.extend(collect_sections(
+        &module.styling_configs,
+        &module.name,
+        "Styling",
+        &styling_path,
+    )?);
+
+    Ok(sections)
+}
+
+/// Builds a TOML snippet of every documented default, ready to paste into
+/// a config file. Returns `None` when no property has a default.
+///
+/// # Errors
+///
+/// Returns `DocsError::SchemaConversion` if the defaults cannot be rendered.
+pub(super) fn example_toml(
+    module: &ModuleInfo,
+    sections: &[DocSection],
+) -> Result<Option<String>, DocsError> {
+    let mut defaults = Map::new();
+
+    for section in sections {
+        merge_objects(
+            &mut defaults,
+            defaults_to_value(&section.table_path, &section.properties),
+        );
+    }
+
+    if defaults.is_empty() {
+        return Ok(None);
+    }
+
+    toml::to_string_pretty(&defaults)
+        .map(Some)
+        .map_err(|e| DocsError::SchemaConversionError {
+            module: module.name.clone(),
+            details: format!("Failed to render example TOML: {e}"),
+        })
+}
+
+/// Human-readable name of a module, e.g. "power_menu" -> "Power Menu".
+pub(super) fn display_name(name: &str) -> String {
+    name.split('_').map(title_case).collect::<Vec<_>>().join(" ")
+}
+
+/// Whether the module is a bar module rather than a top-level section.
+pub(super) fn is_bar_module(module: &ModuleInfo) -> bool {
+    module.config_path.starts_with("modules.")
+}
+
+fn collect_sections(
+    configs: &[(String, SchemeFn)],
+    module_name: &str,
+    section_type: &str,
+    base_path: &str,
+) -> Result<Vec<DocSection>, DocsError> {
+    let mut sections = Vec::new();
+
+    for (config_name, schema_fn) in configs {
+        let schema_value =
+            serde_json::to_value(schema_fn()).map_err(|e| DocsError::SchemaConversionError {
+                module: module_name.to_string(),
+                details: format!("Failed to generate section for '{config_name}': {e}"),
+            })?;
+
+        let properties = extract_property_info(&schema_value);
+        if properties.is_empty() {
+            continue;
+        }
+
+        let (title, table_path) = if config_name.is_empty() {
+            (section_type.to_string(), base_path.to_string())
+        } else {
+            (
+                format!("{} {}", title_case(config_name), section_type),
+                format!("{base_path}.{config_name}"),
+            )
+        };
+
+        sections.push(DocSection {
+            title,
+            table_path,
+            properties,
+        });
+    }
+
+    Ok(sections)
+}
+
+fn merge_objects(target: &mut Map<String, Value>, source: Map<String, Value>) {
+    for (key, value) in source {
+        match (target.get_mut(&key), value) {
+            (Some(Value::Object(existing)), Value::Object(incoming)) => {
+                merge_objects(existing, incoming);
+            }
+            (_, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+fn title_case(s: &str) -> String {
+    if s.is_empty() {
+        return String::new();
+    }
+    let mut chars = s.chars();
+    chars
+        .next()
+        .unwrap_or_default()
+        .to_uppercase()
+        .chain(chars.as_str().chars())
+        .collect()
+}
@@ -0,0 +1,197 @@
+//! Unit tests for docs module
+//!
+//! Ensures every config section is registered for documentation and that
+//! every documented property carries a description.
+
+#![allow(clippy::panic)]
+
+use schemars::schema_for;
+
+use crate::{
+    config::{Config, ModulesConfig},
+    docs::{
+        DocsGenerator, ModuleRegistry, StaleKind, extract_property_info, generate_config_man_page,
+        generate_html_reference, generate_index_page, generate_property_table,
+    },
+};
+
+fn schema_properties(schema: schemars::Schema) -> Vec<String> {
+    let value = serde_json::to_value(schema).unwrap();
+    let mut fields: Vec<String> = extract_property_info(&value)
+        .into_iter()
+        .map(|property| {
+            let top_level = property.name.split('.').next().unwrap_or_default();
+            top_level.to_string()
+        })
+        .collect();
+    fields.dedup();
+    fields
+}
+
+#[test]
+fn every_modules_config_field_is_documented() {
+    for field in schema_properties(schema_for!(ModulesConfig)) {
+        let Some(module) = ModuleRegistry::get_module_by_name(&field) else {
+            panic!("modules.{field} has no ModuleInfoProvider registered in ModuleRegistry");
+        };
+
+        assert_eq!(module.config_path, format!("modules.{field}"));
+    }
+}
+
+#[test]
+fn every_top_level_section_is_documented() {
+    for field in schema_properties(schema_for!(Config)) {
+        if matches!(field.as_str(), "version" | "modules") {
+            continue;
+        }
+
+        let Some(module) = ModuleRegistry::get_module_by_name(&field) else {
+            panic!("[{field}] has no ModuleInfoProvider registered in ModuleRegistry");
+        };
+
+        assert_eq!(module.config_path, field);
+    }
+}
+
+#[test]
+fn every_documented_property_has_a_description() {
+    for module in ModuleRegistry::get_all() {
+        let sections = module
+            .behavior_configs
+            .iter()
+            .chain(&module.styling_configs);
+
+        for (section, schema_fn) in sections {
+            let value = serde_json::to_value(schema_fn()).unwrap();
+
+            for property in extract_property_info(&value) {
+                assert_ne!(
+                    property.description, "No description provided",
+                    "{}.{section}.{} lacks a doc comment",
+                    module.name, property.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn index_page_links_every_module() {
+    let modules = ModuleRegistry::get_all();
+    let index = generate_index_page(&modules);
+
+    for module in &modules {
+        assert!(index.contains(&format!("({}.md)", module.name)));
+    }
+}
+
+#[test]
+fn nested_and_enum_properties_are_flattened() {
+    let schema = serde_json::json!({
+        "properties": {
+            "button": { "$ref": "#/$defs/Button" }
+        },
+        "$defs": {
+            "Button": {
+                "type": "object",
+                "default": { "position": "left" },
+                "properties": {
+                    "position": {
+                        "description": "Where the icon sits",
+                        "oneOf": [
+                            { "const": "left", "description": "Before the label" },
+                            { "const": "right", "description": "After the label" }
+                        ]
+                    }
+                }
+            }
+        }
+    });
+
+    let properties = extract_property_info(&schema);
+
+    assert_eq!(properties.len(), 1);
+    assert_eq!(properties[0].name, "button.position");
+    assert_eq!(properties[0].type_name, "enum");
+    assert_eq!(properties[0].variants.len(), 2);
+    assert_eq!(properties[0].default_value, "\"left\"");
+}
+
+#[test]
+fn multi_line_variant_docs_stay_in_one_table_row() {
+    let schema = serde_json::json!({
+        "properties": {
+            "policy": {
+                "description": "How the player is chosen",
+                "oneOf": [
+                    { "const": "recent", "description": "Follow playback,\nthen pauses" },
+                    { "const": "manual", "description": "Keep it | until it exits" }
+                ]
+            }
+        }
+    });
+
+    let table = generate_property_table("General", "media", extract_property_info(&schema));
+    let rows: Vec<&str> = table
+        .lines()
+        .skip_while(|line| !line.starts_with("|--"))
+        .collect();
+
+    assert_eq!(rows.len(), 2);
+    assert!(rows[1].contains("Follow playback, then pauses"));
+    assert!(rows[1].contains("Keep it \\| until it exits"));
+}
+
+#[test]
+fn check_reports_missing_and_stale_pages() {
+    let dir = tempfile::tempdir().unwrap();
+    let generator = DocsGenerator::new().with_output_dir(dir.path().to_string_lossy());
+
+    let expected = generator.render_all().unwrap().len();
+    assert_eq!(generator.check().unwrap().len(), expected);
+
+    generator.generate_all().unwrap();
+    assert!(generator.check().unwrap().is_empty());
+
+    let index = dir.path().join("index.md");
+    std::fs::write(&index, "# Outdated\n").unwrap();
+
+    let stale = generator.check().unwrap();
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].path, index);
+    assert_eq!(stale[0].kind, StaleKind::Outdated);
+    assert!(stale[0].diff.contains(&"- # Outdated".to_string()));
+}
+
+#[test]
+fn check_reports_pages_that_are_no_longer_generated() {
+    let dir = tempfile::tempdir().unwrap();
+    let generator = DocsGenerator::new().with_output_dir(dir.path().to_string_lossy());
+    generator.generate_all().unwrap();
+
+    let orphan = dir.path().join("button_styling.md");
+    std::fs::write(&orphan, "# Button Styling\n").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "not a page\n").unwrap();
+
+    let stale = generator.check().unwrap();
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].path, orphan);
+    assert_eq!(stale[0].kind, StaleKind::Orphaned);
+    assert_eq!(stale[0].diff, ["- # Button Styling"]);
+}
+
+#[test]
+fn alternate_formats_anchor_and_list_every_module() {
+    let modules = ModuleRegistry::get_all();
+
+    let man_page = generate_config_man_page(&modules).unwrap();
+    assert!(man_page.starts_with(".TH WAYLE-CONFIG 5"));
+
+    let html = generate_html_reference(&modules).unwrap();
+    assert!(html.contains("id=\"search\""));
+
+    for module in &modules {
+        assert!(html.contains(&format!("id=\"{}\"", module.name)));
+    }
+}
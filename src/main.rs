@@ -45,13 +45,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
 }
 
 async fn run_cli_command(args: &[String]) -> Result<(), Box<dyn Error>> {
-    let config_runtime = ConfigRuntime::load()?;
-    let cli_service = CliService::new(config_runtime);
-
     let category = args.first().map(|s| s.as_str()).unwrap_or("help");
     let command = args.get(1).map(|s| s.as_str()).unwrap_or("");
     let command_args = args.get(2..).unwrap_or(&[]);
 
+    // `config migrate` previews and applies migrations itself
+    let config_runtime = if (category, command) == ("config", "migrate") {
+        ConfigRuntime::load_without_migrations().await?
+    } else {
+        ConfigRuntime::load().await?
+    };
+    let cli_service = CliService::new(config_runtime);
+
     let result = cli_service
         .execute_command(category, command, command_args)
         .await;
@@ -5,12 +5,18 @@ use tracing::{info, instrument, warn};
 
 use crate::config::ConfigPaths;
 
+const STATE_FILE_NAME: &str = "runtime-state.json";
+
 /// Runtime state that persists between CLI calls and is shared with UI
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimeState {
     /// Currently active media player ID
     pub active_media_player: Option<String>,
 
+    /// Currently active configuration profile name
+    #[serde(default)]
+    pub active_profile: Option<String>,
+
     /// Last time this state was updated
     pub last_updated: SystemTime,
 }
@@ -19,6 +25,7 @@ impl Default for RuntimeState {
     fn default() -> Self {
         Self {
             active_media_player: None,
+            active_profile: None,
             last_updated: SystemTime::now(),
         }
     }
@@ -27,7 +34,7 @@ impl Default for RuntimeState {
 impl RuntimeState {
     fn state_file_path() -> Result<PathBuf, Error> {
         let config_dir = ConfigPaths::config_dir().map_err(Error::other)?;
-        Ok(config_dir.join("runtime-state.json"))
+        Ok(config_dir.join(STATE_FILE_NAME))
     }
 
     /// Load runtime state from file, or create default if not exists
@@ -91,4 +98,31 @@ impl RuntimeState {
 
         Ok(())
     }
+
+    /// Get the currently active configuration profile
+    ///
+    /// # Errors
+    /// Returns error if state file cannot be loaded
+    pub async fn get_active_profile() -> Result<Option<String>, Error> {
+        let state = Self::load().await?;
+        Ok(state.active_profile)
+    }
+
+    /// Set the active configuration profile and persist to file
+    ///
+    /// # Errors
+    /// Returns error if state cannot be loaded or saved
+    pub async fn set_active_profile(profile: Option<String>) -> Result<(), Error> {
+        let mut state = Self::load().await?;
+        state.active_profile = profile;
+        state.last_updated = SystemTime::now();
+        state.save().await?;
+
+        Ok(())
+    }
+
+    /// Returns the file name used to persist runtime state
+    pub fn state_file_name() -> &'static str {
+        STATE_FILE_NAME
+    }
 }
@@ -1,17 +1,22 @@
 use std::sync::Arc;
 
-use libpulse_binding::{context::Context, volume::ChannelVolumes};
+use libpulse_binding::context::Context;
 
 use crate::services::{
-    DeviceType, StreamType,
-    audio::{device::DeviceKey, stream::StreamKey},
+    DeviceType, StreamType, Volume,
+    audio::{card::CardIndex, device::DeviceKey, stream::StreamKey},
 };
 
 use super::{
-    discovery::{trigger_device_discovery, trigger_server_info_query, trigger_stream_discovery},
+    conversion::convert_volume_to_pulse,
+    discovery::{
+        trigger_card_discovery, trigger_device_discovery, trigger_server_info_query,
+        trigger_stream_discovery,
+    },
+    peak::{PeakStreams, refresh_peak_monitor, start_peak_monitor, stop_peak_monitor},
     types::{
-        DefaultDevice, DeviceListSender, DeviceStore, EventSender, ExternalCommand,
-        InternalCommand, StreamListSender, StreamStore,
+        CardListSender, CardStore, DefaultDevice, DeviceListSender, DeviceStore, EventSender,
+        ExternalCommand, InternalCommand, InternalCommandSender, StreamListSender, StreamStore,
     },
 };
 
@@ -22,9 +27,12 @@ pub fn handle_internal_command(
     command: InternalCommand,
     devices: &DeviceStore,
     streams: &StreamStore,
+    cards: &CardStore,
+    peak_streams: &mut PeakStreams,
     events_tx: &EventSender,
     device_list_tx: &DeviceListSender,
     stream_list_tx: &StreamListSender,
+    card_list_tx: &CardListSender,
     default_input: &DefaultDevice,
     default_output: &DefaultDevice,
 ) {
@@ -38,6 +46,12 @@ pub fn handle_internal_command(
         InternalCommand::RefreshServerInfo => {
             trigger_server_info_query(context, devices, events_tx, default_input, default_output);
         }
+        InternalCommand::RefreshCards => {
+            trigger_card_discovery(context, cards, card_list_tx, events_tx);
+        }
+        InternalCommand::RefreshPeakMonitor(target) => {
+            refresh_peak_monitor(target, devices, streams, peak_streams);
+        }
     }
 }
 
@@ -47,14 +61,22 @@ pub fn handle_external_command(
     command: ExternalCommand,
     devices: &DeviceStore,
     streams: &StreamStore,
+    peak_streams: &mut PeakStreams,
+    internal_command_tx: &InternalCommandSender,
 ) {
     match command {
         ExternalCommand::SetDeviceVolume { device_key, volume } => {
-            set_device_volume(context, device_key, volume, devices);
+            set_device_volume(context, device_key, &volume, devices);
         }
         ExternalCommand::SetDeviceMute { device_key, muted } => {
             set_device_mute(context, device_key, muted, devices);
         }
+        ExternalCommand::SetDevicePort {
+            device_key,
+            port_name,
+        } => {
+            set_device_port(context, device_key, &port_name);
+        }
         ExternalCommand::SetDefaultInput { device_key } => {
             set_default_input(context, device_key, devices);
         }
@@ -62,7 +84,7 @@ pub fn handle_external_command(
             set_default_output(context, device_key, devices);
         }
         ExternalCommand::SetStreamVolume { stream_key, volume } => {
-            set_stream_volume(context, stream_key, volume, streams);
+            set_stream_volume(context, stream_key, &volume, streams);
         }
         ExternalCommand::SetStreamMute { stream_key, muted } => {
             set_stream_mute(context, stream_key, muted, streams);
@@ -73,6 +95,31 @@ pub fn handle_external_command(
         } => {
             move_stream(context, stream_key, device_key, streams);
         }
+        ExternalCommand::SetCardProfile {
+            card_index,
+            profile_name,
+        } => {
+            set_card_profile(context, card_index, &profile_name);
+        }
+        ExternalCommand::StartPeakMonitor {
+            target,
+            rate,
+            sender,
+        } => {
+            start_peak_monitor(
+                context,
+                target,
+                rate,
+                sender,
+                devices,
+                streams,
+                peak_streams,
+                internal_command_tx,
+            );
+        }
+        ExternalCommand::StopPeakMonitor { target } => {
+            stop_peak_monitor(target, peak_streams);
+        }
         ExternalCommand::Shutdown => {
             // Shutdown handled in main loop
         }
@@ -82,9 +129,10 @@ pub fn handle_external_command(
 fn set_device_volume(
     context: &Context,
     device_key: DeviceKey,
-    volume: ChannelVolumes,
+    volume: &Volume,
     devices: &DeviceStore,
 ) {
+    let volume = convert_volume_to_pulse(volume);
     let devices_clone = Arc::clone(devices);
     let mut introspect = context.introspect();
 
@@ -138,6 +186,19 @@ fn set_device_mute(context: &Context, device_key: DeviceKey, muted: bool, device
     }
 }
 
+fn set_device_port(context: &Context, device_key: DeviceKey, port_name: &str) {
+    let mut introspect = context.introspect();
+
+    match device_key.device_type {
+        DeviceType::Output => {
+            introspect.set_sink_port_by_index(device_key.index, port_name, None);
+        }
+        DeviceType::Input => {
+            introspect.set_source_port_by_index(device_key.index, port_name, None);
+        }
+    }
+}
+
 fn set_default_input(context: &mut Context, device_key: DeviceKey, devices: &DeviceStore) {
     if let Ok(devices_guard) = devices.read() {
         if let Some(device_info) = devices_guard.values().find(|d| d.key == device_key) {
@@ -157,9 +218,10 @@ fn set_default_output(context: &mut Context, device_key: DeviceKey, devices: &De
 fn set_stream_volume(
     context: &Context,
     stream_key: StreamKey,
-    volume: ChannelVolumes,
+    volume: &Volume,
     streams: &StreamStore,
 ) {
+    let volume = convert_volume_to_pulse(volume);
     let streams_clone = Arc::clone(streams);
     let mut introspect = context.introspect();
 
@@ -235,3 +297,8 @@ fn move_stream(
         }
     }
 }
+
+fn set_card_profile(context: &Context, card_index: CardIndex, profile_name: &str) {
+    let mut introspect = context.introspect();
+    introspect.set_card_profile_by_index(card_index.0, profile_name, None);
+}
@@ -1,7 +1,11 @@
 use std::borrow::Cow;
 
 use libpulse_binding::{
-    context::introspect::{SinkInfo, SinkInputInfo, SourceInfo, SourceOutputInfo},
+    channelmap::{Map as ChannelMap, Position as PulsePosition},
+    context::introspect::{
+        CardInfo as PulseCardInfo, CardProfileInfo, SinkInfo, SinkInputInfo, SourceInfo,
+        SourceOutputInfo,
+    },
     def::PortAvailable,
     sample::Format as PulseFormat,
     volume::{ChannelVolumes, Volume as PulseVolume},
@@ -10,8 +14,10 @@ use libpulse_binding::{
 use crate::services::{
     DeviceIndex, DeviceInfo, DeviceType, StreamIndex, StreamInfo, StreamType, Volume,
     audio::{
+        card::{CardIndex, CardInfo, CardProfile},
         device::{DeviceName, DevicePort, DeviceState},
         stream::{SampleFormat, StreamFormat, StreamKey, StreamState},
+        volume::ChannelPosition,
     },
 };
 
@@ -21,17 +27,19 @@ use crate::services::{
 /// - 0.0 → PA_VOLUME_MUTED (0)
 /// - 1.0 → PA_VOLUME_NORM (65536)
 /// - 4.0 → PA_VOLUME_MAX (262144)
+///
+/// Each channel is converted separately so balance survives the round trip.
 pub fn convert_volume_to_pulse(volume: &Volume) -> ChannelVolumes {
     let channels = volume.channels();
     if channels == 0 {
         return ChannelVolumes::default();
     }
 
-    let avg_level = volume.average();
-    let pulse_vol = PulseVolume((avg_level * PulseVolume::NORMAL.0 as f64) as u32);
-
     let mut pulse_volume = ChannelVolumes::default();
-    pulse_volume.set(channels as u8, pulse_vol);
+    pulse_volume.set_len(channels as u8);
+    for (pulse_vol, &level) in pulse_volume.get_mut().iter_mut().zip(volume.as_slice()) {
+        *pulse_vol = PulseVolume((level * PulseVolume::NORMAL.0 as f64) as u32);
+    }
 
     pulse_volume
 }
@@ -53,6 +61,59 @@ pub fn convert_volume_from_pulse(pulse_volume: &ChannelVolumes) -> Volume {
     Volume::new(volumes)
 }
 
+/// Convert PulseAudio volume to our volume, keeping its channel positions
+///
+/// Falls back to the default layout if the channel map does not match the
+/// volume's channel count.
+pub fn convert_volume_from_pulse_with_map(
+    pulse_volume: &ChannelVolumes,
+    channel_map: &ChannelMap,
+) -> Volume {
+    let volume = convert_volume_from_pulse(pulse_volume);
+    let positions = convert_channel_map(channel_map);
+    Volume::with_positions(volume.as_slice().to_vec(), positions).unwrap_or(volume)
+}
+
+/// Convert a PulseAudio channel map to our channel positions
+pub fn convert_channel_map(channel_map: &ChannelMap) -> Vec<ChannelPosition> {
+    channel_map
+        .get()
+        .iter()
+        .map(|&position| convert_channel_position(position))
+        .collect()
+}
+
+/// Convert a PulseAudio channel position to ours
+pub fn convert_channel_position(position: PulsePosition) -> ChannelPosition {
+    match position {
+        PulsePosition::Mono => ChannelPosition::Mono,
+        PulsePosition::FrontLeft => ChannelPosition::FrontLeft,
+        PulsePosition::FrontRight => ChannelPosition::FrontRight,
+        PulsePosition::FrontCenter => ChannelPosition::FrontCenter,
+        PulsePosition::RearLeft => ChannelPosition::RearLeft,
+        PulsePosition::RearRight => ChannelPosition::RearRight,
+        PulsePosition::RearCenter => ChannelPosition::RearCenter,
+        PulsePosition::Lfe => ChannelPosition::Lfe,
+        PulsePosition::FrontLeftOfCenter => ChannelPosition::FrontLeftOfCenter,
+        PulsePosition::FrontRightOfCenter => ChannelPosition::FrontRightOfCenter,
+        PulsePosition::SideLeft => ChannelPosition::SideLeft,
+        PulsePosition::SideRight => ChannelPosition::SideRight,
+        PulsePosition::TopCenter => ChannelPosition::TopCenter,
+        PulsePosition::TopFrontLeft => ChannelPosition::TopFrontLeft,
+        PulsePosition::TopFrontRight => ChannelPosition::TopFrontRight,
+        PulsePosition::TopFrontCenter => ChannelPosition::TopFrontCenter,
+        PulsePosition::TopRearLeft => ChannelPosition::TopRearLeft,
+        PulsePosition::TopRearRight => ChannelPosition::TopRearRight,
+        PulsePosition::TopRearCenter => ChannelPosition::TopRearCenter,
+        aux if (PulsePosition::Aux0 as i32..=PulsePosition::Aux31 as i32)
+            .contains(&(aux as i32)) =>
+        {
+            ChannelPosition::Aux((aux as i32 - PulsePosition::Aux0 as i32) as u8)
+        }
+        _ => ChannelPosition::Unknown,
+    }
+}
+
 /// Convert PulseAudio sample format to our format
 pub fn convert_sample_format(format: PulseFormat) -> SampleFormat {
     match format {
@@ -75,7 +136,7 @@ fn cow_str_to_string(cow_str: Option<&Cow<str>>) -> String {
 
 /// Create device info from PulseAudio sink information
 pub fn create_device_info_from_sink(sink_info: &SinkInfo) -> DeviceInfo {
-    let volume = convert_volume_from_pulse(&sink_info.volume);
+    let volume = convert_volume_from_pulse_with_map(&sink_info.volume, &sink_info.channel_map);
     let name = cow_str_to_string(sink_info.name.as_ref());
     let description = cow_str_to_string(sink_info.description.as_ref());
     let ports: Vec<DevicePort> = sink_info
@@ -108,7 +169,7 @@ pub fn create_device_info_from_sink(sink_info: &SinkInfo) -> DeviceInfo {
 
 /// Create device info from PulseAudio source information
 pub fn create_device_info_from_source(source_info: &SourceInfo) -> DeviceInfo {
-    let volume = convert_volume_from_pulse(&source_info.volume);
+    let volume = convert_volume_from_pulse_with_map(&source_info.volume, &source_info.channel_map);
     let name = cow_str_to_string(source_info.name.as_ref());
     let description = cow_str_to_string(source_info.description.as_ref());
     let ports: Vec<DevicePort> = source_info
@@ -141,12 +202,17 @@ pub fn create_device_info_from_source(source_info: &SourceInfo) -> DeviceInfo {
 
 /// Create stream info from PulseAudio sink input information
 pub fn create_stream_info_from_sink_input(sink_input_info: &SinkInputInfo) -> StreamInfo {
-    let volume = convert_volume_from_pulse(&sink_input_info.volume);
+    let volume =
+        convert_volume_from_pulse_with_map(&sink_input_info.volume, &sink_input_info.channel_map);
     let name = sink_input_info.name.clone().unwrap_or_default().to_string();
     let application_name = sink_input_info
         .proplist
         .get_str("application.name")
         .unwrap_or_default();
+    let application_binary = sink_input_info
+        .proplist
+        .get_str("application.process.binary")
+        .unwrap_or_default();
     let format = StreamFormat {
         sample_rate: sink_input_info.sample_spec.rate,
         channels: sink_input_info.sample_spec.channels,
@@ -157,6 +223,7 @@ pub fn create_stream_info_from_sink_input(sink_input_info: &SinkInputInfo) -> St
         index: StreamIndex(sink_input_info.index),
         name,
         application_name,
+        application_binary,
         stream_type: StreamType::Playback,
         state: StreamState::Running,
         device_index: DeviceIndex(sink_input_info.sink),
@@ -172,7 +239,10 @@ pub fn create_stream_info_from_sink_input(sink_input_info: &SinkInputInfo) -> St
 
 /// Create stream info from PulseAudio source output information
 pub fn create_stream_info_from_source_output(source_output_info: &SourceOutputInfo) -> StreamInfo {
-    let volume = convert_volume_from_pulse(&source_output_info.volume);
+    let volume = convert_volume_from_pulse_with_map(
+        &source_output_info.volume,
+        &source_output_info.channel_map,
+    );
     let name = source_output_info
         .name
         .clone()
@@ -182,6 +252,10 @@ pub fn create_stream_info_from_source_output(source_output_info: &SourceOutputIn
         .proplist
         .get_str("application.name")
         .unwrap_or_default();
+    let application_binary = source_output_info
+        .proplist
+        .get_str("application.process.binary")
+        .unwrap_or_default();
     let format = StreamFormat {
         sample_rate: source_output_info.sample_spec.rate,
         channels: source_output_info.sample_spec.channels,
@@ -192,6 +266,7 @@ pub fn create_stream_info_from_source_output(source_output_info: &SourceOutputIn
         index: StreamIndex(source_output_info.index),
         name,
         application_name,
+        application_binary,
         stream_type: StreamType::Record,
         state: StreamState::Running,
         device_index: DeviceIndex(source_output_info.source),
@@ -204,3 +279,40 @@ pub fn create_stream_info_from_source_output(source_output_info: &SourceOutputIn
         },
     }
 }
+
+fn convert_card_profile(profile: &CardProfileInfo) -> CardProfile {
+    CardProfile {
+        name: cow_str_to_string(profile.name.as_ref()),
+        description: cow_str_to_string(profile.description.as_ref()),
+        priority: profile.priority,
+        available: profile.available,
+        sinks: profile.n_sinks,
+        sources: profile.n_sources,
+    }
+}
+
+/// Create card info from PulseAudio card information
+pub fn create_card_info(card_info: &PulseCardInfo) -> CardInfo {
+    let name = cow_str_to_string(card_info.name.as_ref());
+    let description = card_info
+        .proplist
+        .get_str("device.description")
+        .unwrap_or_else(|| name.clone());
+    let active_profile = card_info
+        .active_profile
+        .as_ref()
+        .and_then(|p| p.name.as_ref().map(|s| s.to_string()));
+
+    CardInfo {
+        index: CardIndex(card_info.index),
+        name,
+        description,
+        driver: cow_str_to_string(card_info.driver.as_ref()),
+        profiles: card_info
+            .profiles
+            .iter()
+            .map(convert_card_profile)
+            .collect(),
+        active_profile,
+    }
+}
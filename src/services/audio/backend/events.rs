@@ -5,14 +5,14 @@ use libpulse_binding::context::{
 use tokio::sync::mpsc;
 
 use crate::services::{
-    AudioEvent, DeviceType, AudioError, StreamType,
-    audio::{device::DeviceKey, stream::StreamKey},
+    AudioError, AudioEvent, DeviceType, StreamType,
+    audio::{card::CardIndex, device::DeviceKey, peak::PeakTarget, stream::StreamKey},
 };
 
-use super::super::discovery::{broadcast_device_list, broadcast_stream_list};
+use super::super::discovery::{broadcast_card_list, broadcast_device_list, broadcast_stream_list};
 use super::types::{
-    ChangeNotification, DefaultDevice, DeviceListSender, DeviceStore, EventSender, InternalCommand,
-    InternalCommandSender, StreamListSender, StreamStore,
+    CardListSender, CardStore, ChangeNotification, DefaultDevice, DeviceListSender, DeviceStore,
+    EventSender, InternalCommand, InternalCommandSender, StreamListSender, StreamStore,
 };
 
 type SubscriptionCallback = Option<Box<dyn FnMut(Option<Facility>, Option<Operation>, u32)>>;
@@ -30,7 +30,8 @@ pub fn setup_event_subscription(
         | InterestMaskSet::SOURCE
         | InterestMaskSet::SINK_INPUT
         | InterestMaskSet::SOURCE_OUTPUT
-        | InterestMaskSet::SERVER;
+        | InterestMaskSet::SERVER
+        | InterestMaskSet::CARD;
 
     let subscription_callback: SubscriptionCallback =
         Some(Box::new(move |facility, operation, index| {
@@ -49,6 +50,10 @@ pub fn setup_event_subscription(
                         index,
                     })
                 }
+                (Some(Facility::Card), Some(oper)) => Some(ChangeNotification::Card {
+                    operation: oper,
+                    index,
+                }),
                 (Some(facil @ Facility::Server), Some(oper)) => Some(ChangeNotification::Server {
                     facility: facil,
                     operation: oper,
@@ -75,11 +80,13 @@ pub async fn process_change_notification(
     notification: ChangeNotification,
     devices: &DeviceStore,
     streams: &StreamStore,
+    cards: &CardStore,
     _default_input: &DefaultDevice,
     _default_output: &DefaultDevice,
     events_tx: &EventSender,
     device_list_tx: &DeviceListSender,
     stream_list_tx: &StreamListSender,
+    card_list_tx: &CardListSender,
     command_tx: &InternalCommandSender,
 ) {
     match notification {
@@ -115,6 +122,9 @@ pub async fn process_change_notification(
             )
             .await;
         }
+        ChangeNotification::Card { operation, index } => {
+            handle_card_change(operation, index, cards, events_tx, card_list_tx, command_tx).await;
+        }
         ChangeNotification::Server {
             facility,
             operation,
@@ -161,6 +171,9 @@ async fn handle_device_change(
             if let Some(device_info) = removed_device {
                 let _ = events_tx.send(AudioEvent::DeviceRemoved(device_info));
             }
+            let _ = command_tx.send(InternalCommand::RefreshPeakMonitor(PeakTarget::Device(
+                device_key,
+            )));
             broadcast_device_list(device_list_tx, devices);
         }
         Operation::New => {
@@ -205,6 +218,9 @@ async fn handle_stream_change(
             if let Some(stream_info) = removed_stream {
                 let _ = events_tx.send(AudioEvent::StreamRemoved(stream_info));
             }
+            let _ = command_tx.send(InternalCommand::RefreshPeakMonitor(PeakTarget::Stream(
+                stream_key,
+            )));
             broadcast_stream_list(stream_list_tx, streams);
         }
         Operation::New => {
@@ -218,6 +234,33 @@ async fn handle_stream_change(
     }
 }
 
+async fn handle_card_change(
+    operation: Operation,
+    index: u32,
+    cards: &CardStore,
+    events_tx: &EventSender,
+    card_list_tx: &CardListSender,
+    command_tx: &InternalCommandSender,
+) {
+    match operation {
+        Operation::Removed => {
+            let removed_card = if let Ok(mut cards_guard) = cards.write() {
+                cards_guard.remove(&CardIndex(index))
+            } else {
+                None
+            };
+
+            if let Some(card_info) = removed_card {
+                let _ = events_tx.send(AudioEvent::CardRemoved(card_info));
+            }
+            broadcast_card_list(card_list_tx, cards);
+        }
+        Operation::New | Operation::Changed => {
+            let _ = command_tx.send(InternalCommand::RefreshCards);
+        }
+    }
+}
+
 async fn handle_server_change(
     _facility: Facility,
     operation: Operation,
@@ -0,0 +1,530 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::{
+    sync::{broadcast, mpsc, oneshot},
+    task::JoinHandle,
+};
+use tracing::warn;
+
+use crate::services::{
+    AudioError, AudioEvent, DeviceInfo, DeviceType, StreamInfo, Volume,
+    audio::{
+        card::{CardIndex, CardInfo},
+        connection::ConnectionState,
+        device::{DeviceIndex, DeviceKey},
+        discovery::{sync_cards, sync_devices, sync_streams},
+        peak::PeakTarget,
+        stream::StreamKey,
+    },
+};
+
+use super::{
+    traits::{AudioBackend, BackendContext},
+    types::{DefaultDevice, DeviceStore, EventSender, ExternalCommand},
+};
+
+type FakeActionSender = mpsc::UnboundedSender<(FakeAction, oneshot::Sender<()>)>;
+type FakeActionReceiver = mpsc::UnboundedReceiver<(FakeAction, oneshot::Sender<()>)>;
+
+/// Server-side change scripted through a [`FakeBackendHandle`]
+#[derive(Debug)]
+enum FakeAction {
+    InsertDevice(DeviceInfo),
+    RemoveDevice(DeviceKey),
+    InsertStream(StreamInfo),
+    RemoveStream(StreamKey),
+    InsertCard(CardInfo),
+    RemoveCard(CardIndex),
+    SetDefaultDevice(DeviceKey),
+    EmitPeak { target: PeakTarget, level: f32 },
+    PeakCommands(oneshot::Sender<Vec<FakePeakCommand>>),
+    Disconnect,
+    Reconnect,
+}
+
+/// Peak monitor command received by a [`FakeBackend`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FakePeakCommand {
+    /// Monitoring of the target started at `rate` levels per second
+    Start {
+        /// Monitored device or stream
+        target: PeakTarget,
+        /// Levels per second
+        rate: u32,
+    },
+    /// Monitoring of the target stopped
+    Stop {
+        /// Monitored device or stream
+        target: PeakTarget,
+    },
+}
+
+/// State of the simulated sound server
+#[derive(Debug, Default)]
+struct FakeServer {
+    devices: HashMap<DeviceKey, DeviceInfo>,
+    streams: HashMap<StreamKey, StreamInfo>,
+    cards: HashMap<CardIndex, CardInfo>,
+    default_input: Option<DeviceKey>,
+    default_output: Option<DeviceKey>,
+}
+
+impl FakeServer {
+    fn default_slot(&mut self, device_type: DeviceType) -> &mut Option<DeviceKey> {
+        match device_type {
+            DeviceType::Input => &mut self.default_input,
+            DeviceType::Output => &mut self.default_output,
+        }
+    }
+}
+
+/// In-memory backend simulating a sound server
+///
+/// Devices, streams and cards live in memory and service commands are
+/// applied to them directly, producing the same [`AudioEvent`]s as the
+/// PulseAudio backend. Server-side changes such as hotplug are scripted
+/// through the [`FakeBackendHandle`] returned by [`FakeBackend::new`].
+#[derive(Debug)]
+pub struct FakeBackend {
+    server: FakeServer,
+    actions_rx: FakeActionReceiver,
+}
+
+impl FakeBackend {
+    /// Create an empty fake backend and the handle scripting it
+    pub fn new() -> (Self, FakeBackendHandle) {
+        let (actions_tx, actions_rx) = mpsc::unbounded_channel();
+
+        let backend = Self {
+            server: FakeServer::default(),
+            actions_rx,
+        };
+
+        (backend, FakeBackendHandle { actions_tx })
+    }
+
+    /// Start with the device present
+    pub fn with_device(mut self, device: DeviceInfo) -> Self {
+        self.server.devices.insert(device.key, device);
+        self
+    }
+
+    /// Start with the stream present
+    pub fn with_stream(mut self, stream: StreamInfo) -> Self {
+        self.server.streams.insert(stream.key, stream);
+        self
+    }
+
+    /// Start with the card present
+    pub fn with_card(mut self, card: CardInfo) -> Self {
+        self.server.cards.insert(card.index, card);
+        self
+    }
+
+    /// Start with the device as the default for its type
+    pub fn with_default_device(mut self, device_key: DeviceKey) -> Self {
+        *self.server.default_slot(device_key.device_type) = Some(device_key);
+        self
+    }
+}
+
+#[async_trait]
+impl AudioBackend for FakeBackend {
+    async fn spawn(self, context: BackendContext) -> Result<JoinHandle<()>, AudioError> {
+        let task = FakeTask {
+            server: self.server,
+            context,
+            connected: true,
+            peak_senders: HashMap::new(),
+            peak_commands: Vec::new(),
+        };
+
+        task.sync_all();
+        task.context
+            .connection_state
+            .set(ConnectionState::Connected);
+
+        Ok(tokio::spawn(task.run(self.actions_rx)))
+    }
+}
+
+/// Scripts server-side changes on a running [`FakeBackend`]
+///
+/// Each method resolves once the change has been applied and its events
+/// published, or immediately if the backend has stopped.
+#[derive(Debug, Clone)]
+pub struct FakeBackendHandle {
+    actions_tx: FakeActionSender,
+}
+
+impl FakeBackendHandle {
+    /// Add a device, or replace the device with the same key
+    pub async fn insert_device(&self, device: DeviceInfo) {
+        self.apply(FakeAction::InsertDevice(device)).await;
+    }
+
+    /// Remove a device, as when it is unplugged
+    pub async fn remove_device(&self, device_key: DeviceKey) {
+        self.apply(FakeAction::RemoveDevice(device_key)).await;
+    }
+
+    /// Add a stream, or replace the stream with the same key
+    pub async fn insert_stream(&self, stream: StreamInfo) {
+        self.apply(FakeAction::InsertStream(stream)).await;
+    }
+
+    /// Remove a stream, as when an application stops playing
+    pub async fn remove_stream(&self, stream_key: StreamKey) {
+        self.apply(FakeAction::RemoveStream(stream_key)).await;
+    }
+
+    /// Add a card, or replace the card with the same index
+    pub async fn insert_card(&self, card: CardInfo) {
+        self.apply(FakeAction::InsertCard(card)).await;
+    }
+
+    /// Remove a card
+    pub async fn remove_card(&self, card_index: CardIndex) {
+        self.apply(FakeAction::RemoveCard(card_index)).await;
+    }
+
+    /// Make the device the server default for its type, as another client
+    /// such as `pactl` would
+    pub async fn set_default_device(&self, device_key: DeviceKey) {
+        self.apply(FakeAction::SetDefaultDevice(device_key)).await;
+    }
+
+    /// Publish a peak level to monitors of the target
+    pub async fn emit_peak(&self, target: PeakTarget, level: f32) {
+        self.apply(FakeAction::EmitPeak { target, level }).await;
+    }
+
+    /// Peak monitor commands received so far, oldest first
+    ///
+    /// Commands the service sent before this call are always included.
+    pub async fn peak_commands(&self) -> Vec<FakePeakCommand> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.apply(FakeAction::PeakCommands(reply_tx)).await;
+        reply_rx.await.unwrap_or_default()
+    }
+
+    /// Simulate the server going away
+    ///
+    /// Until [`reconnect`](Self::reconnect), service commands are dropped and
+    /// scripted changes only reach the simulated server.
+    pub async fn disconnect(&self) {
+        self.apply(FakeAction::Disconnect).await;
+    }
+
+    /// Simulate the server coming back, reporting everything that changed
+    /// while it was away
+    pub async fn reconnect(&self) {
+        self.apply(FakeAction::Reconnect).await;
+    }
+
+    async fn apply(&self, action: FakeAction) {
+        let (done_tx, done_rx) = oneshot::channel();
+        if self.actions_tx.send((action, done_tx)).is_ok() {
+            let _ = done_rx.await;
+        }
+    }
+}
+
+struct FakeTask {
+    server: FakeServer,
+    context: BackendContext,
+    connected: bool,
+    peak_senders: HashMap<PeakTarget, broadcast::Sender<f32>>,
+    peak_commands: Vec<FakePeakCommand>,
+}
+
+impl FakeTask {
+    async fn run(mut self, mut actions_rx: FakeActionReceiver) {
+        loop {
+            tokio::select! {
+                biased;
+
+                command = self.context.command_rx.recv() => match command {
+                    Some(ExternalCommand::Shutdown) | None => break,
+                    Some(command) if !self.connected => {
+                        warn!("Dropping {command:?} while disconnected");
+                    }
+                    Some(command) => self.handle_command(command),
+                },
+                Some((action, done_tx)) = actions_rx.recv() => {
+                    self.apply(action);
+                    let _ = done_tx.send(());
+                }
+            }
+        }
+
+        self.context
+            .connection_state
+            .set(ConnectionState::Disconnected);
+    }
+
+    fn handle_command(&mut self, command: ExternalCommand) {
+        match command {
+            ExternalCommand::SetDeviceVolume { device_key, volume } => {
+                if let Some(device) = self.server.devices.get_mut(&device_key) {
+                    device.volume = keep_positions(&device.volume, &volume);
+                }
+                self.sync_devices();
+            }
+            ExternalCommand::SetDeviceMute { device_key, muted } => {
+                if let Some(device) = self.server.devices.get_mut(&device_key) {
+                    device.muted = muted;
+                }
+                self.sync_devices();
+            }
+            ExternalCommand::SetDevicePort {
+                device_key,
+                port_name,
+            } => {
+                if let Some(device) = self.server.devices.get_mut(&device_key)
+                    && device.port(&port_name).is_some()
+                {
+                    device.active_port = Some(port_name);
+                }
+                self.sync_devices();
+            }
+            ExternalCommand::SetStreamVolume { stream_key, volume } => {
+                if let Some(stream) = self.server.streams.get_mut(&stream_key) {
+                    stream.volume = keep_positions(&stream.volume, &volume);
+                }
+                self.sync_streams();
+            }
+            ExternalCommand::SetStreamMute { stream_key, muted } => {
+                if let Some(stream) = self.server.streams.get_mut(&stream_key) {
+                    stream.muted = muted;
+                }
+                self.sync_streams();
+            }
+            ExternalCommand::SetDefaultInput { device_key } => {
+                self.set_default(DeviceType::Input, device_key);
+            }
+            ExternalCommand::SetDefaultOutput { device_key } => {
+                self.set_default(DeviceType::Output, device_key);
+            }
+            ExternalCommand::MoveStream {
+                stream_key,
+                device_key,
+            } => {
+                if self.server.devices.contains_key(&device_key)
+                    && let Some(stream) = self.server.streams.get_mut(&stream_key)
+                {
+                    stream.device_index = DeviceIndex(device_key.index);
+                }
+                self.sync_streams();
+            }
+            ExternalCommand::SetCardProfile {
+                card_index,
+                profile_name,
+            } => {
+                if let Some(card) = self.server.cards.get_mut(&card_index)
+                    && card.profile(&profile_name).is_some()
+                {
+                    card.active_profile = Some(profile_name);
+                }
+                self.sync_cards();
+            }
+            ExternalCommand::StartPeakMonitor {
+                target,
+                rate,
+                sender,
+            } => {
+                self.peak_commands.push(FakePeakCommand::Start { target, rate });
+                if self.target_exists(target) {
+                    self.peak_senders.insert(target, sender);
+                }
+            }
+            ExternalCommand::StopPeakMonitor { target } => {
+                self.peak_commands.push(FakePeakCommand::Stop { target });
+                self.peak_senders.remove(&target);
+            }
+            ExternalCommand::Shutdown => {
+                // Shutdown handled in run loop
+            }
+        }
+    }
+
+    fn apply(&mut self, action: FakeAction) {
+        match action {
+            FakeAction::InsertDevice(device) => {
+                self.server.devices.insert(device.key, device);
+                self.sync_devices();
+            }
+            FakeAction::RemoveDevice(device_key) => {
+                self.server.devices.remove(&device_key);
+                self.peak_senders.remove(&PeakTarget::Device(device_key));
+                let default_slot = self.server.default_slot(device_key.device_type);
+                if *default_slot == Some(device_key) {
+                    *default_slot = None;
+                }
+                self.sync_devices();
+            }
+            FakeAction::InsertStream(stream) => {
+                self.server.streams.insert(stream.key, stream);
+                self.sync_streams();
+            }
+            FakeAction::RemoveStream(stream_key) => {
+                self.server.streams.remove(&stream_key);
+                self.peak_senders.remove(&PeakTarget::Stream(stream_key));
+                self.sync_streams();
+            }
+            FakeAction::InsertCard(card) => {
+                self.server.cards.insert(card.index, card);
+                self.sync_cards();
+            }
+            FakeAction::RemoveCard(card_index) => {
+                self.server.cards.remove(&card_index);
+                self.sync_cards();
+            }
+            FakeAction::SetDefaultDevice(device_key) => {
+                self.set_default(device_key.device_type, device_key);
+            }
+            FakeAction::EmitPeak { target, level } => {
+                if self.connected
+                    && let Some(sender) = self.peak_senders.get(&target)
+                {
+                    let _ = sender.send(level);
+                }
+            }
+            FakeAction::PeakCommands(reply_tx) => {
+                let _ = reply_tx.send(self.peak_commands.clone());
+            }
+            FakeAction::Disconnect => {
+                self.connected = false;
+                self.context
+                    .connection_state
+                    .set(ConnectionState::Reconnecting { attempt: 1 });
+            }
+            FakeAction::Reconnect => {
+                self.connected = true;
+                self.sync_all();
+                self.context
+                    .connection_state
+                    .set(ConnectionState::Connected);
+            }
+        }
+    }
+
+    fn target_exists(&self, target: PeakTarget) -> bool {
+        match target {
+            PeakTarget::Device(device_key) => self.server.devices.contains_key(&device_key),
+            PeakTarget::Stream(stream_key) => self.server.streams.contains_key(&stream_key),
+        }
+    }
+
+    fn set_default(&mut self, device_type: DeviceType, device_key: DeviceKey) {
+        if self.server.devices.contains_key(&device_key) {
+            *self.server.default_slot(device_type) = Some(device_key);
+        }
+        self.sync_defaults();
+    }
+
+    fn sync_all(&self) {
+        self.sync_devices();
+        self.sync_streams();
+        self.sync_cards();
+    }
+
+    fn sync_devices(&self) {
+        if !self.connected {
+            return;
+        }
+
+        sync_devices(
+            self.server.devices.values().cloned().collect(),
+            &self.context.devices,
+            &self.context.device_list_tx,
+            &self.context.events_tx,
+        );
+        self.sync_defaults();
+    }
+
+    fn sync_streams(&self) {
+        if !self.connected {
+            return;
+        }
+
+        sync_streams(
+            self.server.streams.values().cloned().collect(),
+            &self.context.streams,
+            &self.context.stream_list_tx,
+            &self.context.events_tx,
+        );
+    }
+
+    fn sync_cards(&self) {
+        if !self.connected {
+            return;
+        }
+
+        sync_cards(
+            self.server.cards.values().cloned().collect(),
+            &self.context.cards,
+            &self.context.card_list_tx,
+            &self.context.events_tx,
+        );
+    }
+
+    fn sync_defaults(&self) {
+        if !self.connected {
+            return;
+        }
+
+        sync_default_device(
+            self.server.default_input,
+            &self.context.devices,
+            &self.context.default_input,
+            &self.context.events_tx,
+            AudioEvent::DefaultInputChanged,
+        );
+        sync_default_device(
+            self.server.default_output,
+            &self.context.devices,
+            &self.context.default_output,
+            &self.context.events_tx,
+            AudioEvent::DefaultOutputChanged,
+        );
+    }
+}
+
+/// Refresh a default device slot, emitting `event` when the default moves
+/// to a different device
+fn sync_default_device(
+    device_key: Option<DeviceKey>,
+    devices: &DeviceStore,
+    default_device: &DefaultDevice,
+    events_tx: &EventSender,
+    event: fn(DeviceInfo) -> AudioEvent,
+) {
+    let device = device_key.and_then(|key| {
+        devices
+            .read()
+            .ok()
+            .and_then(|devices_guard| devices_guard.get(&key).cloned())
+    });
+
+    let Ok(mut default_guard) = default_device.write() else {
+        return;
+    };
+
+    let default_moved =
+        default_guard.as_ref().map(|current| current.key) != device.as_ref().map(|new| new.key);
+    *default_guard = device.clone();
+    drop(default_guard);
+
+    if default_moved && let Some(device) = device {
+        let _ = events_tx.send(event(device));
+    }
+}
+
+/// Apply a commanded volume while keeping the existing channel positions,
+/// as the sound server keeps a device's or stream's channel map
+fn keep_positions(current: &Volume, volume: &Volume) -> Volume {
+    Volume::with_positions(volume.as_slice().to_vec(), current.positions().to_vec())
+        .unwrap_or_else(|_| volume.clone())
+}
@@ -4,73 +4,144 @@ pub mod commands;
 pub mod conversion;
 /// Event subscription and handling
 pub mod events;
+/// In-memory backend for tests
+pub mod fake;
+/// Peak-detect record streams
+pub mod peak;
+/// Backend abstraction
+pub mod traits;
 /// Type definitions and aliases
 pub mod types;
 
+pub use fake::{FakeBackend, FakeBackendHandle, FakePeakCommand};
+pub use traits::{AudioBackend, BackendContext};
 pub use types::{
-    CommandSender, DefaultDevice, DeviceListSender, DeviceStore, EventSender, ExternalCommand,
-    InternalCommand, ServerInfo, StreamListSender, StreamStore,
+    CardListSender, CardStore, CommandSender, DefaultDevice, DeviceListSender, DeviceStore,
+    EventSender, ExternalCommand, ExternalCommandReceiver, InternalCommand, ServerInfo,
+    StreamListSender, StreamStore,
 };
 
 pub use conversion::{convert_volume_from_pulse, convert_volume_to_pulse};
 
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use libpulse_binding::{
-    context::{Context, FlagSet as ContextFlags},
+    context::{Context, FlagSet as ContextFlags, State as ContextState},
     volume::ChannelVolumes,
 };
 use tokio::sync::mpsc;
-use tracing::{error, info, instrument};
+use tracing::{info, instrument, warn};
 
-use crate::services::AudioError;
+use crate::services::{AudioError, common::Property};
 
-use super::{discovery, tokio_mainloop::TokioMain, volume};
+use super::{
+    connection::{Backoff, ConnectionState},
+    discovery,
+    tokio_mainloop::TokioMain,
+    volume,
+};
 use commands::{handle_external_command, handle_internal_command};
 use events::{process_change_notification, setup_event_subscription};
-use types::{ChangeNotification, ExternalCommandReceiver, InternalCommandReceiver};
+use peak::{PeakStreams, restart_peak_monitors};
+use types::ChangeNotification;
+
+/// How a connection session ended
+enum SessionEnd {
+    /// Shutdown was requested or the service was dropped
+    Shutdown,
+    /// The server went away after the connection was established
+    Disconnected,
+}
 
 /// PulseAudio backend implementation
 pub struct PulseBackend;
 
+#[async_trait]
+impl AudioBackend for PulseBackend {
+    async fn spawn(
+        self,
+        context: BackendContext,
+    ) -> Result<tokio::task::JoinHandle<()>, AudioError> {
+        Self::spawn_monitoring_task(context).await
+    }
+}
+
 impl PulseBackend {
     /// Spawn the monitoring task for PulseAudio events
     ///
     /// Creates a background task that monitors PulseAudio for device and stream changes,
-    /// processes commands, and manages the connection lifecycle.
+    /// processes commands, and manages the connection lifecycle. When the
+    /// server goes away (e.g. `pipewire-pulse` restarts), the task reconnects
+    /// with exponential backoff and rediscovers everything, reporting progress
+    /// through `connection_state`. Peak monitor streams are owned by this task
+    /// and dispatched by its tokio mainloop.
     ///
     /// # Errors
-    /// Returns error if PulseAudio connection or monitoring setup fails
-    #[allow(clippy::too_many_arguments)]
+    /// Does not currently fail: an unreachable server is retried in the
+    /// background rather than reported here
     #[instrument(name = "pulse_backend_spawn", skip_all)]
     pub async fn spawn_monitoring_task(
-        command_rx: ExternalCommandReceiver,
-        device_list_tx: DeviceListSender,
-        stream_list_tx: StreamListSender,
-        events_tx: EventSender,
-        devices: DeviceStore,
-        streams: StreamStore,
-        default_input: DefaultDevice,
-        default_output: DefaultDevice,
-        _server_info: ServerInfo,
+        context: BackendContext,
     ) -> Result<tokio::task::JoinHandle<()>, AudioError> {
+        let BackendContext {
+            command_rx,
+            device_list_tx,
+            stream_list_tx,
+            card_list_tx,
+            events_tx,
+            devices,
+            streams,
+            cards,
+            default_input,
+            default_output,
+            server_info: _,
+            connection_state,
+        } = context;
+
         let handle = tokio::task::spawn_blocking(move || {
             let runtime = tokio::runtime::Handle::current();
             runtime.block_on(async move {
-                if let Err(e) = Self::monitor_pulse_events(
-                    command_rx,
-                    device_list_tx,
-                    stream_list_tx,
-                    events_tx,
-                    devices,
-                    streams,
-                    default_input,
-                    default_output,
-                )
-                .await
-                {
-                    error!("PulseAudio monitoring task failed: {e}");
+                let mut command_rx = command_rx;
+                let mut peak_streams = PeakStreams::new();
+                let mut backoff = Backoff::default();
+
+                loop {
+                    let session = Self::monitor_pulse_events(
+                        &mut command_rx,
+                        &mut peak_streams,
+                        &device_list_tx,
+                        &stream_list_tx,
+                        &card_list_tx,
+                        &events_tx,
+                        &devices,
+                        &streams,
+                        &cards,
+                        &default_input,
+                        &default_output,
+                        &connection_state,
+                    )
+                    .await;
+
+                    match session {
+                        Ok(SessionEnd::Shutdown) => break,
+                        Ok(SessionEnd::Disconnected) => {
+                            warn!("Lost connection to PulseAudio server");
+                            backoff.reset();
+                        }
+                        Err(e) => warn!("PulseAudio connection attempt failed: {e}"),
+                    }
+
+                    connection_state.set(ConnectionState::Reconnecting {
+                        attempt: backoff.attempt() + 1,
+                    });
+
+                    if Self::wait_before_reconnect(&mut command_rx, &mut backoff).await {
+                        break;
+                    }
                 }
+
+                connection_state.set(ConnectionState::Disconnected);
             });
         });
 
@@ -87,17 +158,44 @@ impl PulseBackend {
         conversion::convert_volume_from_pulse(pulse_volume)
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Sleeps for the next backoff delay, returning `true` if shutdown was
+    /// requested meanwhile
+    ///
+    /// Other commands arriving while disconnected are dropped, since the
+    /// devices and streams they target may not exist after reconnecting.
+    async fn wait_before_reconnect(
+        command_rx: &mut ExternalCommandReceiver,
+        backoff: &mut Backoff,
+    ) -> bool {
+        let delay = tokio::time::sleep(backoff.next_delay());
+        tokio::pin!(delay);
+
+        loop {
+            tokio::select! {
+                _ = &mut delay => return false,
+                command = command_rx.recv() => match command {
+                    Some(ExternalCommand::Shutdown) | None => return true,
+                    Some(command) => warn!("Dropping {command:?} while disconnected"),
+                },
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     async fn monitor_pulse_events(
-        command_rx: ExternalCommandReceiver,
-        device_list_tx: DeviceListSender,
-        stream_list_tx: StreamListSender,
-        events_tx: EventSender,
-        devices: DeviceStore,
-        streams: StreamStore,
-        default_input: DefaultDevice,
-        default_output: DefaultDevice,
-    ) -> Result<(), AudioError> {
+        command_rx: &mut ExternalCommandReceiver,
+        peak_streams: &mut PeakStreams,
+        device_list_tx: &DeviceListSender,
+        stream_list_tx: &StreamListSender,
+        card_list_tx: &CardListSender,
+        events_tx: &EventSender,
+        devices: &DeviceStore,
+        streams: &StreamStore,
+        cards: &CardStore,
+        default_input: &DefaultDevice,
+        default_output: &DefaultDevice,
+        connection_state: &Property<ConnectionState>,
+    ) -> Result<SessionEnd, AudioError> {
         info!("Starting PulseAudio backend monitoring task");
 
         let mut mainloop = TokioMain::new();
@@ -111,37 +209,51 @@ impl PulseBackend {
             .map_err(|e| AudioError::ConnectionFailed(format!("Connection failed: {e}")))?;
 
         info!("Waiting for PulseAudio context to become ready");
-        mainloop.wait_for_ready(&context).await.map_err(|e| {
+        let state = mainloop.wait_for_ready(&context).await.map_err(|e| {
             AudioError::ConnectionFailed(format!("Context failed to become ready: {e:?}"))
         })?;
+        if state != ContextState::Ready {
+            return Err(AudioError::ConnectionFailed(format!(
+                "Context failed to become ready: {state:?}"
+            )));
+        }
+
+        let (state_tx, mut state_rx) = mpsc::unbounded_channel::<()>();
+        context.set_state_callback(Some(Box::new(move || {
+            let _ = state_tx.send(());
+        })));
 
         let (change_tx, mut change_rx) = mpsc::unbounded_channel::<ChangeNotification>();
-        let (internal_command_tx, internal_command_rx) =
+        let (internal_command_tx, mut internal_command_rx) =
             mpsc::unbounded_channel::<InternalCommand>();
 
         info!("Setting up PulseAudio event subscription");
         setup_event_subscription(&mut context, change_tx, internal_command_tx.clone())?;
 
-        let devices_clone = Arc::clone(&devices);
-        let streams_clone = Arc::clone(&streams);
-        let default_input_clone = Arc::clone(&default_input);
-        let default_output_clone = Arc::clone(&default_output);
+        let devices_clone = Arc::clone(devices);
+        let streams_clone = Arc::clone(streams);
+        let cards_clone = Arc::clone(cards);
+        let default_input_clone = Arc::clone(default_input);
+        let default_output_clone = Arc::clone(default_output);
         let events_tx_clone = events_tx.clone();
         let device_list_tx_clone = device_list_tx.clone();
         let stream_list_tx_clone = stream_list_tx.clone();
+        let card_list_tx_clone = card_list_tx.clone();
         let command_tx_clone = internal_command_tx.clone();
 
-        tokio::spawn(async move {
+        let notification_task = tokio::spawn(async move {
             while let Some(notification) = change_rx.recv().await {
                 process_change_notification(
                     notification,
                     &devices_clone,
                     &streams_clone,
+                    &cards_clone,
                     &default_input_clone,
                     &default_output_clone,
                     &events_tx_clone,
                     &device_list_tx_clone,
                     &stream_list_tx_clone,
+                    &card_list_tx_clone,
                     &command_tx_clone,
                 )
                 .await;
@@ -149,92 +261,83 @@ impl PulseBackend {
         });
 
         info!("Triggering initial device and stream discovery");
-        discovery::trigger_device_discovery(&context, &devices, &device_list_tx, &events_tx);
-        discovery::trigger_stream_discovery(&context, &streams, &stream_list_tx, &events_tx);
+        restart_peak_monitors(
+            &mut context,
+            devices,
+            streams,
+            peak_streams,
+            &internal_command_tx,
+        );
+        discovery::trigger_device_discovery(&context, devices, device_list_tx, events_tx);
+        discovery::trigger_stream_discovery(&context, streams, stream_list_tx, events_tx);
+        discovery::trigger_card_discovery(&context, cards, card_list_tx, events_tx);
         discovery::trigger_server_info_query(
             &context,
-            &devices,
-            &events_tx,
-            &default_input,
-            &default_output,
+            devices,
+            events_tx,
+            default_input,
+            default_output,
         );
 
+        connection_state.set(ConnectionState::Connected);
         info!("PulseAudio backend fully initialized and monitoring");
 
-        tokio::select! {
-            _ = mainloop.run() => {
-                info!("PulseAudio mainloop exited");
-            }
-            _ = Self::run_command_loop(
-                &mut context,
-                command_rx,
-                internal_command_rx,
-                &devices,
-                &streams,
-                &events_tx,
-                &device_list_tx,
-                &stream_list_tx,
-                &default_input,
-                &default_output,
-            ) => {
-                info!("Command processing loop exited");
-            }
-        }
-
-        Ok(())
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    async fn run_command_loop(
-        context: &mut Context,
-        mut command_rx: ExternalCommandReceiver,
-        mut internal_command_rx: InternalCommandReceiver,
-        devices: &DeviceStore,
-        streams: &StreamStore,
-        events_tx: &EventSender,
-        device_list_tx: &DeviceListSender,
-        stream_list_tx: &StreamListSender,
-        default_input: &DefaultDevice,
-        default_output: &DefaultDevice,
-    ) {
-        loop {
+        let session_end = loop {
             tokio::select! {
+                _ = mainloop.run() => {
+                    info!("PulseAudio mainloop exited");
+                    break SessionEnd::Disconnected;
+                }
                 external_cmd = command_rx.recv() => {
                     match external_cmd {
                         Some(ExternalCommand::Shutdown) => {
                             info!("Received shutdown command");
-                            break;
+                            break SessionEnd::Shutdown;
                         }
                         Some(command) => {
                             handle_external_command(
-                                context,
+                                &mut context,
                                 command,
                                 devices,
                                 streams,
+                                peak_streams,
+                                &internal_command_tx,
                             );
                         }
                         None => {
                             info!("External command channel closed");
-                            break;
+                            break SessionEnd::Shutdown;
                         }
                     }
                 }
-                internal_cmd = internal_command_rx.recv() => {
-                    if let Some(command) = internal_cmd {
-                        handle_internal_command(
-                            context,
-                            command,
-                            devices,
-                            streams,
-                            events_tx,
-                            device_list_tx,
-                            stream_list_tx,
-                            default_input,
-                            default_output,
-                        );
+                Some(command) = internal_command_rx.recv() => {
+                    handle_internal_command(
+                        &mut context,
+                        command,
+                        devices,
+                        streams,
+                        cards,
+                        peak_streams,
+                        events_tx,
+                        device_list_tx,
+                        stream_list_tx,
+                        card_list_tx,
+                        default_input,
+                        default_output,
+                    );
+                }
+                Some(()) = state_rx.recv() => {
+                    if matches!(context.get_state(), ContextState::Failed | ContextState::Terminated) {
+                        break SessionEnd::Disconnected;
                     }
                 }
             }
-        }
+        };
+
+        notification_task.abort();
+        context.set_state_callback(None);
+        context.disconnect();
+
+        Ok(session_end)
     }
 }
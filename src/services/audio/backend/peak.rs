@@ -0,0 +1,269 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use libpulse_binding::{
+    context::Context,
+    def::BufferAttr,
+    sample::{Format, Spec},
+    stream::{FlagSet as StreamFlags, PeekResult, Stream},
+};
+use tokio::sync::broadcast;
+use tracing::{debug, warn};
+
+use crate::services::{
+    DeviceType, StreamType,
+    audio::{device::DeviceKey, peak::PeakTarget, stream::StreamKey},
+};
+
+use super::types::{DeviceStore, InternalCommand, InternalCommandSender, StreamStore};
+
+/// Record streams backing active peak monitors
+///
+/// The streams live on the backend task and are dispatched by the tokio
+/// mainloop like every other PulseAudio object.
+pub type PeakStreams = HashMap<PeakTarget, ActivePeakMonitor>;
+
+/// A running peak monitor and what is needed to restart it
+pub struct ActivePeakMonitor {
+    stream: Rc<RefCell<Stream>>,
+    rate: u32,
+    sender: broadcast::Sender<f32>,
+}
+
+/// Where a peak monitor records from
+struct PeakSource {
+    source_name: String,
+    monitor_stream: Option<u32>,
+}
+
+/// Start a peak-detect record stream for the target
+///
+/// PulseAudio computes peaks server-side: with `PEAK_DETECT` each sample of
+/// the mono float stream is the peak of a `1 / rate` second window. A
+/// monitor already running for the target is replaced, and the sender is
+/// dropped if the stream cannot be set up so subscribers see it close.
+#[allow(clippy::too_many_arguments)]
+pub fn start_peak_monitor(
+    context: &mut Context,
+    target: PeakTarget,
+    rate: u32,
+    sender: broadcast::Sender<f32>,
+    devices: &DeviceStore,
+    streams: &StreamStore,
+    peak_streams: &mut PeakStreams,
+    command_tx: &InternalCommandSender,
+) {
+    stop_peak_monitor(target, peak_streams);
+
+    let Some(source) = resolve_peak_source(target, devices, streams) else {
+        warn!("Cannot monitor peaks for {target:?}: target not found");
+        return;
+    };
+
+    let spec = Spec {
+        format: Format::F32le,
+        channels: 1,
+        rate,
+    };
+
+    let Some(mut stream) = Stream::new(context, "wayle-peak", &spec, None) else {
+        warn!("Failed to create peak stream for {target:?}");
+        return;
+    };
+
+    if let Some(index) = source.monitor_stream
+        && let Err(e) = stream.set_monitor_stream(index)
+    {
+        warn!("Failed to attach peak stream to stream {index}: {e}");
+        return;
+    }
+
+    let buffer_attr = BufferAttr {
+        maxlength: u32::MAX,
+        tlength: u32::MAX,
+        prebuf: u32::MAX,
+        minreq: u32::MAX,
+        fragsize: size_of::<f32>() as u32,
+    };
+
+    let flags = StreamFlags::PEAK_DETECT | StreamFlags::ADJUST_LATENCY | StreamFlags::DONT_MOVE;
+
+    if let Err(e) = stream.connect_record(Some(&source.source_name), Some(&buffer_attr), flags) {
+        warn!(
+            "Failed to connect peak stream to {}: {e}",
+            source.source_name
+        );
+        return;
+    }
+
+    let stream = Rc::new(RefCell::new(stream));
+    let weak_stream = Rc::downgrade(&stream);
+    let levels_tx = sender.clone();
+
+    stream
+        .borrow_mut()
+        .set_read_callback(Some(Box::new(move |_length| {
+            let Some(stream) = weak_stream.upgrade() else {
+                return;
+            };
+            let Ok(mut stream) = stream.try_borrow_mut() else {
+                return;
+            };
+
+            match stream.peek() {
+                Ok(PeekResult::Data(data)) => {
+                    if let Some(level) = last_sample(data) {
+                        let _ = levels_tx.send(level.clamp(0.0, 1.0));
+                    }
+                }
+                Ok(PeekResult::Hole(_)) => {}
+                Ok(PeekResult::Empty) | Err(_) => return,
+            }
+
+            let _ = stream.discard();
+        })));
+
+    let state_tx = command_tx.clone();
+    stream
+        .borrow_mut()
+        .set_state_callback(Some(Box::new(move || {
+            let _ = state_tx.send(InternalCommand::RefreshPeakMonitor(target));
+        })));
+
+    debug!(
+        "Started peak monitor for {target:?} on {}",
+        source.source_name
+    );
+    peak_streams.insert(
+        target,
+        ActivePeakMonitor {
+            stream,
+            rate,
+            sender,
+        },
+    );
+}
+
+/// Recreate every peak monitor on a new connection
+///
+/// Streams of a lost connection are dead; subscribers keep their channels
+/// and resume receiving levels once the new streams are connected. Monitors
+/// without subscribers or whose target is gone are dropped, ending their
+/// channels.
+pub fn restart_peak_monitors(
+    context: &mut Context,
+    devices: &DeviceStore,
+    streams: &StreamStore,
+    peak_streams: &mut PeakStreams,
+    command_tx: &InternalCommandSender,
+) {
+    let monitors: Vec<(PeakTarget, ActivePeakMonitor)> = peak_streams.drain().collect();
+
+    for (target, monitor) in monitors {
+        if monitor.sender.receiver_count() == 0 {
+            continue;
+        }
+
+        start_peak_monitor(
+            context,
+            target,
+            monitor.rate,
+            monitor.sender,
+            devices,
+            streams,
+            peak_streams,
+            command_tx,
+        );
+    }
+}
+
+/// Drop the target's peak monitor if its stream failed or was terminated
+/// by the server, or the target itself is gone
+pub fn refresh_peak_monitor(
+    target: PeakTarget,
+    devices: &DeviceStore,
+    streams: &StreamStore,
+    peak_streams: &mut PeakStreams,
+) {
+    let Some(monitor) = peak_streams.get(&target) else {
+        return;
+    };
+
+    let stream_ended = monitor
+        .stream
+        .try_borrow()
+        .is_ok_and(|stream| !stream.get_state().is_good());
+
+    if stream_ended || resolve_peak_source(target, devices, streams).is_none() {
+        stop_peak_monitor(target, peak_streams);
+    }
+}
+
+/// Stop the peak-detect record stream for the target
+pub fn stop_peak_monitor(target: PeakTarget, peak_streams: &mut PeakStreams) {
+    let Some(monitor) = peak_streams.remove(&target) else {
+        return;
+    };
+
+    let mut stream = monitor.stream.borrow_mut();
+    stream.set_read_callback(None);
+    stream.set_state_callback(None);
+    let _ = stream.disconnect();
+    debug!("Stopped peak monitor for {target:?}");
+}
+
+fn last_sample(data: &[u8]) -> Option<f32> {
+    let sample = data.len().checked_sub(size_of::<f32>())?;
+    let bytes = data.get(sample..)?.try_into().ok()?;
+    Some(f32::from_le_bytes(bytes))
+}
+
+fn resolve_peak_source(
+    target: PeakTarget,
+    devices: &DeviceStore,
+    streams: &StreamStore,
+) -> Option<PeakSource> {
+    match target {
+        PeakTarget::Device(device_key) => {
+            device_source(device_key, devices).map(|source_name| PeakSource {
+                source_name,
+                monitor_stream: None,
+            })
+        }
+        PeakTarget::Stream(stream_key) => stream_source(stream_key, devices, streams),
+    }
+}
+
+fn device_source(device_key: DeviceKey, devices: &DeviceStore) -> Option<String> {
+    let devices_guard = devices.read().ok()?;
+    let device = devices_guard.get(&device_key)?;
+
+    match device.device_type {
+        DeviceType::Output => Some(format!("{}.monitor", device.name)),
+        DeviceType::Input => Some(device.name.to_string()),
+    }
+}
+
+fn stream_source(
+    stream_key: StreamKey,
+    devices: &DeviceStore,
+    streams: &StreamStore,
+) -> Option<PeakSource> {
+    let stream = streams.read().ok()?.get(&stream_key).cloned()?;
+
+    match stream.stream_type {
+        StreamType::Playback => {
+            let sink = DeviceKey::new(stream.device_index.0, DeviceType::Output);
+            Some(PeakSource {
+                source_name: device_source(sink, devices)?,
+                monitor_stream: Some(stream_key.index),
+            })
+        }
+        StreamType::Record | StreamType::Capture => {
+            let source = DeviceKey::new(stream.device_index.0, DeviceType::Input);
+            Some(PeakSource {
+                source_name: device_source(source, devices)?,
+                monitor_stream: None,
+            })
+        }
+    }
+}
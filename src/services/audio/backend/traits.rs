@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+
+use crate::services::{AudioError, audio::connection::ConnectionState, common::Property};
+
+use super::types::{
+    CardListSender, CardStore, DefaultDevice, DeviceListSender, DeviceStore, EventSender,
+    ExternalCommandReceiver, ServerInfo, StreamListSender, StreamStore,
+};
+
+/// Sound server backend driving an [`AudioService`](crate::services::AudioService)
+///
+/// A backend owns the connection to the sound server. It discovers devices,
+/// streams and cards into the shared stores of [`BackendContext`], applies
+/// the commands arriving on its command channel, and publishes every change
+/// on the event and list channels until it receives
+/// [`ExternalCommand::Shutdown`](super::ExternalCommand::Shutdown).
+#[async_trait]
+pub trait AudioBackend: Send + Sized + 'static {
+    /// Start the backend's background task
+    ///
+    /// # Errors
+    /// Returns error if the backend cannot be started
+    async fn spawn(self, context: BackendContext) -> Result<JoinHandle<()>, AudioError>;
+}
+
+/// Channels and shared state handed to a backend by the service
+pub struct BackendContext {
+    /// Commands issued through the service
+    pub command_rx: ExternalCommandReceiver,
+    /// Device list updates
+    pub device_list_tx: DeviceListSender,
+    /// Stream list updates
+    pub stream_list_tx: StreamListSender,
+    /// Card list updates
+    pub card_list_tx: CardListSender,
+    /// Audio events
+    pub events_tx: EventSender,
+    /// Known devices
+    pub devices: DeviceStore,
+    /// Known streams
+    pub streams: StreamStore,
+    /// Known cards
+    pub cards: CardStore,
+    /// Current default input device
+    pub default_input: DefaultDevice,
+    /// Current default output device
+    pub default_output: DefaultDevice,
+    /// Server information
+    pub server_info: ServerInfo,
+    /// Connection state reported to the service
+    pub connection_state: Property<ConnectionState>,
+}
@@ -3,15 +3,17 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use libpulse_binding::{
-    context::subscribe::{Facility, Operation},
-    volume::ChannelVolumes,
-};
+use libpulse_binding::context::subscribe::{Facility, Operation};
 use tokio::sync::{broadcast, mpsc};
 
 use crate::services::{
-    AudioEvent, DeviceInfo, StreamInfo,
-    audio::{device::DeviceKey, stream::StreamKey},
+    AudioEvent, DeviceInfo, StreamInfo, Volume,
+    audio::{
+        card::{CardIndex, CardInfo},
+        device::DeviceKey,
+        peak::PeakTarget,
+        stream::StreamKey,
+    },
 };
 
 /// Thread-safe storage for audio devices
@@ -20,6 +22,9 @@ pub type DeviceStore = Arc<RwLock<HashMap<DeviceKey, DeviceInfo>>>;
 /// Thread-safe storage for audio streams  
 pub type StreamStore = Arc<RwLock<HashMap<StreamKey, StreamInfo>>>;
 
+/// Thread-safe storage for sound cards
+pub type CardStore = Arc<RwLock<HashMap<CardIndex, CardInfo>>>;
+
 /// Thread-safe storage for default device information
 pub type DefaultDevice = Arc<RwLock<Option<DeviceInfo>>>;
 
@@ -32,6 +37,9 @@ pub type DeviceListSender = broadcast::Sender<Vec<DeviceInfo>>;
 /// Channel sender for stream list updates
 pub type StreamListSender = broadcast::Sender<Vec<StreamInfo>>;
 
+/// Channel sender for card list updates
+pub type CardListSender = broadcast::Sender<Vec<CardInfo>>;
+
 /// Channel sender for external backend commands
 pub type CommandSender = mpsc::UnboundedSender<ExternalCommand>;
 
@@ -41,8 +49,8 @@ pub(super) type InternalCommandSender = mpsc::UnboundedSender<InternalCommand>;
 /// Thread-safe storage for server information
 pub type ServerInfo = Arc<RwLock<Option<String>>>;
 
-pub(super) type ExternalCommandReceiver = mpsc::UnboundedReceiver<ExternalCommand>;
-pub(super) type InternalCommandReceiver = mpsc::UnboundedReceiver<InternalCommand>;
+/// Channel receiver for external backend commands
+pub type ExternalCommandReceiver = mpsc::UnboundedReceiver<ExternalCommand>;
 
 /// Change notifications from PulseAudio subscription
 #[derive(Debug, Clone)]
@@ -65,6 +73,13 @@ pub enum ChangeNotification {
         /// Stream index
         index: u32,
     },
+    /// Card-related change notification
+    Card {
+        /// Operation performed on the card
+        operation: Operation,
+        /// Card index
+        index: u32,
+    },
     /// Server-related change notification
     Server {
         /// PulseAudio facility type
@@ -85,6 +100,10 @@ pub enum InternalCommand {
     RefreshStreams,
     /// Refresh server info for default device updates
     RefreshServerInfo,
+    /// Refresh card information after change notification
+    RefreshCards,
+    /// Drop a peak monitor whose stream ended or whose target was removed
+    RefreshPeakMonitor(PeakTarget),
 }
 
 /// External commands from user/API requests
@@ -95,7 +114,7 @@ pub enum ExternalCommand {
         /// Target device
         device_key: DeviceKey,
         /// New volume levels
-        volume: ChannelVolumes,
+        volume: Volume,
     },
     /// Set device mute state
     SetDeviceMute {
@@ -104,12 +123,19 @@ pub enum ExternalCommand {
         /// Mute state
         muted: bool,
     },
+    /// Set device active port
+    SetDevicePort {
+        /// Target device
+        device_key: DeviceKey,
+        /// Port to activate
+        port_name: String,
+    },
     /// Set stream volume
     SetStreamVolume {
         /// Target stream
         stream_key: StreamKey,
         /// New volume levels
-        volume: ChannelVolumes,
+        volume: Volume,
     },
     /// Set stream mute state
     SetStreamMute {
@@ -135,6 +161,27 @@ pub enum ExternalCommand {
         /// Destination device
         device_key: DeviceKey,
     },
+    /// Switch card profile
+    SetCardProfile {
+        /// Target card
+        card_index: CardIndex,
+        /// Profile to activate
+        profile_name: String,
+    },
+    /// Start a peak-detect record stream
+    StartPeakMonitor {
+        /// Device or stream to measure
+        target: PeakTarget,
+        /// Peak samples per second
+        rate: u32,
+        /// Channel receiving the peak levels
+        sender: broadcast::Sender<f32>,
+    },
+    /// Stop a peak-detect record stream
+    StopPeakMonitor {
+        /// Device or stream being measured
+        target: PeakTarget,
+    },
     /// Shutdown backend
     Shutdown,
 }
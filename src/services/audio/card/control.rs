@@ -0,0 +1,113 @@
+use std::{error::Error, sync::Arc};
+
+use async_trait::async_trait;
+use futures::Stream;
+
+use super::{CardIndex, CardInfo, CardProfile};
+use crate::services::audio::{AudioEvent, AudioService};
+
+/// Card management operations
+#[async_trait]
+pub trait CardManager {
+    /// Error type for card operations
+    type Error: Error + Send + Sync + 'static;
+
+    /// Get specific card information
+    ///
+    /// # Errors
+    /// Returns error if card is not found or communication fails
+    async fn card(&self, card_index: CardIndex) -> Result<CardInfo, Self::Error>;
+
+    /// Get all cards
+    ///
+    /// # Errors
+    /// Returns error if communication with audio backend fails
+    async fn list_cards(&self) -> Result<Vec<CardInfo>, Self::Error>;
+
+    /// Switch a card to another profile
+    ///
+    /// # Errors
+    /// Returns error if card or profile is not found, the profile is
+    /// unavailable, or the operation fails
+    async fn set_card_profile(
+        &self,
+        card_index: CardIndex,
+        profile_name: &str,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Card monitoring streams
+pub trait CardStreams {
+    /// Stream of all cards
+    fn cards(&self) -> impl Stream<Item = Vec<CardInfo>> + Send;
+
+    /// Stream of active profile changes for specific card
+    fn card_profile(&self, card_index: CardIndex) -> impl Stream<Item = CardProfile> + Send;
+
+    /// Stream of state changes for specific card, such as profile availability
+    fn card_state(&self, card_index: CardIndex) -> impl Stream<Item = CardInfo> + Send;
+}
+
+impl CardStreams for AudioService {
+    fn cards(&self) -> impl Stream<Item = Vec<CardInfo>> + Send {
+        use async_stream::stream;
+
+        let cards = Arc::clone(&self.cards);
+        let mut card_list_rx = self.card_list_tx.subscribe();
+
+        stream! {
+            let mut current_cards = if let Ok(cards_guard) = cards.read() {
+                let card_list: Vec<CardInfo> = cards_guard.values().cloned().collect();
+                card_list
+            } else {
+                Vec::new()
+            };
+
+            if !current_cards.is_empty() {
+                yield current_cards.clone();
+            }
+
+            while let Ok(card_list) = card_list_rx.recv().await {
+                if current_cards != card_list {
+                    current_cards = card_list.clone();
+                    yield card_list;
+                }
+            }
+        }
+    }
+
+    fn card_profile(&self, card: CardIndex) -> impl Stream<Item = CardProfile> + Send {
+        use async_stream::stream;
+        use futures::{StreamExt, pin_mut};
+
+        let events_stream = self.events();
+        stream! {
+            pin_mut!(events_stream);
+            while let Some(event) = events_stream.next().await {
+                if let AudioEvent::CardProfileChanged { card_index, profile } = event
+                    && card_index == card
+                {
+                    yield profile;
+                }
+            }
+        }
+    }
+
+    fn card_state(&self, card_index: CardIndex) -> impl Stream<Item = CardInfo> + Send {
+        use async_stream::stream;
+        use futures::{StreamExt, pin_mut};
+
+        let events_stream = self.events();
+        stream! {
+            pin_mut!(events_stream);
+            while let Some(event) = events_stream.next().await {
+                match event {
+                    AudioEvent::CardChanged(card_info) if card_info.index == card_index => {
+                        yield card_info;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/// Card index identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardIndex(pub u32);
+
+/// Card profile information
+///
+/// A profile selects which sinks and sources a card exposes, e.g.
+/// "Analog Stereo Duplex", "HDMI Output" or a Bluetooth codec such as A2DP
+/// or HFP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardProfile {
+    /// Profile name (e.g. "output:hdmi-stereo")
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+    /// Profile priority, higher is preferred
+    pub priority: u32,
+    /// Whether the profile can currently be activated
+    pub available: bool,
+    /// Number of sinks the profile creates
+    pub sinks: u32,
+    /// Number of sources the profile creates
+    pub sources: u32,
+}
+
+/// Complete card information
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardInfo {
+    /// Card index
+    pub index: CardIndex,
+    /// Card name (e.g. "alsa_card.pci-0000_00_1f.3")
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+    /// Driver handling the card
+    pub driver: String,
+    /// Profiles supported by the card
+    pub profiles: Vec<CardProfile>,
+    /// Name of the currently active profile
+    pub active_profile: Option<String>,
+}
+
+impl CardInfo {
+    /// Get the currently active profile
+    pub fn active_profile(&self) -> Option<&CardProfile> {
+        let active = self.active_profile.as_deref()?;
+        self.profiles.iter().find(|profile| profile.name == active)
+    }
+
+    /// Get a profile by name
+    pub fn profile(&self, name: &str) -> Option<&CardProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Check if card properties have changed, excluding the active profile
+    ///
+    /// Profile switches are reported separately via `CardProfileChanged`.
+    ///
+    /// # Arguments
+    /// * `other` - Card info to compare against
+    ///
+    /// # Returns
+    /// `true` if any tracked properties differ, `false` if all are identical
+    pub fn properties_changed(&self, other: &CardInfo) -> bool {
+        self.name != other.name
+            || self.description != other.description
+            || self.driver != other.driver
+            || self.profiles != other.profiles
+    }
+}
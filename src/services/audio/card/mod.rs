@@ -0,0 +1,7 @@
+/// Card management for PulseAudio
+pub mod control;
+/// Card information types and structures
+pub mod info;
+
+pub use control::*;
+pub use info::*;
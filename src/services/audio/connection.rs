@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+/// State of the connection to the PulseAudio (or pipewire-pulse) server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionState {
+    /// Connecting for the first time
+    #[default]
+    Connecting,
+    /// Connected; device and stream information is live
+    Connected,
+    /// Connection lost; retrying with exponential backoff
+    Reconnecting {
+        /// Number of failed attempts since the connection was lost
+        attempt: u32,
+    },
+    /// Service shut down; no further reconnection attempts
+    Disconnected,
+}
+
+/// Exponential backoff between reconnection attempts
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(250), Duration::from_secs(30))
+    }
+}
+
+impl Backoff {
+    /// Create a backoff doubling from `initial` up to `max`
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            attempt: 0,
+        }
+    }
+
+    /// Number of delays handed out since the last reset
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Delay before the next attempt
+    pub fn next_delay(&mut self) -> Duration {
+        let factor = 2u32.saturating_pow(self.attempt);
+        self.attempt = self.attempt.saturating_add(1);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+
+    /// Start over after a successful connection
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
@@ -3,7 +3,7 @@ use std::{error::Error, sync::Arc};
 use async_trait::async_trait;
 use futures::Stream;
 
-use super::{DeviceInfo, DeviceKey, DeviceType};
+use super::{DeviceInfo, DeviceKey, DevicePort, DeviceType};
 use crate::services::audio::{AudioEvent, AudioService, volume::Volume};
 
 /// Device management operations
@@ -50,6 +50,16 @@ pub trait DeviceManager {
     /// # Errors
     /// Returns error if device is not found or operation fails
     async fn set_default_output(&self, device_key: DeviceKey) -> Result<(), Self::Error>;
+
+    /// Switch a device to another port (e.g. speakers to headphones)
+    ///
+    /// # Errors
+    /// Returns error if device or port is not found, or operation fails
+    async fn set_device_port(
+        &self,
+        device_key: DeviceKey,
+        port_name: &str,
+    ) -> Result<(), Self::Error>;
 }
 
 /// Device volume control operations
@@ -58,14 +68,14 @@ pub trait DeviceVolumeController {
     /// Error type for volume operations
     type Error: Error + Send + Sync + 'static;
 
-    /// Set device volume using percentage level (0.0 to 4.0)
+    /// Set device volume to a level on the configured volume scale
     ///
-    /// Automatically handles channel count matching the device configuration.
-    /// Volume level applies uniformly across all device channels.
+    /// The level applies uniformly across all device channels, keeping their
+    /// positions, and is capped at the configured maximum volume.
     ///
     /// # Arguments
-    /// * `device` - Target device index
-    /// * `level` - Volume level where 0.0=mute, 1.0=normal, 4.0=maximum
+    /// * `device_key` - Target device
+    /// * `level` - Volume level where 0.0=mute and 1.0=normal
     ///
     /// # Errors
     /// Returns error if device is not found, level is invalid, or operation fails
@@ -77,6 +87,37 @@ pub trait DeviceVolumeController {
     /// # Errors
     /// Returns error if device is not found or operation fails
     async fn set_device_mute(&self, device_key: DeviceKey, muted: bool) -> Result<(), Self::Error>;
+
+    /// Step device volume on the configured volume scale
+    ///
+    /// Steps are measured on the service's volume scale and capped at its
+    /// maximum volume, so repeated volume key presses feel even.
+    ///
+    /// # Arguments
+    /// * `device_key` - Target device
+    /// * `step` - Fraction of the scale to move by, e.g. `0.05` or `-0.05`
+    ///
+    /// # Errors
+    /// Returns error if device is not found or operation fails
+    async fn step_device_volume(&self, device_key: DeviceKey, step: f64)
+    -> Result<(), Self::Error>;
+
+    /// Set device left/right balance
+    ///
+    /// The louder side keeps its volume, so overall loudness is preserved.
+    /// Devices without left and right channels are left unchanged.
+    ///
+    /// # Arguments
+    /// * `device_key` - Target device
+    /// * `balance` - -1.0 for left only, 0.0 for centered, 1.0 for right only
+    ///
+    /// # Errors
+    /// Returns error if device is not found or operation fails
+    async fn set_device_balance(
+        &self,
+        device_key: DeviceKey,
+        balance: f64,
+    ) -> Result<(), Self::Error>;
 }
 
 /// Device monitoring streams
@@ -104,6 +145,9 @@ pub trait DeviceStreams {
 
     /// Stream of state changes for specific device
     fn device_state(&self, device_key: DeviceKey) -> impl Stream<Item = DeviceInfo> + Send;
+
+    /// Stream of active port changes for specific device
+    fn device_port(&self, device_key: DeviceKey) -> impl Stream<Item = DevicePort> + Send;
 }
 
 impl DeviceStreams for AudioService {
@@ -267,4 +311,21 @@ impl DeviceStreams for AudioService {
             }
         }
     }
+
+    fn device_port(&self, device: DeviceKey) -> impl Stream<Item = DevicePort> + Send {
+        use async_stream::stream;
+        use futures::{StreamExt, pin_mut};
+
+        let events_stream = self.events();
+        stream! {
+            pin_mut!(events_stream);
+            while let Some(event) = events_stream.next().await {
+                if let AudioEvent::DevicePortChanged { device_key, port } = event
+                    && device_key == device
+                {
+                    yield port;
+                }
+            }
+        }
+    }
 }
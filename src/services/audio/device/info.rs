@@ -1,4 +1,6 @@
 use crate::services::audio::Volume;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Device index identifier
@@ -151,4 +153,46 @@ impl DeviceInfo {
             || self.active_port != other.active_port
             || self.ports != other.ports
     }
+
+    /// Get a port by name
+    pub fn port(&self, name: &str) -> Option<&DevicePort> {
+        self.ports.iter().find(|port| port.name == name)
+    }
+
+    /// Get the currently active port
+    pub fn active_port(&self) -> Option<&DevicePort> {
+        self.port(self.active_port.as_deref()?)
+    }
+
+    /// Check whether switching to the named port would be an upgrade
+    ///
+    /// A port is preferred when it is available, not already active, and
+    /// either outranks the active port by priority or the active port is
+    /// unavailable (e.g. headphones were unplugged).
+    pub fn prefers_port(&self, name: &str) -> bool {
+        let Some(candidate) = self.port(name) else {
+            return false;
+        };
+
+        if !candidate.available || self.active_port.as_deref() == Some(name) {
+            return false;
+        }
+
+        match self.active_port() {
+            Some(active) => !active.available || candidate.priority > active.priority,
+            None => true,
+        }
+    }
+}
+
+/// How devices react when one of their ports becomes available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PortSwitchPolicy {
+    /// Ports are only changed through `set_device_port`
+    #[default]
+    Manual,
+    /// Switch to a newly available port when it has a higher priority than
+    /// the active one, e.g. when headphones are plugged in
+    PreferHigherPriority,
 }
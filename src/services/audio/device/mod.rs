@@ -2,6 +2,12 @@
 pub mod control;
 /// Device information types and structures
 pub mod info;
+/// Reactive device model
+pub mod model;
+/// Default device priority policy
+pub mod priority;
 
 pub use control::*;
 pub use info::*;
+pub use model::*;
+pub use priority::*;
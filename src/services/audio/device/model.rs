@@ -0,0 +1,117 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use futures::Stream;
+
+use super::{DeviceInfo, DeviceKey, DeviceName, DevicePort, DeviceState};
+use crate::{
+    services::{audio::volume::Volume, common::Property},
+    watch_all,
+};
+
+/// Shared reactive device models keyed by device
+pub type DeviceModels = Arc<RwLock<HashMap<DeviceKey, Arc<AudioDevice>>>>;
+
+/// Reactive device model with fine-grained property updates.
+///
+/// Each property can be watched independently, so widgets can bind to a
+/// single device without filtering the global event stream. Properties are
+/// kept in sync with the backend by the audio service; a removed device
+/// simply stops updating.
+#[derive(Clone, Debug)]
+pub struct AudioDevice {
+    /// Unique device key
+    pub key: DeviceKey,
+    /// Device name
+    pub name: DeviceName,
+
+    /// Human-readable description
+    pub description: Property<String>,
+    /// Device state
+    pub state: Property<DeviceState>,
+    /// Device volume
+    pub volume: Property<Volume>,
+    /// Whether device is muted
+    pub muted: Property<bool>,
+    /// Available ports
+    pub ports: Property<Vec<DevicePort>>,
+    /// Currently active port
+    pub active_port: Property<Option<DevicePort>>,
+}
+
+impl PartialEq for AudioDevice {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl AudioDevice {
+    pub(crate) fn from_info(info: &DeviceInfo) -> Self {
+        Self {
+            key: info.key,
+            name: info.name.clone(),
+
+            description: Property::new(info.description.clone()),
+            state: Property::new(info.state),
+            volume: Property::new(info.volume.clone()),
+            muted: Property::new(info.muted),
+            ports: Property::new(info.ports.clone()),
+            active_port: Property::new(info.active_port().cloned()),
+        }
+    }
+
+    pub(crate) fn update(&self, info: &DeviceInfo) {
+        self.description.set(info.description.clone());
+        self.state.set(info.state);
+        self.volume.set(info.volume.clone());
+        self.muted.set(info.muted);
+        self.ports.set(info.ports.clone());
+        self.active_port.set(info.active_port().cloned());
+    }
+
+    /// Watch for any device property changes.
+    ///
+    /// Returns a stream that emits a clone of the device whenever any
+    /// property changes.
+    pub fn watch(&self) -> impl Stream<Item = AudioDevice> + Send {
+        watch_all!(self, description, state, volume, muted, ports, active_port)
+    }
+}
+
+/// Bring device models in line with a complete device list
+///
+/// Existing models are updated in place so watchers keep their
+/// subscriptions; models for vanished devices are dropped, and a reused
+/// index with a different name gets a fresh model.
+pub(crate) fn sync_device_models(
+    device_list: &[DeviceInfo],
+    models: &DeviceModels,
+    model_list: &Property<Vec<Arc<AudioDevice>>>,
+) {
+    let Ok(mut models_guard) = models.write() else {
+        return;
+    };
+
+    models_guard.retain(|key, model| {
+        device_list
+            .iter()
+            .any(|device| device.key == *key && device.name == model.name)
+    });
+
+    for device in device_list {
+        match models_guard.get(&device.key) {
+            Some(model) => model.update(device),
+            None => {
+                models_guard.insert(device.key, Arc::new(AudioDevice::from_info(device)));
+            }
+        }
+    }
+
+    let mut device_models: Vec<Arc<AudioDevice>> = models_guard.values().cloned().collect();
+    device_models.sort_by_key(|model| (model.key.index, model.key.device_type as u8));
+    drop(models_guard);
+
+    model_list.set(device_models);
+}
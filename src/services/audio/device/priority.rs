@@ -0,0 +1,88 @@
+use glob::Pattern;
+
+use super::{DeviceInfo, DeviceName, DeviceType};
+
+/// Ordered device name patterns preferred as default devices
+///
+/// Patterns are globs matched against the device name, e.g.
+/// `alsa_output.usb-*` or `bluez_output.*`. Earlier patterns rank higher;
+/// invalid patterns match nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DevicePriority {
+    /// Output device patterns, most preferred first
+    pub output: Vec<String>,
+    /// Input device patterns, most preferred first
+    pub input: Vec<String>,
+}
+
+impl DevicePriority {
+    /// Position of the first pattern matching the device, if any
+    pub fn rank(&self, device: &DeviceInfo) -> Option<usize> {
+        let patterns = match device.device_type {
+            DeviceType::Output => &self.output,
+            DeviceType::Input => &self.input,
+        };
+
+        patterns.iter().position(|pattern| {
+            Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(device.name.as_str()))
+        })
+    }
+
+    /// Check whether no patterns are configured for either direction
+    pub fn is_empty(&self) -> bool {
+        self.output.is_empty() && self.input.is_empty()
+    }
+}
+
+/// Why the device priority policy switched the default device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultSwitchReason {
+    /// A device ranking above the current default appeared
+    PreferredDeviceAdded,
+    /// The default device disappeared and the previous default took over
+    DefaultDeviceRemoved,
+}
+
+/// Devices that were default for one direction, most recent last
+///
+/// Tracked by name, which stays stable when a device is unplugged and
+/// plugged back in under a new index.
+#[derive(Debug, Default)]
+pub(crate) struct DefaultHistory {
+    names: Vec<DeviceName>,
+}
+
+impl DefaultHistory {
+    pub(crate) fn current(&self) -> Option<&DeviceName> {
+        self.names.last()
+    }
+
+    pub(crate) fn push(&mut self, name: DeviceName) {
+        self.names.retain(|previous| *previous != name);
+        self.names.push(name);
+    }
+
+    /// Pick the device to fall back to once `removed` is gone
+    ///
+    /// Ranked devices win by rank, then more recent defaults win. Devices
+    /// that are neither ranked nor former defaults are left to the server.
+    pub(crate) fn fallback<'a>(
+        &self,
+        removed: &DeviceInfo,
+        candidates: &'a [DeviceInfo],
+        priority: &DevicePriority,
+    ) -> Option<&'a DeviceInfo> {
+        candidates
+            .iter()
+            .filter(|device| device.name != removed.name)
+            .filter_map(|device| {
+                let rank = priority.rank(device);
+                let recency = self.names.iter().rposition(|name| *name == device.name);
+                (rank.is_some() || recency.is_some()).then_some((device, rank, recency))
+            })
+            .min_by_key(|&(_, rank, recency)| {
+                (rank.unwrap_or(usize::MAX), std::cmp::Reverse(recency))
+            })
+            .map(|(device, _, _)| device)
+    }
+}
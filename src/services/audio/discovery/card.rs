@@ -0,0 +1,132 @@
+use std::{collections::HashSet, sync::Arc};
+
+use libpulse_binding::{callbacks::ListResult, context::Context};
+use tracing::{debug, instrument};
+
+use crate::services::{
+    AudioEvent,
+    audio::{
+        backend::{CardListSender, CardStore, EventSender, conversion::create_card_info},
+        card::{CardIndex, CardInfo},
+    },
+};
+
+/// Trigger card discovery from PulseAudio
+#[instrument(skip_all)]
+pub fn trigger_card_discovery(
+    context: &Context,
+    cards: &CardStore,
+    card_list_tx: &CardListSender,
+    events_tx: &EventSender,
+) {
+    debug!("Starting PulseAudio card discovery");
+
+    let cards_clone = Arc::clone(cards);
+    let card_list_tx_clone = card_list_tx.clone();
+    let events_tx_clone = events_tx.clone();
+    let introspect = context.introspect();
+
+    let mut seen = HashSet::new();
+
+    introspect.get_card_info_list(move |result| match result {
+        ListResult::Item(pulse_card_info) => {
+            let card_info = create_card_info(pulse_card_info);
+            seen.insert(card_info.index);
+            process_card_info(card_info, &cards_clone, &events_tx_clone);
+        }
+        ListResult::End => {
+            debug!("Completed card discovery");
+            remove_unseen_cards(&seen, &cards_clone, &events_tx_clone);
+            broadcast_card_list(&card_list_tx_clone, &cards_clone);
+        }
+        ListResult::Error => {}
+    });
+}
+
+/// Reconcile the card store with a complete list of server cards
+///
+/// Emits the same added, changed and removed events as a discovery pass, for
+/// backends that know every card up front.
+pub fn sync_cards(
+    card_list: Vec<CardInfo>,
+    cards: &CardStore,
+    card_list_tx: &CardListSender,
+    events_tx: &EventSender,
+) {
+    let seen: HashSet<CardIndex> = card_list.iter().map(|card| card.index).collect();
+
+    for card_info in card_list {
+        process_card_info(card_info, cards, events_tx);
+    }
+
+    remove_unseen_cards(&seen, cards, events_tx);
+    broadcast_card_list(card_list_tx, cards);
+}
+
+fn process_card_info(card_info: CardInfo, cards: &CardStore, events_tx: &EventSender) {
+    if let Ok(mut cards_guard) = cards.write() {
+        let index_reused = cards_guard
+            .get(&card_info.index)
+            .is_some_and(|existing_card| existing_card.name != card_info.name);
+        if index_reused && let Some(replaced_card) = cards_guard.remove(&card_info.index) {
+            let _ = events_tx.send(AudioEvent::CardRemoved(replaced_card));
+        }
+
+        let is_new_card = !cards_guard.contains_key(&card_info.index);
+
+        if let Some(existing_card) = cards_guard.get(&card_info.index) {
+            emit_card_change_events(existing_card, &card_info, events_tx);
+        }
+
+        cards_guard.insert(card_info.index, card_info.clone());
+
+        if is_new_card {
+            let _ = events_tx.send(AudioEvent::CardAdded(card_info));
+        }
+    }
+}
+
+/// Drop cards the server no longer reports, e.g. after reconnecting
+fn remove_unseen_cards(seen: &HashSet<CardIndex>, cards: &CardStore, events_tx: &EventSender) {
+    let removed: Vec<CardInfo> = if let Ok(mut cards_guard) = cards.write() {
+        let stale: Vec<CardIndex> = cards_guard
+            .keys()
+            .filter(|index| !seen.contains(index))
+            .copied()
+            .collect();
+
+        stale
+            .iter()
+            .filter_map(|index| cards_guard.remove(index))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for card_info in removed {
+        let _ = events_tx.send(AudioEvent::CardRemoved(card_info));
+    }
+}
+
+fn emit_card_change_events(existing_card: &CardInfo, new_card: &CardInfo, events_tx: &EventSender) {
+    if existing_card.active_profile != new_card.active_profile
+        && let Some(profile) = new_card.active_profile()
+    {
+        let _ = events_tx.send(AudioEvent::CardProfileChanged {
+            card_index: new_card.index,
+            profile: profile.clone(),
+        });
+    }
+
+    if existing_card.properties_changed(new_card) {
+        let _ = events_tx.send(AudioEvent::CardChanged(new_card.clone()));
+    }
+}
+
+/// Broadcast current card list to subscribers
+pub fn broadcast_card_list(card_list_tx: &CardListSender, cards: &CardStore) {
+    if let Ok(cards_guard) = cards.read() {
+        let card_list = cards_guard.values().cloned().collect();
+        let _ = card_list_tx.send(card_list);
+    }
+}
@@ -1,13 +1,16 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use libpulse_binding::{callbacks::ListResult, context::Context};
 use tracing::{debug, instrument, warn};
 
 use crate::services::{
-    AudioEvent, DeviceInfo,
-    audio::backend::{
-        DeviceListSender, DeviceStore, EventSender,
-        conversion::{create_device_info_from_sink, create_device_info_from_source},
+    AudioEvent, DeviceInfo, DeviceType,
+    audio::{
+        backend::{
+            DeviceListSender, DeviceStore, EventSender,
+            conversion::{create_device_info_from_sink, create_device_info_from_source},
+        },
+        device::DeviceKey,
     },
 };
 
@@ -36,13 +39,17 @@ fn discover_sinks(
     let events_tx_clone = events_tx.clone();
     let introspect = context.introspect();
 
+    let mut seen = HashSet::new();
+
     introspect.get_sink_info_list(move |result| match result {
         ListResult::Item(sink_info) => {
             let device_info = create_device_info_from_sink(sink_info);
+            seen.insert(device_info.key);
             process_device_info(device_info, &devices_clone, &events_tx_clone);
         }
         ListResult::End => {
             debug!("Completed sink discovery");
+            remove_unseen_devices(DeviceType::Output, &seen, &devices_clone, &events_tx_clone);
             broadcast_device_list(&device_list_tx_clone, &devices_clone);
         }
         ListResult::Error => {}
@@ -60,22 +67,55 @@ fn discover_sources(
     let events_tx_clone = events_tx.clone();
     let introspect = context.introspect();
 
+    let mut seen = HashSet::new();
+
     introspect.get_source_info_list(move |result| match result {
         ListResult::Item(source_info) => {
             let device_info = create_device_info_from_source(source_info);
+            seen.insert(device_info.key);
             process_device_info(device_info, &devices_clone, &events_tx_clone);
         }
         ListResult::End => {
             debug!("Completed source discovery");
+            remove_unseen_devices(DeviceType::Input, &seen, &devices_clone, &events_tx_clone);
             broadcast_device_list(&device_list_tx_clone, &devices_clone);
         }
         ListResult::Error => {}
     });
 }
 
+/// Reconcile the device store with a complete list of server devices
+///
+/// Emits the same added, changed and removed events as a discovery pass, for
+/// backends that know every device up front.
+pub fn sync_devices(
+    device_list: Vec<DeviceInfo>,
+    devices: &DeviceStore,
+    device_list_tx: &DeviceListSender,
+    events_tx: &EventSender,
+) {
+    let seen: HashSet<DeviceKey> = device_list.iter().map(|device| device.key).collect();
+
+    for device_info in device_list {
+        process_device_info(device_info, devices, events_tx);
+    }
+
+    remove_unseen_devices(DeviceType::Output, &seen, devices, events_tx);
+    remove_unseen_devices(DeviceType::Input, &seen, devices, events_tx);
+    broadcast_device_list(device_list_tx, devices);
+}
+
 fn process_device_info(device_info: DeviceInfo, devices: &DeviceStore, events_tx: &EventSender) {
     if let Ok(mut devices_guard) = devices.write() {
         let device_key = device_info.key;
+
+        let index_reused = devices_guard
+            .get(&device_key)
+            .is_some_and(|existing_device| existing_device.name != device_info.name);
+        if index_reused && let Some(replaced_device) = devices_guard.remove(&device_key) {
+            let _ = events_tx.send(AudioEvent::DeviceRemoved(replaced_device));
+        }
+
         let is_new_device = !devices_guard.contains_key(&device_key);
 
         if let Some(existing_device) = devices_guard.get(&device_key) {
@@ -90,6 +130,33 @@ fn process_device_info(device_info: DeviceInfo, devices: &DeviceStore, events_tx
     }
 }
 
+/// Drop devices the server no longer reports, e.g. after reconnecting
+fn remove_unseen_devices(
+    device_type: DeviceType,
+    seen: &HashSet<DeviceKey>,
+    devices: &DeviceStore,
+    events_tx: &EventSender,
+) {
+    let removed: Vec<DeviceInfo> = if let Ok(mut devices_guard) = devices.write() {
+        let stale: Vec<DeviceKey> = devices_guard
+            .keys()
+            .filter(|key| key.device_type == device_type && !seen.contains(key))
+            .copied()
+            .collect();
+
+        stale
+            .iter()
+            .filter_map(|key| devices_guard.remove(key))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for device_info in removed {
+        let _ = events_tx.send(AudioEvent::DeviceRemoved(device_info));
+    }
+}
+
 fn emit_device_change_events(
     existing_device: &DeviceInfo,
     new_device: &DeviceInfo,
@@ -109,6 +176,28 @@ fn emit_device_change_events(
         });
     }
 
+    if existing_device.active_port != new_device.active_port
+        && let Some(port) = new_device.active_port()
+    {
+        let _ = events_tx.send(AudioEvent::DevicePortChanged {
+            device_key: new_device.key,
+            port: port.clone(),
+        });
+    }
+
+    for port in &new_device.ports {
+        let availability_changed = existing_device
+            .port(&port.name)
+            .is_some_and(|existing_port| existing_port.available != port.available);
+
+        if availability_changed {
+            let _ = events_tx.send(AudioEvent::PortAvailabilityChanged {
+                device_key: new_device.key,
+                port: port.clone(),
+            });
+        }
+    }
+
     if existing_device.properties_changed(new_device) {
         let _ = events_tx.send(AudioEvent::DeviceChanged(new_device.clone()));
     }
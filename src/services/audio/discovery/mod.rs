@@ -1,3 +1,5 @@
+/// Card discovery and management
+pub mod card;
 /// Device discovery and management
 pub mod device;
 /// Server information queries
@@ -5,6 +7,7 @@ pub mod server;
 /// Stream discovery and management
 pub mod stream;
 
-pub use device::{broadcast_device_list, trigger_device_discovery};
+pub use card::{broadcast_card_list, sync_cards, trigger_card_discovery};
+pub use device::{broadcast_device_list, sync_devices, trigger_device_discovery};
 pub use server::trigger_server_info_query;
-pub use stream::{broadcast_stream_list, trigger_stream_discovery};
+pub use stream::{broadcast_stream_list, sync_streams, trigger_stream_discovery};
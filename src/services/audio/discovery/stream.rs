@@ -1,13 +1,18 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use libpulse_binding::{callbacks::ListResult, context::Context};
 use tracing::{debug, instrument, warn};
 
 use crate::services::{
-    AudioEvent, StreamInfo,
-    audio::backend::{
-        EventSender, StreamListSender, StreamStore,
-        conversion::{create_stream_info_from_sink_input, create_stream_info_from_source_output},
+    AudioEvent, StreamInfo, StreamType,
+    audio::{
+        backend::{
+            EventSender, StreamListSender, StreamStore,
+            conversion::{
+                create_stream_info_from_sink_input, create_stream_info_from_source_output,
+            },
+        },
+        stream::StreamKey,
     },
 };
 
@@ -36,13 +41,22 @@ fn discover_sink_inputs(
     let events_tx_clone = events_tx.clone();
     let introspect = context.introspect();
 
+    let mut seen = HashSet::new();
+
     introspect.get_sink_input_info_list(move |result| match result {
         ListResult::Item(sink_input_info) => {
             let stream_info = create_stream_info_from_sink_input(sink_input_info);
+            seen.insert(stream_info.key);
             process_stream_info(stream_info, &streams_clone, &events_tx_clone);
         }
         ListResult::End => {
             debug!("Completed sink input discovery");
+            remove_unseen_streams(
+                |stream_type| stream_type == StreamType::Playback,
+                &seen,
+                &streams_clone,
+                &events_tx_clone,
+            );
             broadcast_stream_list(&stream_list_tx_clone, &streams_clone);
         }
         ListResult::Error => {}
@@ -60,21 +74,59 @@ fn discover_source_outputs(
     let events_tx_clone = events_tx.clone();
     let introspect = context.introspect();
 
+    let mut seen = HashSet::new();
+
     introspect.get_source_output_info_list(move |result| match result {
         ListResult::Item(source_output_info) => {
             let stream_info = create_stream_info_from_source_output(source_output_info);
+            seen.insert(stream_info.key);
             process_stream_info(stream_info, &streams_clone, &events_tx_clone);
         }
         ListResult::End => {
             debug!("Completed source output discovery");
+            remove_unseen_streams(
+                |stream_type| matches!(stream_type, StreamType::Record | StreamType::Capture),
+                &seen,
+                &streams_clone,
+                &events_tx_clone,
+            );
             broadcast_stream_list(&stream_list_tx_clone, &streams_clone);
         }
         ListResult::Error => {}
     });
 }
 
+/// Reconcile the stream store with a complete list of server streams
+///
+/// Emits the same added, changed, moved and removed events as a discovery
+/// pass, for backends that know every stream up front.
+pub fn sync_streams(
+    stream_list: Vec<StreamInfo>,
+    streams: &StreamStore,
+    stream_list_tx: &StreamListSender,
+    events_tx: &EventSender,
+) {
+    let seen: HashSet<StreamKey> = stream_list.iter().map(|stream| stream.key).collect();
+
+    for stream_info in stream_list {
+        process_stream_info(stream_info, streams, events_tx);
+    }
+
+    remove_unseen_streams(|_| true, &seen, streams, events_tx);
+    broadcast_stream_list(stream_list_tx, streams);
+}
+
 fn process_stream_info(stream_info: StreamInfo, streams: &StreamStore, events_tx: &EventSender) {
     if let Ok(mut streams_guard) = streams.write() {
+        let index_reused = streams_guard
+            .get(&stream_info.key)
+            .is_some_and(|existing_stream| {
+                existing_stream.application_name != stream_info.application_name
+            });
+        if index_reused && let Some(replaced_stream) = streams_guard.remove(&stream_info.key) {
+            let _ = events_tx.send(AudioEvent::StreamRemoved(replaced_stream));
+        }
+
         let is_new_stream = !streams_guard.contains_key(&stream_info.key);
 
         if let Some(existing_stream) = streams_guard.get(&stream_info.key) {
@@ -89,6 +141,33 @@ fn process_stream_info(stream_info: StreamInfo, streams: &StreamStore, events_tx
     }
 }
 
+/// Drop streams the server no longer reports, e.g. after reconnecting
+fn remove_unseen_streams(
+    matches_type: impl Fn(StreamType) -> bool,
+    seen: &HashSet<StreamKey>,
+    streams: &StreamStore,
+    events_tx: &EventSender,
+) {
+    let removed: Vec<StreamInfo> = if let Ok(mut streams_guard) = streams.write() {
+        let stale: Vec<StreamKey> = streams_guard
+            .keys()
+            .filter(|key| matches_type(key.stream_type) && !seen.contains(key))
+            .copied()
+            .collect();
+
+        stale
+            .iter()
+            .filter_map(|key| streams_guard.remove(key))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for stream_info in removed {
+        let _ = events_tx.send(AudioEvent::StreamRemoved(stream_info));
+    }
+}
+
 fn emit_stream_change_events(
     existing_stream: &StreamInfo,
     new_stream: &StreamInfo,
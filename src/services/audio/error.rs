@@ -29,6 +29,28 @@ pub enum AudioError {
     #[error("Stream {0:?} not found")]
     StreamNotFound(u32, StreamType),
 
+    /// Device port not found
+    #[error("Port '{port}' not found on device {device}")]
+    PortNotFound {
+        /// Device index
+        device: u32,
+        /// Requested port name
+        port: String,
+    },
+
+    /// Card not found
+    #[error("Card {0} not found")]
+    CardNotFound(u32),
+
+    /// Card profile not found or not available
+    #[error("Profile '{profile}' is not available on card {card}")]
+    ProfileUnavailable {
+        /// Card index
+        card: u32,
+        /// Requested profile name
+        profile: String,
+    },
+
     /// Command channel disconnected
     #[error("Command channel disconnected")]
     CommandChannelDisconnected,
@@ -1,6 +1,7 @@
 use super::{
     DeviceIndex, Volume,
-    device::{DeviceInfo, DeviceKey},
+    card::{CardIndex, CardInfo, CardProfile},
+    device::{DefaultSwitchReason, DeviceInfo, DeviceKey, DevicePort},
     stream::{StreamInfo, StreamKey},
 };
 
@@ -27,10 +28,34 @@ pub enum AudioEvent {
     },
     /// Device state changed
     DeviceChanged(DeviceInfo),
+    /// Device switched to a different port
+    DevicePortChanged {
+        /// Device that changed
+        device_key: DeviceKey,
+        /// Newly active port
+        port: DevicePort,
+    },
+    /// A device port was plugged in or unplugged
+    PortAvailabilityChanged {
+        /// Device owning the port
+        device_key: DeviceKey,
+        /// Port with its new availability
+        port: DevicePort,
+    },
     /// Default input device changed
     DefaultInputChanged(DeviceInfo),
     /// Default output device changed
     DefaultOutputChanged(DeviceInfo),
+    /// The device priority policy switched the default device
+    ///
+    /// Followed by `DefaultInputChanged` or `DefaultOutputChanged` once the
+    /// server has applied the switch.
+    DefaultDeviceSwitched {
+        /// New default device
+        device: DeviceInfo,
+        /// Why the default was switched
+        reason: DefaultSwitchReason,
+    },
     /// Stream was added to the system
     StreamAdded(StreamInfo),
     /// Stream was removed from the system
@@ -60,4 +85,17 @@ pub enum AudioEvent {
         /// Destination device
         to_device: DeviceIndex,
     },
+    /// Card was added to the system
+    CardAdded(CardInfo),
+    /// Card was removed from the system
+    CardRemoved(CardInfo),
+    /// Card switched to a different profile
+    CardProfileChanged {
+        /// Card that changed
+        card_index: CardIndex,
+        /// Newly active profile
+        profile: CardProfile,
+    },
+    /// Card properties changed, e.g. profile availability
+    CardChanged(CardInfo),
 }
@@ -1,5 +1,9 @@
 /// PulseAudio backend implementation
 pub mod backend;
+/// Sound card and profile domain
+pub mod card;
+/// Connection state tracking
+pub mod connection;
 /// Device management domain
 pub mod device;
 /// Discovery functionality
@@ -8,6 +12,8 @@ pub mod discovery;
 pub mod error;
 /// Event types and handling
 pub mod events;
+/// Peak level metering
+pub mod peak;
 /// Audio service implementation
 mod service;
 /// Stream management domain
@@ -17,13 +23,25 @@ pub mod tokio_mainloop;
 /// Volume control domain
 pub mod volume;
 
+#[cfg(test)]
+mod tests;
+
+pub use backend::{AudioBackend, FakeBackend, FakeBackendHandle, FakePeakCommand};
+pub use card::{CardIndex, CardInfo, CardManager, CardProfile, CardStreams};
+pub use connection::{Backoff, ConnectionState};
 pub use device::{
-    DeviceIndex, DeviceInfo, DeviceManager, DeviceStreams, DeviceType, DeviceVolumeController,
+    AudioDevice, DefaultSwitchReason, DeviceIndex, DeviceInfo, DeviceManager, DevicePriority,
+    DeviceStreams, DeviceType, DeviceVolumeController, PortSwitchPolicy,
 };
 pub use error::AudioError;
 pub use events::AudioEvent;
+pub use peak::{DEFAULT_PEAK_RATE, PeakMonitor, PeakTarget};
 pub use service::AudioService;
 pub use stream::{
-    StreamIndex, StreamInfo, StreamManager, StreamStreams, StreamType, StreamVolumeController,
+    AudioStream, StreamIndex, StreamInfo, StreamManager, StreamRule, StreamRules, StreamStreams,
+    StreamType, StreamVolumeController, VOLUME_MEMORY_FILE_NAME, VOLUME_MEMORY_SAVE_DELAY,
+    VolumeMemory, VolumeSnapshot,
+};
+pub use volume::{
+    ChannelPosition, DEFAULT_MAX_VOLUME, Volume, VolumeError, VolumeScale, VolumeSettings,
 };
-pub use volume::{Volume, VolumeError};
@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, Weak},
+};
+
+use futures::Stream;
+use tokio::sync::broadcast;
+
+use super::PeakTarget;
+use crate::services::audio::{
+    AudioService,
+    backend::{CommandSender, DeviceStore, ExternalCommand, StreamStore},
+};
+
+/// Shared peak monitors, one per monitored target
+pub type PeakRegistry = Arc<Mutex<HashMap<PeakTarget, Weak<PeakLease>>>>;
+
+/// Keeps a peak monitor running while any subscriber holds it
+///
+/// Only the backend holds the sending half of the levels channel, so
+/// subscribers see the channel close when the backend drops the monitor.
+#[derive(Debug)]
+pub struct PeakLease {
+    sender: broadcast::WeakSender<f32>,
+    registry: PeakRegistry,
+    command_tx: CommandSender,
+    target: PeakTarget,
+}
+
+/// Live peak level monitoring
+pub trait PeakMonitor {
+    /// Stream of peak levels (0.0 to 1.0) for a device or stream
+    ///
+    /// Monitoring starts when the stream is first polled and stops once the
+    /// last stream for the target is dropped, so idle targets cost nothing.
+    /// `rate` is the number of levels per second; subscribers joining a
+    /// running monitor share the rate it was started with. The stream ends
+    /// immediately if the target does not exist, and ends later when the
+    /// target is removed or its monitor fails.
+    fn peak_levels(&self, target: PeakTarget, rate: u32) -> impl Stream<Item = f32> + Send;
+}
+
+impl PeakMonitor for AudioService {
+    fn peak_levels(&self, target: PeakTarget, rate: u32) -> impl Stream<Item = f32> + Send {
+        use async_stream::stream;
+
+        let registry = Arc::clone(&self.peak_registry);
+        let command_tx = self.command_tx.clone();
+        let devices = Arc::clone(&self.devices);
+        let streams = Arc::clone(&self.streams);
+
+        stream! {
+            if !target_exists(target, &devices, &streams) {
+                return;
+            }
+
+            let Some((mut levels_rx, _lease)) =
+                PeakLease::acquire(registry, command_tx, target, rate)
+            else {
+                return;
+            };
+
+            loop {
+                match levels_rx.recv().await {
+                    Ok(level) => yield level,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+}
+
+fn target_exists(target: PeakTarget, devices: &DeviceStore, streams: &StreamStore) -> bool {
+    match target {
+        PeakTarget::Device(device_key) => devices
+            .read()
+            .is_ok_and(|devices_guard| devices_guard.contains_key(&device_key)),
+        PeakTarget::Stream(stream_key) => streams
+            .read()
+            .is_ok_and(|streams_guard| streams_guard.contains_key(&stream_key)),
+    }
+}
+
+impl PeakLease {
+    fn acquire(
+        registry: PeakRegistry,
+        command_tx: CommandSender,
+        target: PeakTarget,
+        rate: u32,
+    ) -> Option<(broadcast::Receiver<f32>, Arc<Self>)> {
+        const LEVELS_BUFFER_SIZE: usize = 16;
+
+        let mut stale = None;
+        let (sender, levels_rx, lease) = {
+            let mut leases = registry.lock().ok()?;
+
+            if let Some(lease) = leases.get(&target).and_then(Weak::upgrade) {
+                match lease.sender.upgrade() {
+                    Some(sender) => return Some((sender.subscribe(), lease)),
+                    None => stale = Some(lease),
+                }
+            }
+
+            let (sender, levels_rx) = broadcast::channel(LEVELS_BUFFER_SIZE);
+            let lease = Arc::new(Self {
+                sender: sender.downgrade(),
+                registry: Arc::clone(&registry),
+                command_tx: command_tx.clone(),
+                target,
+            });
+            leases.insert(target, Arc::downgrade(&lease));
+
+            (sender, levels_rx, lease)
+        };
+
+        // A lease whose monitor already ended must not be released while the
+        // registry is locked, since its drop locks the registry itself.
+        drop(stale);
+
+        let _ = command_tx.send(ExternalCommand::StartPeakMonitor {
+            target,
+            rate: rate.max(1),
+            sender,
+        });
+
+        Some((levels_rx, lease))
+    }
+}
+
+impl Drop for PeakLease {
+    fn drop(&mut self) {
+        if let Ok(mut leases) = self.registry.lock()
+            && leases
+                .get(&self.target)
+                .is_some_and(|lease| lease.strong_count() == 0)
+        {
+            leases.remove(&self.target);
+        }
+
+        if self.sender.upgrade().is_some() {
+            let _ = self.command_tx.send(ExternalCommand::StopPeakMonitor {
+                target: self.target,
+            });
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::services::audio::{device::DeviceKey, stream::StreamKey};
+
+/// Default number of peak samples per second
+pub const DEFAULT_PEAK_RATE: u32 = 25;
+
+/// Source of peak level measurements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeakTarget {
+    /// Output devices are measured on their monitor source, input devices
+    /// directly
+    Device(DeviceKey),
+    /// Playback streams are measured on their sink's monitor, record streams
+    /// on the source they record from
+    Stream(StreamKey),
+}
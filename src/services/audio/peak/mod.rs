@@ -0,0 +1,7 @@
+/// Peak level monitoring operations
+pub mod control;
+/// Peak monitoring types
+pub mod info;
+
+pub use control::*;
+pub use info::*;
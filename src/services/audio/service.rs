@@ -1,41 +1,83 @@
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
 };
 
 use async_stream::stream;
 use async_trait::async_trait;
-use tokio::sync::{broadcast, mpsc};
+use futures::{Stream, StreamExt};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::Instant,
+};
+use tracing::warn;
+
+use crate::{config::ConfigPaths, config_runtime::ConfigRuntime, services::common::Property};
 
 use super::{
     backend::{
-        CommandSender, DefaultDevice, DeviceListSender, DeviceStore, EventSender, ExternalCommand,
-        PulseBackend, ServerInfo, StreamListSender, StreamStore,
+        AudioBackend, BackendContext, CardListSender, CardStore, CommandSender, DefaultDevice,
+        DeviceListSender, DeviceStore, EventSender, ExternalCommand, PulseBackend, ServerInfo,
+        StreamListSender, StreamStore,
+    },
+    card::{CardIndex, CardInfo, CardManager},
+    connection::ConnectionState,
+    device::{
+        AudioDevice, DefaultHistory, DefaultSwitchReason, DeviceInfo, DeviceKey, DeviceManager,
+        DeviceModels, DevicePriority, DeviceType, DeviceVolumeController, PortSwitchPolicy,
+        sync_device_models,
     },
-    device::{DeviceInfo, DeviceKey, DeviceManager, DeviceType, DeviceVolumeController},
     error::AudioError,
     events::AudioEvent,
-    stream::{StreamInfo, StreamKey, StreamManager, StreamVolumeController},
-    volume::Volume,
+    peak::PeakRegistry,
+    stream::{
+        AudioStream, StreamInfo, StreamKey, StreamManager, StreamModels, StreamRules,
+        StreamVolumeController, VOLUME_MEMORY_FILE_NAME, VOLUME_MEMORY_SAVE_DELAY, VolumeMemory,
+        application_key, device_type_for, sync_stream_models,
+    },
+    volume::{Volume, VolumeSettings},
 };
 
 /// Audio service implementation
 ///
-/// Provides device and stream management through PulseAudio backend.
+/// Provides device, stream and card management through an [`AudioBackend`],
+/// PulseAudio by default. The PulseAudio backend reconnects with exponential
+/// backoff when the server goes away; progress is visible through
+/// [`AudioService::connection_state`].
 pub struct AudioService {
     pub(super) command_tx: CommandSender,
 
     pub(super) device_list_tx: DeviceListSender,
     pub(super) stream_list_tx: StreamListSender,
+    pub(super) card_list_tx: CardListSender,
     pub(super) events_tx: EventSender,
 
     pub(super) devices: DeviceStore,
     pub(super) streams: StreamStore,
+    pub(super) cards: CardStore,
     pub(super) default_input: DefaultDevice,
     pub(super) default_output: DefaultDevice,
     pub(super) server_info: ServerInfo,
+    pub(super) peak_registry: PeakRegistry,
+    pub(super) volume_settings: Property<VolumeSettings>,
+
+    pub(super) device_models: DeviceModels,
+    pub(super) stream_models: StreamModels,
+    pub(super) device_model_list: Property<Vec<Arc<AudioDevice>>>,
+    pub(super) stream_model_list: Property<Vec<Arc<AudioStream>>>,
+    pub(super) stream_rules: Property<StreamRules>,
+    pub(super) device_priority: Property<DevicePriority>,
+    pub(super) port_switch_policy: Property<PortSwitchPolicy>,
+
+    /// Connection state of the PulseAudio server
+    pub connection_state: Property<ConnectionState>,
 
     pub(super) monitoring_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(super) port_policy_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(super) model_sync_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(super) stream_rules_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(super) device_priority_handle: Option<tokio::task::JoinHandle<()>>,
+    pub(super) config_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl Clone for AudioService {
@@ -44,13 +86,30 @@ impl Clone for AudioService {
             command_tx: self.command_tx.clone(),
             device_list_tx: self.device_list_tx.clone(),
             stream_list_tx: self.stream_list_tx.clone(),
+            card_list_tx: self.card_list_tx.clone(),
             events_tx: self.events_tx.clone(),
             devices: Arc::clone(&self.devices),
             streams: Arc::clone(&self.streams),
+            cards: Arc::clone(&self.cards),
             default_input: Arc::clone(&self.default_input),
             default_output: Arc::clone(&self.default_output),
             server_info: Arc::clone(&self.server_info),
+            peak_registry: Arc::clone(&self.peak_registry),
+            volume_settings: self.volume_settings.clone(),
+            device_models: Arc::clone(&self.device_models),
+            stream_models: Arc::clone(&self.stream_models),
+            device_model_list: self.device_model_list.clone(),
+            stream_model_list: self.stream_model_list.clone(),
+            stream_rules: self.stream_rules.clone(),
+            device_priority: self.device_priority.clone(),
+            port_switch_policy: self.port_switch_policy.clone(),
+            connection_state: self.connection_state.clone(),
             monitoring_handle: None,
+            port_policy_handle: None,
+            model_sync_handle: None,
+            stream_rules_handle: None,
+            device_priority_handle: None,
+            config_handle: None,
         }
     }
 }
@@ -58,52 +117,734 @@ impl Clone for AudioService {
 impl AudioService {
     /// Create a new PulseAudio service with default settings
     ///
+    /// Returns without waiting for the sound server. If it cannot be reached,
+    /// the connection is retried in the background and progress is reported
+    /// through [`AudioService::connection_state`].
+    ///
     /// # Errors
-    /// Returns error if PulseAudio connection fails or service initialization fails
+    /// Returns error if the backend task cannot be started
     pub async fn new() -> Result<Self, AudioError> {
+        Self::with_backend(PulseBackend).await
+    }
+
+    /// Create a service driven by the given backend
+    ///
+    /// Pass a [`FakeBackend`](super::backend::FakeBackend) to exercise audio
+    /// consumers without a running sound server.
+    ///
+    /// # Errors
+    /// Returns error if the backend fails to start
+    pub async fn with_backend<B: AudioBackend>(backend: B) -> Result<Self, AudioError> {
         const DEVICE_BUFFER_SIZE: usize = 100;
         const STREAM_BUFFER_SIZE: usize = 100;
+        const CARD_BUFFER_SIZE: usize = 100;
         const EVENTS_BUFFER_SIZE: usize = 100;
 
         let (command_tx, command_rx) = mpsc::unbounded_channel();
 
         let (device_list_tx, _) = broadcast::channel(DEVICE_BUFFER_SIZE);
         let (stream_list_tx, _) = broadcast::channel(STREAM_BUFFER_SIZE);
+        let (card_list_tx, _) = broadcast::channel(CARD_BUFFER_SIZE);
         let (events_tx, _) = broadcast::channel(EVENTS_BUFFER_SIZE);
 
         let devices = Arc::new(RwLock::new(HashMap::new()));
         let streams = Arc::new(RwLock::new(HashMap::new()));
+        let cards = Arc::new(RwLock::new(HashMap::new()));
         let default_input = Arc::new(RwLock::new(None));
         let default_output = Arc::new(RwLock::new(None));
         let server_info = Arc::new(RwLock::new(None));
+        let connection_state = Property::new(ConnectionState::Connecting);
 
-        let monitoring_handle = PulseBackend::spawn_monitoring_task(
-            command_rx,
-            device_list_tx.clone(),
-            stream_list_tx.clone(),
-            events_tx.clone(),
-            Arc::clone(&devices),
-            Arc::clone(&streams),
-            Arc::clone(&default_input),
-            Arc::clone(&default_output),
-            Arc::clone(&server_info),
-        )
-        .await?;
-
-        Ok(AudioService {
+        let device_list_rx = device_list_tx.subscribe();
+        let stream_list_rx = stream_list_tx.subscribe();
+
+        let monitoring_handle = backend
+            .spawn(BackendContext {
+                command_rx,
+                device_list_tx: device_list_tx.clone(),
+                stream_list_tx: stream_list_tx.clone(),
+                card_list_tx: card_list_tx.clone(),
+                events_tx: events_tx.clone(),
+                devices: Arc::clone(&devices),
+                streams: Arc::clone(&streams),
+                cards: Arc::clone(&cards),
+                default_input: Arc::clone(&default_input),
+                default_output: Arc::clone(&default_output),
+                server_info: Arc::clone(&server_info),
+                connection_state: connection_state.clone(),
+            })
+            .await?;
+
+        let mut service = AudioService {
             command_tx,
             device_list_tx,
             stream_list_tx,
+            card_list_tx,
             events_tx,
             devices,
             streams,
+            cards,
             default_input,
             default_output,
             server_info,
+            peak_registry: Arc::new(Mutex::new(HashMap::new())),
+            volume_settings: Property::new(VolumeSettings::default()),
+            device_models: Arc::new(RwLock::new(HashMap::new())),
+            stream_models: Arc::new(RwLock::new(HashMap::new())),
+            device_model_list: Property::new(Vec::new()),
+            stream_model_list: Property::new(Vec::new()),
+            stream_rules: Property::new(StreamRules::default()),
+            device_priority: Property::new(DevicePriority::default()),
+            port_switch_policy: Property::new(PortSwitchPolicy::default()),
+            connection_state,
             monitoring_handle: Some(monitoring_handle),
+            port_policy_handle: None,
+            model_sync_handle: None,
+            stream_rules_handle: None,
+            device_priority_handle: None,
+            config_handle: None,
+        };
+        service.model_sync_handle = Some(service.spawn_model_sync(device_list_rx, stream_list_rx));
+
+        Ok(service)
+    }
+
+    /// Apply a port switching policy to all devices
+    ///
+    /// With [`PortSwitchPolicy::PreferHigherPriority`], a device switches to a
+    /// port as soon as it becomes available if it outranks the active port.
+    /// Replaces any previously applied policy.
+    pub fn with_port_switch_policy(mut self, policy: PortSwitchPolicy) -> Self {
+        if let Some(handle) = self.port_policy_handle.take() {
+            handle.abort();
+        }
+
+        self.port_switch_policy.set(policy);
+        self.port_policy_handle = Some(self.spawn_port_auto_switch());
+        self
+    }
+
+    /// Replace the port switching policy, e.g. after the configuration was
+    /// reloaded
+    ///
+    /// The new policy applies from the next port availability change.
+    pub fn set_port_switch_policy(&self, policy: PortSwitchPolicy) {
+        self.port_switch_policy.set(policy);
+    }
+
+    /// Get the port switching policy currently applied
+    pub fn port_switch_policy(&self) -> PortSwitchPolicy {
+        self.port_switch_policy.get()
+    }
+
+    /// Set the scale and maximum used by the volume level and step operations
+    pub fn with_volume_settings(self, settings: VolumeSettings) -> Self {
+        self.volume_settings.set(settings);
+        self
+    }
+
+    /// Replace the volume settings, e.g. after the configuration was reloaded
+    pub fn set_volume_settings(&self, settings: VolumeSettings) {
+        self.volume_settings.set(settings);
+    }
+
+    /// Get the scale and maximum used by the volume level and step operations
+    pub fn volume_settings(&self) -> VolumeSettings {
+        self.volume_settings.get()
+    }
+
+    /// Apply per-application rules to streams as they appear
+    ///
+    /// A new stream matching a rule is moved to the rule's device and set
+    /// to its volume. Without a volume rule, the application's last volume
+    /// is restored from `memory` when `rules.remember_volume` is set.
+    /// Replaces any previously applied rules.
+    pub fn with_stream_rules(mut self, rules: StreamRules, memory: VolumeMemory) -> Self {
+        if let Some(handle) = self.stream_rules_handle.take() {
+            handle.abort();
+        }
+
+        self.stream_rules.set(rules);
+        self.stream_rules_handle = Some(self.spawn_stream_rules(memory));
+        self
+    }
+
+    /// Replace the stream rules, e.g. after the configuration was reloaded
+    ///
+    /// Rules that are new or changed are applied to existing streams right
+    /// away; unchanged rules only affect streams that appear later.
+    pub fn set_stream_rules(&self, rules: StreamRules) {
+        self.stream_rules.set(rules);
+    }
+
+    /// Get the rules currently applied to streams
+    pub fn stream_rules(&self) -> StreamRules {
+        self.stream_rules.get()
+    }
+
+    /// Choose default devices from an ordered priority list
+    ///
+    /// A new device ranking above the current default becomes the default.
+    /// When the default device disappears, the default returns to the
+    /// best-ranked remaining device, or else to the most recent previous
+    /// default, instead of the server's pick. Each switch is announced with
+    /// [`AudioEvent::DefaultDeviceSwitched`]. Replaces any previously
+    /// applied priority.
+    pub fn with_device_priority(mut self, priority: DevicePriority) -> Self {
+        if let Some(handle) = self.device_priority_handle.take() {
+            handle.abort();
+        }
+
+        self.device_priority.set(priority);
+        self.device_priority_handle = Some(self.spawn_device_priority());
+        self
+    }
+
+    /// Replace the device priority, e.g. after the configuration was reloaded
+    ///
+    /// The new priority applies from the next device that appears or
+    /// disappears.
+    pub fn set_device_priority(&self, priority: DevicePriority) {
+        self.device_priority.set(priority);
+    }
+
+    /// Get the device priority currently applied
+    pub fn device_priority(&self) -> DevicePriority {
+        self.device_priority.get()
+    }
+
+    /// Create a PulseAudio service configured from the `audio` config section
+    ///
+    /// Remembered application volumes are kept in [`VOLUME_MEMORY_FILE_NAME`]
+    /// in the app data directory. See [`AudioService::with_config`] for how
+    /// configuration changes are followed.
+    ///
+    /// # Errors
+    /// Returns error if the backend task cannot be started or the
+    /// configuration cannot be subscribed to
+    pub async fn from_config(config_runtime: &ConfigRuntime) -> Result<Self, AudioError> {
+        let memory = match ConfigPaths::app_data_dir() {
+            Ok(dir) => VolumeMemory::load(dir.join(VOLUME_MEMORY_FILE_NAME)),
+            Err(e) => {
+                warn!("Cannot locate app data directory, app volumes will not persist: {e}");
+                VolumeMemory::in_memory()
+            }
+        };
+
+        Self::new().await?.with_config(config_runtime, memory).await
+    }
+
+    /// Apply the `audio` config section and follow its changes
+    ///
+    /// Volume settings, stream rules, device priority and the port switch
+    /// policy are taken from the current configuration and replaced whenever
+    /// an `audio.*` value changes. `memory` holds the remembered application volumes.
+    ///
+    /// # Errors
+    /// Returns error if the configuration cannot be subscribed to
+    pub async fn with_config(
+        self,
+        config_runtime: &ConfigRuntime,
+        memory: VolumeMemory,
+    ) -> Result<Self, AudioError> {
+        let mut subscription = config_runtime
+            .subscribe_to_path("audio.*")
+            .await
+            .map_err(|e| AudioError::InitializationFailed(format!("audio config: {e}")))?;
+
+        let audio = config_runtime.get_current().audio;
+        let mut service = self
+            .with_volume_settings(audio.volume_settings())
+            .with_stream_rules(audio.stream_rules(), memory)
+            .with_device_priority(audio.device_priority())
+            .with_port_switch_policy(audio.port_switch);
+
+        if let Some(handle) = service.config_handle.take() {
+            handle.abort();
+        }
+
+        let config_runtime = config_runtime.clone();
+        let target = service.clone();
+        service.config_handle = Some(tokio::spawn(async move {
+            while subscription.receiver_mut().recv().await.is_some() {
+                let audio = config_runtime.get_current().audio;
+                target.set_volume_settings(audio.volume_settings());
+                target.set_stream_rules(audio.stream_rules());
+                target.set_device_priority(audio.device_priority());
+                target.set_port_switch_policy(audio.port_switch);
+            }
+        }));
+
+        Ok(service)
+    }
+
+    /// Applies the device priority on hotplug
+    ///
+    /// Default changes made by the server because the default device went
+    /// away are not recorded as previous defaults, so the removal can be
+    /// answered with the device that was default before.
+    fn spawn_device_priority(&self) -> tokio::task::JoinHandle<()> {
+        let mut events_rx = self.events_tx.subscribe();
+        let priority = self.device_priority.clone();
+        let service = self.clone();
+
+        let mut input_history = DefaultHistory::default();
+        let mut output_history = DefaultHistory::default();
+        for (default_device, history) in [
+            (&self.default_input, &mut input_history),
+            (&self.default_output, &mut output_history),
+        ] {
+            if let Ok(default_guard) = default_device.read()
+                && let Some(device) = default_guard.as_ref()
+            {
+                history.push(device.name.clone());
+            }
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let event = match events_rx.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                match event {
+                    AudioEvent::DeviceAdded(device) => {
+                        let history = match device.device_type {
+                            DeviceType::Input => &mut input_history,
+                            DeviceType::Output => &mut output_history,
+                        };
+                        service
+                            .prefer_added_device(device, &priority.get(), history)
+                            .await;
+                    }
+                    AudioEvent::DeviceRemoved(device) => {
+                        let history = match device.device_type {
+                            DeviceType::Input => &mut input_history,
+                            DeviceType::Output => &mut output_history,
+                        };
+                        service
+                            .replace_removed_default(&device, &priority.get(), history)
+                            .await;
+                    }
+                    AudioEvent::DefaultInputChanged(device) => {
+                        service.record_default(device, &mut input_history);
+                    }
+                    AudioEvent::DefaultOutputChanged(device) => {
+                        service.record_default(device, &mut output_history);
+                    }
+                    _ => {}
+                }
+            }
+        })
+    }
+
+    async fn prefer_added_device(
+        &self,
+        device: DeviceInfo,
+        priority: &DevicePriority,
+        history: &mut DefaultHistory,
+    ) {
+        let Some(rank) = priority.rank(&device) else {
+            return;
+        };
+        if history.current() == Some(&device.name) {
+            return;
+        }
+
+        let current_rank = match self.devices_by_type(device.device_type).await {
+            Ok(devices) => devices
+                .iter()
+                .find(|candidate| Some(&candidate.name) == history.current())
+                .and_then(|current| priority.rank(current)),
+            Err(_) => None,
+        };
+        if current_rank.is_some_and(|current_rank| current_rank <= rank) {
+            return;
+        }
+
+        self.switch_default(device, DefaultSwitchReason::PreferredDeviceAdded, history)
+            .await;
+    }
+
+    async fn replace_removed_default(
+        &self,
+        removed: &DeviceInfo,
+        priority: &DevicePriority,
+        history: &mut DefaultHistory,
+    ) {
+        if history.current() != Some(&removed.name) {
+            return;
+        }
+
+        let Ok(candidates) = self.devices_by_type(removed.device_type).await else {
+            return;
+        };
+        if let Some(device) = history.fallback(removed, &candidates, priority) {
+            self.switch_default(
+                device.clone(),
+                DefaultSwitchReason::DefaultDeviceRemoved,
+                history,
+            )
+            .await;
+        }
+    }
+
+    async fn switch_default(
+        &self,
+        device: DeviceInfo,
+        reason: DefaultSwitchReason,
+        history: &mut DefaultHistory,
+    ) {
+        let result = match device.device_type {
+            DeviceType::Input => self.set_default_input(device.key).await,
+            DeviceType::Output => self.set_default_output(device.key).await,
+        };
+        if let Err(e) = result {
+            warn!("Cannot switch default device to '{}': {e}", device.name);
+            return;
+        }
+
+        history.push(device.name.clone());
+        let _ = self
+            .events_tx
+            .send(AudioEvent::DefaultDeviceSwitched { device, reason });
+    }
+
+    /// Record a default change unless the server made it because the
+    /// recorded default disappeared
+    fn record_default(&self, device: DeviceInfo, history: &mut DefaultHistory) {
+        let current_gone = history.current().is_some_and(|current| {
+            self.devices.read().is_ok_and(|devices_guard| {
+                !devices_guard
+                    .values()
+                    .any(|known| known.name == *current && known.device_type == device.device_type)
+            })
+        });
+
+        if !current_gone {
+            history.push(device.name);
+        }
+    }
+
+    /// Applies stream rules to new streams and remembers application volumes
+    ///
+    /// Application keys are tracked from the event stream itself, so a
+    /// volume change is attributed correctly even when the stream is gone
+    /// from the store by the time the event is handled.
+    fn spawn_stream_rules(&self, mut memory: VolumeMemory) -> tokio::task::JoinHandle<()> {
+        let mut events_rx = self.events_tx.subscribe();
+        let mut rule_updates = Box::pin(self.stream_rules.watch());
+        let mut rules = self.stream_rules.get();
+        let mut applications: HashMap<StreamKey, String> = self
+            .streams
+            .read()
+            .map(|streams_guard| {
+                streams_guard
+                    .values()
+                    .filter_map(|stream| Some((stream.key, application_key(stream)?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let service = self.clone();
+
+        tokio::spawn(async move {
+            let mut save_at: Option<Instant> = None;
+
+            loop {
+                tokio::select! {
+                    event = events_rx.recv() => match event {
+                        Ok(AudioEvent::StreamAdded(stream)) => {
+                            let key = application_key(&stream);
+                            let remembered = key
+                                .as_ref()
+                                .filter(|_| rules.remember_volume)
+                                .and_then(|key| memory.get(key));
+                            if let Some(key) = key {
+                                applications.insert(stream.key, key);
+                            }
+                            service.apply_stream_rules(&stream, &rules, remembered).await;
+                        }
+                        Ok(AudioEvent::StreamRemoved(stream)) => {
+                            applications.remove(&stream.key);
+                        }
+                        Ok(AudioEvent::StreamVolumeChanged { stream_key, volume })
+                            if rules.remember_volume =>
+                        {
+                            let Some(key) = applications.get(&stream_key) else {
+                                continue;
+                            };
+                            memory.remember(key.clone(), volume.average());
+                            save_at = Some(Instant::now() + VOLUME_MEMORY_SAVE_DELAY);
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    Some(updated) = rule_updates.next() => {
+                        service.apply_changed_stream_rules(&rules, &updated).await;
+                        rules = updated;
+                    }
+                    () = tokio::time::sleep_until(save_at.unwrap_or_else(Instant::now)),
+                        if save_at.is_some() =>
+                    {
+                        save_at = None;
+                        save_volume_memory(&mut memory).await;
+                    }
+                }
+            }
+
+            save_volume_memory(&mut memory).await;
         })
     }
 
+    async fn apply_stream_rules(
+        &self,
+        stream: &StreamInfo,
+        rules: &StreamRules,
+        remembered: Option<f64>,
+    ) {
+        let settings = self.volume_settings.get();
+        let factor = rules
+            .volume_for(stream)
+            .map(|level| settings.scale.to_factor(level))
+            .or(remembered);
+        if let Some(factor) = factor {
+            let mut volume = stream.volume.clone();
+            volume.set_all(factor.min(settings.max));
+            if volume.as_slice() != stream.volume.as_slice()
+                && let Err(e) = self.set_stream_volume(stream.key, volume).await
+            {
+                warn!(
+                    "Cannot apply volume rule to '{}': {e}",
+                    stream.application_name
+                );
+            }
+        }
+
+        let Some(rule) = rules.routing_for(stream) else {
+            return;
+        };
+        let devices = match self
+            .devices_by_type(device_type_for(stream.stream_type))
+            .await
+        {
+            Ok(devices) => devices,
+            Err(e) => {
+                warn!(
+                    "Cannot apply routing rule to '{}': {e}",
+                    stream.application_name
+                );
+                return;
+            }
+        };
+
+        if let Some(device) = devices.iter().find(|device| rule.targets_device(device))
+            && device.index != stream.device_index
+            && let Err(e) = self.move_stream(stream.key, device.key).await
+        {
+            warn!(
+                "Cannot apply routing rule to '{}': {e}",
+                stream.application_name
+            );
+        }
+    }
+
+    async fn apply_changed_stream_rules(&self, previous: &StreamRules, updated: &StreamRules) {
+        let changed = StreamRules {
+            rules: updated
+                .rules
+                .iter()
+                .filter(|rule| !previous.rules.contains(rule))
+                .cloned()
+                .collect(),
+            remember_volume: false,
+        };
+        if changed.rules.is_empty() {
+            return;
+        }
+
+        let streams: Vec<StreamInfo> = match self.streams.read() {
+            Ok(streams_guard) => streams_guard.values().cloned().collect(),
+            Err(_) => return,
+        };
+        for stream in streams {
+            self.apply_stream_rules(&stream, &changed, None).await;
+        }
+    }
+
+    fn spawn_port_auto_switch(&self) -> tokio::task::JoinHandle<()> {
+        let mut events_rx = self.events_tx.subscribe();
+        let devices = Arc::clone(&self.devices);
+        let command_tx = self.command_tx.clone();
+        let policy = self.port_switch_policy.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (device_key, port) = match events_rx.recv().await {
+                    Ok(AudioEvent::PortAvailabilityChanged { device_key, port }) => {
+                        (device_key, port)
+                    }
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                if policy.get() == PortSwitchPolicy::Manual {
+                    continue;
+                }
+
+                let should_switch = devices.read().is_ok_and(|devices_guard| {
+                    devices_guard
+                        .get(&device_key)
+                        .is_some_and(|device| device.prefers_port(&port.name))
+                });
+
+                if should_switch {
+                    let _ = command_tx.send(ExternalCommand::SetDevicePort {
+                        device_key,
+                        port_name: port.name,
+                    });
+                }
+            }
+        })
+    }
+
+    /// Keeps the reactive device and stream models in line with the backend
+    ///
+    /// Lists are full snapshots, so a lagging receiver loses nothing by
+    /// skipping to the newest one.
+    fn spawn_model_sync(
+        &self,
+        mut device_list_rx: broadcast::Receiver<Vec<DeviceInfo>>,
+        mut stream_list_rx: broadcast::Receiver<Vec<StreamInfo>>,
+    ) -> tokio::task::JoinHandle<()> {
+        let device_models = Arc::clone(&self.device_models);
+        let stream_models = Arc::clone(&self.stream_models);
+        let device_model_list = self.device_model_list.clone();
+        let stream_model_list = self.stream_model_list.clone();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    device_list = device_list_rx.recv() => match device_list {
+                        Ok(device_list) => {
+                            sync_device_models(&device_list, &device_models, &device_model_list);
+                        }
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    stream_list = stream_list_rx.recv() => match stream_list {
+                        Ok(stream_list) => {
+                            sync_stream_models(&stream_list, &stream_models, &stream_model_list);
+                        }
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                }
+            }
+        })
+    }
+
+    /// Get a live-updating model of a device
+    ///
+    /// The returned model is shared by every caller and its properties
+    /// update as the device changes.
+    ///
+    /// # Errors
+    /// Returns `AudioError::DeviceNotFound` if the device doesn't exist
+    pub fn device_monitored(&self, device_key: DeviceKey) -> Result<Arc<AudioDevice>, AudioError> {
+        if let Some(model) = self.cached_device_model(device_key)? {
+            return Ok(model);
+        }
+
+        let device_list: Vec<DeviceInfo> = self
+            .devices
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?
+            .values()
+            .cloned()
+            .collect();
+        sync_device_models(&device_list, &self.device_models, &self.device_model_list);
+
+        self.cached_device_model(device_key)?
+            .ok_or(AudioError::DeviceNotFound(
+                device_key.index,
+                device_key.device_type,
+            ))
+    }
+
+    /// Get a live-updating model of a stream
+    ///
+    /// The returned model is shared by every caller and its properties
+    /// update as the stream changes.
+    ///
+    /// # Errors
+    /// Returns `AudioError::StreamNotFound` if the stream doesn't exist
+    pub fn stream_monitored(&self, stream_key: StreamKey) -> Result<Arc<AudioStream>, AudioError> {
+        if let Some(model) = self.cached_stream_model(stream_key)? {
+            return Ok(model);
+        }
+
+        let stream_list: Vec<StreamInfo> = self
+            .streams
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?
+            .values()
+            .cloned()
+            .collect();
+        sync_stream_models(&stream_list, &self.stream_models, &self.stream_model_list);
+
+        self.cached_stream_model(stream_key)?
+            .ok_or(AudioError::StreamNotFound(
+                stream_key.index,
+                stream_key.stream_type,
+            ))
+    }
+
+    /// Get the live-updating models of all devices
+    pub fn audio_devices(&self) -> Vec<Arc<AudioDevice>> {
+        self.device_model_list.get()
+    }
+
+    /// Get a stream that emits the device models whenever devices are added
+    /// or removed
+    pub fn audio_devices_monitored(&self) -> impl Stream<Item = Vec<Arc<AudioDevice>>> + Send {
+        self.device_model_list.watch()
+    }
+
+    /// Get the live-updating models of all streams
+    pub fn audio_streams(&self) -> Vec<Arc<AudioStream>> {
+        self.stream_model_list.get()
+    }
+
+    /// Get a stream that emits the stream models whenever streams are added
+    /// or removed
+    pub fn audio_streams_monitored(&self) -> impl Stream<Item = Vec<Arc<AudioStream>>> + Send {
+        self.stream_model_list.watch()
+    }
+
+    fn cached_device_model(
+        &self,
+        device_key: DeviceKey,
+    ) -> Result<Option<Arc<AudioDevice>>, AudioError> {
+        let models = self
+            .device_models
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
+        Ok(models.get(&device_key).cloned())
+    }
+
+    fn cached_stream_model(
+        &self,
+        stream_key: StreamKey,
+    ) -> Result<Option<Arc<AudioStream>>, AudioError> {
+        let models = self
+            .stream_models
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
+        Ok(models.get(&stream_key).cloned())
+    }
+
     /// Gracefully shutdown the service
     ///
     /// Stops background monitoring and cleans up resources.
@@ -131,11 +872,44 @@ impl AudioService {
     }
 }
 
+/// Write changed remembered volumes from the blocking thread pool
+async fn save_volume_memory(memory: &mut VolumeMemory) {
+    let Some(snapshot) = memory.take_changes() else {
+        return;
+    };
+
+    match tokio::task::spawn_blocking(move || snapshot.write()).await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => warn!("Cannot save remembered application volumes: {e}"),
+        Err(e) => warn!("Saving remembered application volumes failed: {e}"),
+    }
+}
+
 impl Drop for AudioService {
     fn drop(&mut self) {
         if let Some(handle) = self.monitoring_handle.take() {
             handle.abort();
         }
+
+        if let Some(handle) = self.port_policy_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.model_sync_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.stream_rules_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.device_priority_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.config_handle.take() {
+            handle.abort();
+        }
     }
 }
 
@@ -200,6 +974,28 @@ impl DeviceManager for AudioService {
             .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
         Ok(())
     }
+
+    async fn set_device_port(
+        &self,
+        device_key: DeviceKey,
+        port_name: &str,
+    ) -> Result<(), Self::Error> {
+        let device_info = self.device(device_key).await?;
+        if device_info.port(port_name).is_none() {
+            return Err(AudioError::PortNotFound {
+                device: device_key.index,
+                port: port_name.to_string(),
+            });
+        }
+
+        self.command_tx
+            .send(ExternalCommand::SetDevicePort {
+                device_key,
+                port_name: port_name.to_string(),
+            })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -211,20 +1007,12 @@ impl DeviceVolumeController for AudioService {
         device_key: DeviceKey,
         level: f64,
     ) -> Result<(), Self::Error> {
-        println!(
-            "Setting volume for device '{}-{:?}': {}",
-            device_key.index, device_key.device_type, level
-        );
-        let device_info = self.device(device_key).await?;
-        let channel_count = device_info.volume.channels();
-        let volume = Volume::new(vec![level; channel_count]);
-        let pulse_volume = PulseBackend::convert_volume_to_pulse(&volume);
+        let settings = self.volume_settings.get();
+        let mut volume = self.device(device_key).await?.volume;
+        volume.set_all(settings.scale.to_factor(level).min(settings.max));
         self.command_tx
-            .send(ExternalCommand::SetDeviceVolume {
-                device_key,
-                volume: pulse_volume,
-            })
-            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
+            .send(ExternalCommand::SetDeviceVolume { device_key, volume })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
         Ok(())
     }
 
@@ -234,6 +1022,32 @@ impl DeviceVolumeController for AudioService {
             .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
         Ok(())
     }
+
+    async fn step_device_volume(
+        &self,
+        device_key: DeviceKey,
+        step: f64,
+    ) -> Result<(), Self::Error> {
+        let device_info = self.device(device_key).await?;
+        let volume = device_info.volume.stepped(step, self.volume_settings.get());
+        self.command_tx
+            .send(ExternalCommand::SetDeviceVolume { device_key, volume })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
+        Ok(())
+    }
+
+    async fn set_device_balance(
+        &self,
+        device_key: DeviceKey,
+        balance: f64,
+    ) -> Result<(), Self::Error> {
+        let mut volume = self.device(device_key).await?.volume;
+        volume.set_balance(balance);
+        self.command_tx
+            .send(ExternalCommand::SetDeviceVolume { device_key, volume })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -278,12 +1092,8 @@ impl StreamVolumeController for AudioService {
         stream_key: StreamKey,
         volume: Volume,
     ) -> Result<(), Self::Error> {
-        let pulse_volume = PulseBackend::convert_volume_to_pulse(&volume);
         self.command_tx
-            .send(ExternalCommand::SetStreamVolume {
-                stream_key,
-                volume: pulse_volume,
-            })
+            .send(ExternalCommand::SetStreamVolume { stream_key, volume })
             .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
         Ok(())
     }
@@ -294,4 +1104,66 @@ impl StreamVolumeController for AudioService {
             .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
         Ok(())
     }
+
+    async fn step_stream_volume(
+        &self,
+        stream_key: StreamKey,
+        step: f64,
+    ) -> Result<(), Self::Error> {
+        let stream_info = self.stream(stream_key).await?;
+        let volume = stream_info.volume.stepped(step, self.volume_settings.get());
+        self.command_tx
+            .send(ExternalCommand::SetStreamVolume { stream_key, volume })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CardManager for AudioService {
+    type Error = AudioError;
+
+    async fn card(&self, card_index: CardIndex) -> Result<CardInfo, Self::Error> {
+        let cards = self
+            .cards
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
+        cards
+            .get(&card_index)
+            .cloned()
+            .ok_or(AudioError::CardNotFound(card_index.0))
+    }
+
+    async fn list_cards(&self) -> Result<Vec<CardInfo>, Self::Error> {
+        let cards = self
+            .cards
+            .read()
+            .map_err(|e| AudioError::LockPoisoned(format!("shared data lock: {e}")))?;
+        Ok(cards.values().cloned().collect())
+    }
+
+    async fn set_card_profile(
+        &self,
+        card_index: CardIndex,
+        profile_name: &str,
+    ) -> Result<(), Self::Error> {
+        let card_info = self.card(card_index).await?;
+        if !card_info
+            .profile(profile_name)
+            .is_some_and(|profile| profile.available)
+        {
+            return Err(AudioError::ProfileUnavailable {
+                card: card_index.0,
+                profile: profile_name.to_string(),
+            });
+        }
+
+        self.command_tx
+            .send(ExternalCommand::SetCardProfile {
+                card_index,
+                profile_name: profile_name.to_string(),
+            })
+            .map_err(|_| AudioError::CommandChannelDisconnected)?;
+        Ok(())
+    }
 }
@@ -53,6 +53,16 @@ pub trait StreamVolumeController {
     /// # Errors
     /// Returns error if stream is not found or operation fails
     async fn set_stream_mute(&self, stream_key: StreamKey, muted: bool) -> Result<(), Self::Error>;
+
+    /// Step stream volume on the configured volume scale
+    ///
+    /// `step` is a fraction of the scale, e.g. `0.05` or `-0.05`; the result
+    /// is capped at the service's maximum volume.
+    ///
+    /// # Errors
+    /// Returns error if stream is not found or operation fails
+    async fn step_stream_volume(&self, stream_key: StreamKey, step: f64)
+    -> Result<(), Self::Error>;
 }
 
 /// Stream monitoring streams
@@ -180,6 +180,8 @@ pub struct StreamInfo {
     pub name: String,
     /// Application name
     pub application_name: String,
+    /// Application binary, empty if not reported
+    pub application_binary: String,
     /// Stream type
     pub stream_type: StreamType,
     /// Stream state
@@ -210,6 +212,7 @@ impl StreamInfo {
     pub fn properties_changed(&self, other: &StreamInfo) -> bool {
         self.name != other.name
             || self.application_name != other.application_name
+            || self.application_binary != other.application_binary
             || self.stream_type != other.stream_type
             || self.state != other.state
             || self.format != other.format
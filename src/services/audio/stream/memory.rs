@@ -0,0 +1,104 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, time::Duration};
+
+use tracing::warn;
+
+/// File name the remembered application volumes are stored under
+pub const VOLUME_MEMORY_FILE_NAME: &str = "app-volumes.json";
+
+/// Quiet period after the last volume change before remembered volumes are
+/// written, so a burst of changes such as a slider drag is saved once
+pub const VOLUME_MEMORY_SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// Last volume of each application, persisted across restarts
+///
+/// Volumes are keyed by [`application_key`](super::application_key) and
+/// written back to disk only when they changed, via [`VolumeMemory::take_changes`].
+#[derive(Debug, Default)]
+pub struct VolumeMemory {
+    path: Option<PathBuf>,
+    volumes: HashMap<String, f64>,
+    dirty: bool,
+}
+
+impl VolumeMemory {
+    /// Load remembered volumes from a JSON file
+    ///
+    /// A missing or unreadable file starts an empty memory that will be
+    /// written to `path` on the next save.
+    pub fn load(path: PathBuf) -> Self {
+        let volumes = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                warn!("Invalid volume memory file {}: {e}", path.display());
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(),
+        };
+
+        Self {
+            path: Some(path),
+            volumes,
+            dirty: false,
+        }
+    }
+
+    /// Create a memory that is never written to disk
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Get the remembered volume of an application
+    pub fn get(&self, application: &str) -> Option<f64> {
+        self.volumes.get(application).copied()
+    }
+
+    /// Remember the volume of an application
+    pub fn remember(&mut self, application: String, volume: f64) {
+        if self.volumes.get(&application) != Some(&volume) {
+            self.volumes.insert(application, volume);
+            self.dirty = true;
+        }
+    }
+
+    /// Take a snapshot of the remembered volumes for saving
+    ///
+    /// Returns `None` if nothing changed since the last snapshot or the
+    /// memory is not backed by a file.
+    pub fn take_changes(&mut self) -> Option<VolumeSnapshot> {
+        let path = self.path.as_ref().filter(|_| self.dirty)?;
+        let snapshot = VolumeSnapshot {
+            path: path.clone(),
+            volumes: self.volumes.clone(),
+        };
+        self.dirty = false;
+
+        Some(snapshot)
+    }
+}
+
+/// Remembered volumes captured by [`VolumeMemory::take_changes`]
+#[derive(Debug)]
+pub struct VolumeSnapshot {
+    path: PathBuf,
+    volumes: HashMap<String, f64>,
+}
+
+impl VolumeSnapshot {
+    /// Write the volumes to disk
+    ///
+    /// The file is written next to its destination and renamed into place,
+    /// so an interrupted write leaves the previous file intact. This blocks
+    /// on the filesystem.
+    ///
+    /// # Errors
+    /// Returns error if the file or its directory cannot be written
+    pub fn write(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(&self.volumes).map_err(Error::other)?;
+        let partial = self.path.with_extension("part");
+        fs::write(&partial, content)?;
+        fs::rename(&partial, &self.path)
+    }
+}
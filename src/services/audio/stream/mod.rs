@@ -2,6 +2,15 @@
 pub mod control;
 /// Stream information types and structures
 pub mod info;
+/// Remembered per-application volumes
+pub mod memory;
+/// Reactive stream model
+pub mod model;
+/// Per-application volume and routing rules
+pub mod rules;
 
 pub use control::*;
 pub use info::*;
+pub use memory::*;
+pub use model::*;
+pub use rules::*;
@@ -0,0 +1,116 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use futures::Stream;
+
+use super::{StreamInfo, StreamKey, StreamState};
+use crate::{
+    services::{
+        audio::{device::DeviceIndex, volume::Volume},
+        common::Property,
+    },
+    watch_all,
+};
+
+/// Shared reactive stream models keyed by stream
+pub type StreamModels = Arc<RwLock<HashMap<StreamKey, Arc<AudioStream>>>>;
+
+/// Reactive stream model with fine-grained property updates.
+///
+/// Each property can be watched independently, e.g. a per-application
+/// volume slider binds to `volume` and `muted` only. Properties are kept in
+/// sync with the backend by the audio service; a removed stream simply
+/// stops updating.
+#[derive(Clone, Debug)]
+pub struct AudioStream {
+    /// Unique stream key
+    pub key: StreamKey,
+    /// Application name
+    pub application_name: String,
+
+    /// Stream name
+    pub name: Property<String>,
+    /// Stream state
+    pub state: Property<StreamState>,
+    /// Stream volume
+    pub volume: Property<Volume>,
+    /// Whether stream is muted
+    pub muted: Property<bool>,
+    /// Device this stream is connected to
+    pub device_index: Property<DeviceIndex>,
+}
+
+impl PartialEq for AudioStream {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl AudioStream {
+    pub(crate) fn from_info(info: &StreamInfo) -> Self {
+        Self {
+            key: info.key,
+            application_name: info.application_name.clone(),
+
+            name: Property::new(info.name.clone()),
+            state: Property::new(info.state),
+            volume: Property::new(info.volume.clone()),
+            muted: Property::new(info.muted),
+            device_index: Property::new(info.device_index),
+        }
+    }
+
+    pub(crate) fn update(&self, info: &StreamInfo) {
+        self.name.set(info.name.clone());
+        self.state.set(info.state);
+        self.volume.set(info.volume.clone());
+        self.muted.set(info.muted);
+        self.device_index.set(info.device_index);
+    }
+
+    /// Watch for any stream property changes.
+    ///
+    /// Returns a stream that emits a clone of the stream whenever any
+    /// property changes.
+    pub fn watch(&self) -> impl Stream<Item = AudioStream> + Send {
+        watch_all!(self, name, state, volume, muted, device_index)
+    }
+}
+
+/// Bring stream models in line with a complete stream list
+///
+/// Existing models are updated in place so watchers keep their
+/// subscriptions; models for vanished streams are dropped, and a reused
+/// index with a different application gets a fresh model.
+pub(crate) fn sync_stream_models(
+    stream_list: &[StreamInfo],
+    models: &StreamModels,
+    model_list: &Property<Vec<Arc<AudioStream>>>,
+) {
+    let Ok(mut models_guard) = models.write() else {
+        return;
+    };
+
+    models_guard.retain(|key, model| {
+        stream_list
+            .iter()
+            .any(|stream| stream.key == *key && stream.application_name == model.application_name)
+    });
+
+    for stream in stream_list {
+        match models_guard.get(&stream.key) {
+            Some(model) => model.update(stream),
+            None => {
+                models_guard.insert(stream.key, Arc::new(AudioStream::from_info(stream)));
+            }
+        }
+    }
+
+    let mut stream_models: Vec<Arc<AudioStream>> = models_guard.values().cloned().collect();
+    stream_models.sort_by_key(|model| (model.key.index, model.key.stream_type as u8));
+    drop(models_guard);
+
+    model_list.set(stream_models);
+}
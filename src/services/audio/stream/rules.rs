@@ -0,0 +1,102 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{StreamInfo, StreamType};
+use crate::services::audio::device::{DeviceInfo, DeviceType};
+
+/// Rule applied to application streams as they appear
+///
+/// A rule matches a stream when every matcher it sets matches, compared
+/// case-insensitively; a rule without matchers matches nothing.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct StreamRule {
+    /// Application name to match, e.g. `Spotify`
+    pub application: Option<String>,
+
+    /// Application binary to match, e.g. `spotify`
+    pub binary: Option<String>,
+
+    /// Volume level applied to matching streams, measured on the volume scale
+    /// and capped at the maximum volume
+    pub volume: Option<f64>,
+
+    /// Name or description of the device matching streams are routed to
+    pub device: Option<String>,
+}
+
+impl StreamRule {
+    /// Check whether this rule applies to a stream
+    pub fn matches(&self, stream: &StreamInfo) -> bool {
+        if self.application.is_none() && self.binary.is_none() {
+            return false;
+        }
+
+        let application_matches = self
+            .application
+            .as_ref()
+            .is_none_or(|application| application.eq_ignore_ascii_case(&stream.application_name));
+        let binary_matches = self
+            .binary
+            .as_ref()
+            .is_none_or(|binary| binary.eq_ignore_ascii_case(&stream.application_binary));
+
+        application_matches && binary_matches
+    }
+
+    /// Check whether a device is this rule's routing target
+    pub fn targets_device(&self, device: &DeviceInfo) -> bool {
+        self.device.as_ref().is_some_and(|target| {
+            target.eq_ignore_ascii_case(device.name.as_str())
+                || target.eq_ignore_ascii_case(&device.description)
+        })
+    }
+}
+
+/// Rules applied to application streams by the audio service
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StreamRules {
+    /// Rules in priority order; for volume and device separately, the first
+    /// matching rule that sets it wins
+    pub rules: Vec<StreamRule>,
+    /// Restore each application's last volume when no rule sets one
+    pub remember_volume: bool,
+}
+
+impl StreamRules {
+    /// Volume the rules assign to a stream
+    pub fn volume_for(&self, stream: &StreamInfo) -> Option<f64> {
+        self.matching(stream).find_map(|rule| rule.volume)
+    }
+
+    /// First matching rule that routes the stream to a device
+    pub fn routing_for(&self, stream: &StreamInfo) -> Option<&StreamRule> {
+        self.matching(stream).find(|rule| rule.device.is_some())
+    }
+
+    fn matching<'a, 's>(
+        &'a self,
+        stream: &'s StreamInfo,
+    ) -> impl Iterator<Item = &'a StreamRule> + use<'a, 's> {
+        self.rules.iter().filter(|rule| rule.matches(stream))
+    }
+}
+
+/// Key an application's remembered volume is stored under
+///
+/// Prefers the binary, which is stable across locales, over the
+/// application name. Returns `None` for anonymous streams.
+pub fn application_key(stream: &StreamInfo) -> Option<String> {
+    [&stream.application_binary, &stream.application_name]
+        .into_iter()
+        .find(|name| !name.is_empty())
+        .map(|name| name.to_lowercase())
+}
+
+/// Device type a stream of the given type plays to or records from
+pub fn device_type_for(stream_type: StreamType) -> DeviceType {
+    match stream_type {
+        StreamType::Playback => DeviceType::Output,
+        StreamType::Record | StreamType::Capture => DeviceType::Input,
+    }
+}
@@ -0,0 +1,958 @@
+//! Unit tests for audio service
+//!
+//! Drives `AudioService` through the in-memory fake backend and asserts on
+//! the resulting `AudioEvent` sequences. No sound server required.
+
+#![allow(clippy::panic)]
+
+use std::{sync::Arc, time::Duration};
+
+use futures::{Stream, StreamExt, poll};
+use tokio::time::timeout;
+
+use crate::{
+    config_runtime::ConfigRuntime,
+    services::audio::{
+        AudioError, AudioEvent, AudioService, CardIndex, CardInfo, CardManager, CardProfile,
+        ChannelPosition, ConnectionState, DefaultSwitchReason, DeviceInfo, DeviceManager,
+        DevicePriority, DeviceType, DeviceVolumeController, FakeBackend, FakeBackendHandle,
+        FakePeakCommand, PeakMonitor, PeakTarget, PortSwitchPolicy, StreamInfo, StreamManager,
+        StreamRule, StreamRules, StreamType, StreamVolumeController, VOLUME_MEMORY_SAVE_DELAY,
+        Volume, VolumeMemory, VolumeScale, VolumeSettings,
+        device::{DeviceIndex, DeviceName, DevicePort, DeviceState},
+        stream::{SampleFormat, StreamFormat, StreamIndex, StreamKey, StreamState},
+    },
+};
+
+fn sink(index: u32, name: &str) -> DeviceInfo {
+    DeviceInfo::new(
+        index,
+        DeviceType::Output,
+        DeviceName::new(name.to_string()),
+        name.to_string(),
+        DeviceState::Idle,
+        false,
+        Volume::stereo(1.0, 1.0),
+        Vec::new(),
+        None,
+    )
+}
+
+fn playback(index: u32, application_name: &str, device_index: u32) -> StreamInfo {
+    StreamInfo {
+        index: StreamIndex(index),
+        name: "playback".to_string(),
+        application_name: application_name.to_string(),
+        application_binary: application_name.to_lowercase(),
+        stream_type: StreamType::Playback,
+        state: StreamState::Running,
+        device_index: DeviceIndex(device_index),
+        volume: Volume::stereo(1.0, 1.0),
+        muted: false,
+        format: StreamFormat {
+            sample_rate: 48000,
+            channels: 2,
+            sample_format: SampleFormat::F32LE,
+        },
+        key: StreamKey {
+            index,
+            stream_type: StreamType::Playback,
+        },
+    }
+}
+
+async fn start(backend: FakeBackend) -> AudioService {
+    AudioService::with_backend(backend).await.unwrap()
+}
+
+async fn next_event(events: &mut (impl Stream<Item = AudioEvent> + Unpin)) -> AudioEvent {
+    match timeout(Duration::from_secs(1), events.next()).await {
+        Ok(Some(event)) => event,
+        Ok(None) => panic!("event stream ended"),
+        Err(_) => panic!("timed out waiting for an audio event"),
+    }
+}
+
+fn fake_with_speakers() -> (FakeBackend, FakeBackendHandle, DeviceInfo) {
+    let speakers = sink(0, "speakers");
+    let (backend, handle) = FakeBackend::new();
+    let backend = backend
+        .with_device(speakers.clone())
+        .with_default_device(speakers.key);
+    (backend, handle, speakers)
+}
+
+#[tokio::test]
+async fn initial_state_is_discovered_before_service_returns() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+
+    assert_eq!(service.connection_state.get(), ConnectionState::Connected);
+    assert_eq!(service.device(speakers.key).await.unwrap(), speakers);
+    assert_eq!(service.default_output().await.unwrap(), Some(speakers));
+}
+
+#[tokio::test]
+async fn device_hotplug_emits_added_then_removed() {
+    let (backend, handle, _speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    let headset = sink(1, "headset");
+    handle.insert_device(headset.clone()).await;
+    handle.remove_device(headset.key).await;
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceAdded(device) => assert_eq!(device, headset),
+        other => panic!("expected DeviceAdded, got {other:?}"),
+    }
+    match next_event(&mut events).await {
+        AudioEvent::DeviceRemoved(device) => assert_eq!(device, headset),
+        other => panic!("expected DeviceRemoved, got {other:?}"),
+    }
+
+    let outputs = service.devices_by_type(DeviceType::Output).await.unwrap();
+    assert_eq!(outputs.len(), 1);
+}
+
+#[tokio::test]
+async fn setting_default_output_emits_default_changed() {
+    let headset = sink(1, "headset");
+    let (backend, _handle, _speakers) = fake_with_speakers();
+    let service = start(backend.with_device(headset.clone())).await;
+    let mut events = Box::pin(service.events());
+
+    service.set_default_output(headset.key).await.unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::DefaultOutputChanged(device) => assert_eq!(device, headset),
+        other => panic!("expected DefaultOutputChanged, got {other:?}"),
+    }
+    assert_eq!(service.default_output().await.unwrap(), Some(headset));
+}
+
+#[tokio::test]
+async fn moving_stream_emits_stream_moved() {
+    let headset = sink(1, "headset");
+    let stream = playback(7, "firefox", 0);
+    let (backend, _handle, _speakers) = fake_with_speakers();
+    let service = start(
+        backend
+            .with_device(headset.clone())
+            .with_stream(stream.clone()),
+    )
+    .await;
+    let mut events = Box::pin(service.events());
+
+    service.move_stream(stream.key, headset.key).await.unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::StreamMoved {
+            stream_key,
+            from_device,
+            to_device,
+        } => {
+            assert_eq!(stream_key, stream.key);
+            assert_eq!(from_device, DeviceIndex(0));
+            assert_eq!(to_device, headset.index);
+        }
+        other => panic!("expected StreamMoved, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn mute_command_emits_mute_changed() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    service.set_device_mute(speakers.key, true).await.unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceMuteChanged { device_key, muted } => {
+            assert_eq!(device_key, speakers.key);
+            assert!(muted);
+        }
+        other => panic!("expected DeviceMuteChanged, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn reconnect_reports_changes_missed_during_outage() {
+    let (backend, handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    handle.disconnect().await;
+    assert!(matches!(
+        service.connection_state.get(),
+        ConnectionState::Reconnecting { .. }
+    ));
+
+    let stream = playback(3, "mpv", speakers.index.0);
+    handle.remove_device(speakers.key).await;
+    handle.insert_stream(stream.clone()).await;
+    assert!(service.device(speakers.key).await.is_ok());
+
+    handle.reconnect().await;
+    assert_eq!(service.connection_state.get(), ConnectionState::Connected);
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceRemoved(device) => assert_eq!(device, speakers),
+        other => panic!("expected DeviceRemoved, got {other:?}"),
+    }
+    match next_event(&mut events).await {
+        AudioEvent::StreamAdded(added) => assert_eq!(added, stream),
+        other => panic!("expected StreamAdded, got {other:?}"),
+    }
+    assert_eq!(service.default_output().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn device_model_tracks_backend_changes() {
+    let (backend, handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+
+    let model = service.device_monitored(speakers.key).unwrap();
+    assert!(Arc::ptr_eq(
+        &model,
+        &service.device_monitored(speakers.key).unwrap()
+    ));
+    let mut muted = Box::pin(model.muted.watch());
+    assert_eq!(muted.next().await, Some(false));
+
+    service.set_device_mute(speakers.key, true).await.unwrap();
+    let update = timeout(Duration::from_secs(1), muted.next()).await;
+    assert_eq!(update.unwrap(), Some(true));
+
+    handle.remove_device(speakers.key).await;
+    let mut models = Box::pin(service.audio_devices_monitored());
+    let emptied = timeout(Duration::from_secs(1), async {
+        while let Some(devices) = models.next().await {
+            if devices.is_empty() {
+                return true;
+            }
+        }
+        false
+    })
+    .await;
+    assert!(emptied.unwrap());
+    assert!(service.device_monitored(speakers.key).is_err());
+}
+
+#[test]
+fn volume_scales_round_trip() {
+    for scale in [
+        VolumeScale::Linear,
+        VolumeScale::Cubic,
+        VolumeScale::Decibel,
+    ] {
+        for factor in [0.0, 0.01, 0.125, 0.5, 1.0, 2.0] {
+            let level = scale.to_level(factor);
+            assert!(
+                (scale.to_factor(level) - factor).abs() < 1e-9,
+                "{scale:?} at {factor}"
+            );
+        }
+    }
+
+    assert!((VolumeScale::Cubic.to_level(0.125) - 0.5).abs() < 1e-9);
+    assert_eq!(VolumeScale::Decibel.to_level(1.0), 1.0);
+    assert!((Volume::from_decibels(-6.0, 2).to_decibels() + 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn volume_steps_are_even_on_the_scale_and_capped() {
+    let settings = VolumeSettings {
+        scale: VolumeScale::Cubic,
+        max: 1.0,
+    };
+
+    let quiet = Volume::from_level(0.2, VolumeScale::Cubic, 2).stepped(0.05, settings);
+    assert!((quiet.level(VolumeScale::Cubic) - 0.25).abs() < 1e-9);
+
+    let loud = Volume::from_level(0.98, VolumeScale::Cubic, 2).stepped(0.05, settings);
+    assert!(loud.as_slice().iter().all(|v| (v - 1.0).abs() < 1e-9));
+
+    let unbalanced = Volume::stereo(0.5, 0.25).stepped(-0.1, settings);
+    let [left, right] = unbalanced.as_slice() else {
+        panic!("expected two channels");
+    };
+    assert!((left / right - 2.0).abs() < 1e-9);
+
+    let above_max = Volume::stereo(1.5, 1.5);
+    assert_eq!(above_max.stepped(0.05, settings), above_max);
+}
+
+#[tokio::test]
+async fn stepping_device_volume_uses_service_settings() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await.with_volume_settings(VolumeSettings {
+        scale: VolumeScale::Linear,
+        max: 1.1,
+    });
+    let mut events = Box::pin(service.events());
+
+    service.step_device_volume(speakers.key, 0.5).await.unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceVolumeChanged { volume, .. } => {
+            assert!((volume.average() - 1.1).abs() < 1e-3);
+        }
+        other => panic!("expected DeviceVolumeChanged, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn replaced_volume_settings_apply_to_later_steps() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    service.set_volume_settings(VolumeSettings {
+        scale: VolumeScale::Linear,
+        max: 1.1,
+    });
+    service.step_device_volume(speakers.key, 0.5).await.unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceVolumeChanged { volume, .. } => {
+            assert!((volume.average() - 1.1).abs() < 1e-3);
+        }
+        other => panic!("expected DeviceVolumeChanged, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn audio_config_section_is_applied_to_the_service() {
+    let (backend, _handle, _speakers) = fake_with_speakers();
+    let config_runtime = ConfigRuntime::with_defaults();
+    let audio = config_runtime.get_current().audio;
+
+    let service = start(backend)
+        .await
+        .with_config(&config_runtime, VolumeMemory::in_memory())
+        .await
+        .unwrap();
+
+    assert_eq!(service.volume_settings(), audio.volume_settings());
+    assert_eq!(service.stream_rules(), audio.stream_rules());
+    assert_eq!(service.device_priority(), audio.device_priority());
+    assert_eq!(service.port_switch_policy(), audio.port_switch);
+    assert!(service.stream_rules().remember_volume);
+}
+
+#[tokio::test]
+async fn setting_device_volume_maps_the_level_and_caps_it() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await.with_volume_settings(VolumeSettings {
+        scale: VolumeScale::Cubic,
+        max: 1.1,
+    });
+    let mut events = Box::pin(service.events());
+
+    service.set_device_volume(speakers.key, 0.5).await.unwrap();
+    match next_event(&mut events).await {
+        AudioEvent::DeviceVolumeChanged { volume, .. } => {
+            assert!((volume.average() - 0.125).abs() < 1e-3);
+        }
+        other => panic!("expected DeviceVolumeChanged, got {other:?}"),
+    }
+
+    service.set_device_volume(speakers.key, 1.5).await.unwrap();
+    match next_event(&mut events).await {
+        AudioEvent::DeviceVolumeChanged { volume, .. } => {
+            assert!((volume.average() - 1.1).abs() < 1e-3);
+        }
+        other => panic!("expected DeviceVolumeChanged, got {other:?}"),
+    }
+}
+
+#[test]
+fn balance_and_fade_preserve_the_louder_side() {
+    let mut stereo = Volume::stereo(0.8, 0.8);
+    assert_eq!(
+        stereo.positions(),
+        [ChannelPosition::FrontLeft, ChannelPosition::FrontRight]
+    );
+
+    stereo.set_balance(-0.5);
+    let [left, right] = stereo.as_slice() else {
+        panic!("expected two channels");
+    };
+    assert!((left - 0.8).abs() < 1e-9 && (right - 0.4).abs() < 1e-9);
+    assert!((stereo.balance() + 0.5).abs() < 1e-9);
+
+    stereo.set_balance(1.0);
+    assert_eq!(stereo.channel(0), Some(0.0));
+    assert!(
+        stereo
+            .channel(1)
+            .is_some_and(|right| (right - 0.8).abs() < 1e-9)
+    );
+    assert_eq!(stereo.balance(), 1.0);
+
+    let mut surround = Volume::normal(6);
+    surround.set_fade(0.5);
+    let positions = surround.positions().to_vec();
+    for (volume, position) in surround.as_slice().iter().zip(positions) {
+        let expected = if position.is_rear() { 0.5 } else { 1.0 };
+        assert_eq!(*volume, expected, "{position:?}");
+    }
+    assert!((surround.fade() - 0.5).abs() < 1e-9);
+    assert_eq!(surround.balance(), 0.0);
+
+    let mut mono = Volume::mono(0.7);
+    mono.set_balance(-1.0);
+    assert_eq!(mono, Volume::mono(0.7));
+    assert_eq!(mono.balance(), 0.0);
+}
+
+#[test]
+fn setting_all_channels_keeps_channel_positions() {
+    let positions = vec![ChannelPosition::RearLeft, ChannelPosition::RearRight];
+    let mut rear = Volume::with_positions(vec![0.2, 0.6], positions.clone()).unwrap();
+
+    rear.set_all(0.5);
+
+    assert_eq!(rear.as_slice(), [0.5, 0.5]);
+    assert_eq!(rear.positions(), positions);
+}
+
+#[tokio::test]
+async fn setting_device_balance_keeps_channel_positions() {
+    let (backend, _handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    service
+        .set_device_balance(speakers.key, 0.25)
+        .await
+        .unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::DeviceVolumeChanged { volume, .. } => {
+            assert!((volume.balance() - 0.25).abs() < 1e-3);
+            assert_eq!(volume.positions(), speakers.volume.positions());
+        }
+        other => panic!("expected DeviceVolumeChanged, got {other:?}"),
+    }
+}
+
+fn spotify_rule() -> StreamRule {
+    StreamRule {
+        application: Some("Spotify".to_string()),
+        volume: Some(0.6),
+        device: Some("headset".to_string()),
+        ..StreamRule::default()
+    }
+}
+
+#[tokio::test]
+async fn stream_rules_route_and_set_volume_of_new_streams() {
+    let headset = sink(1, "headset");
+    let (backend, handle, _speakers) = fake_with_speakers();
+    let rules = StreamRules {
+        rules: vec![spotify_rule()],
+        remember_volume: false,
+    };
+    let service = start(backend.with_device(headset.clone()))
+        .await
+        .with_stream_rules(rules, VolumeMemory::in_memory());
+    let mut events = Box::pin(service.events());
+
+    let stream = playback(4, "spotify", 0);
+    handle.insert_stream(stream.clone()).await;
+
+    let mut volume_set = false;
+    let mut moved = false;
+    while !(volume_set && moved) {
+        match next_event(&mut events).await {
+            AudioEvent::StreamVolumeChanged { volume, .. } => {
+                let expected = VolumeSettings::default().scale.to_factor(0.6);
+                assert!((volume.average() - expected).abs() < 1e-3);
+                volume_set = true;
+            }
+            AudioEvent::StreamMoved { to_device, .. } => {
+                assert_eq!(to_device, headset.index);
+                moved = true;
+            }
+            AudioEvent::StreamAdded(_) => {}
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn stream_rule_volumes_are_capped_at_the_maximum() {
+    let (backend, handle, _speakers) = fake_with_speakers();
+    let rules = StreamRules {
+        rules: vec![StreamRule {
+            volume: Some(5.0),
+            ..spotify_rule()
+        }],
+        remember_volume: false,
+    };
+    let service = start(backend)
+        .await
+        .with_volume_settings(VolumeSettings {
+            scale: VolumeScale::Linear,
+            max: 1.5,
+        })
+        .with_stream_rules(rules, VolumeMemory::in_memory());
+    let mut events = Box::pin(service.events());
+
+    handle.insert_stream(playback(4, "spotify", 0)).await;
+
+    loop {
+        if let AudioEvent::StreamVolumeChanged { volume, .. } = next_event(&mut events).await {
+            assert!((volume.average() - 1.5).abs() < 1e-3);
+            break;
+        }
+    }
+}
+
+#[tokio::test]
+async fn remembered_app_volume_is_restored_and_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("app-volumes.json");
+    let (backend, handle, _speakers) = fake_with_speakers();
+    let rules = StreamRules {
+        rules: Vec::new(),
+        remember_volume: true,
+    };
+    let service = start(backend)
+        .await
+        .with_stream_rules(rules, VolumeMemory::load(path.clone()));
+    let mut events = Box::pin(service.events());
+
+    let first = playback(5, "mpv", 0);
+    handle.insert_stream(first.clone()).await;
+    service
+        .set_stream_volume(first.key, Volume::stereo(0.3, 0.3))
+        .await
+        .unwrap();
+    loop {
+        if let AudioEvent::StreamVolumeChanged { .. } = next_event(&mut events).await {
+            break;
+        }
+    }
+    handle.remove_stream(first.key).await;
+
+    let second = playback(6, "mpv", 0);
+    handle.insert_stream(second.clone()).await;
+    loop {
+        if let AudioEvent::StreamVolumeChanged { stream_key, volume } =
+            next_event(&mut events).await
+        {
+            assert_eq!(stream_key, second.key);
+            assert!((volume.average() - 0.3).abs() < 1e-3);
+            break;
+        }
+    }
+
+    tokio::time::sleep(VOLUME_MEMORY_SAVE_DELAY * 2).await;
+    assert!((VolumeMemory::load(path).get("mpv").unwrap() - 0.3).abs() < 1e-3);
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[tokio::test]
+async fn reloaded_stream_rules_apply_to_existing_streams() {
+    let headset = sink(1, "headset");
+    let stream = playback(4, "Spotify", 0);
+    let (backend, _handle, _speakers) = fake_with_speakers();
+    let service = start(
+        backend
+            .with_device(headset.clone())
+            .with_stream(stream.clone()),
+    )
+    .await
+    .with_stream_rules(StreamRules::default(), VolumeMemory::in_memory());
+    let mut events = Box::pin(service.events());
+
+    service.set_stream_rules(StreamRules {
+        rules: vec![StreamRule {
+            volume: None,
+            ..spotify_rule()
+        }],
+        remember_volume: false,
+    });
+
+    match next_event(&mut events).await {
+        AudioEvent::StreamMoved {
+            stream_key,
+            to_device,
+            ..
+        } => {
+            assert_eq!(stream_key, stream.key);
+            assert_eq!(to_device, headset.index);
+        }
+        other => panic!("expected StreamMoved, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn device_priority_prefers_hotplugged_device_and_falls_back() {
+    let (backend, handle, speakers) = fake_with_speakers();
+    let service = start(backend).await.with_device_priority(DevicePriority {
+        output: vec!["usb-*".to_string()],
+        input: Vec::new(),
+    });
+    let mut events = Box::pin(service.events());
+
+    let dac = sink(1, "usb-dac");
+    handle.insert_device(dac.clone()).await;
+
+    let mut switched = false;
+    while !switched {
+        match next_event(&mut events).await {
+            AudioEvent::DefaultDeviceSwitched { device, reason } => {
+                assert_eq!(device, dac);
+                assert_eq!(reason, DefaultSwitchReason::PreferredDeviceAdded);
+                switched = true;
+            }
+            AudioEvent::DeviceAdded(_) => {}
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+    match next_event(&mut events).await {
+        AudioEvent::DefaultOutputChanged(device) => assert_eq!(device, dac),
+        other => panic!("expected DefaultOutputChanged, got {other:?}"),
+    }
+
+    handle.remove_device(dac.key).await;
+
+    let mut switched = false;
+    while !switched {
+        match next_event(&mut events).await {
+            AudioEvent::DefaultDeviceSwitched { device, reason } => {
+                assert_eq!(device, speakers);
+                assert_eq!(reason, DefaultSwitchReason::DefaultDeviceRemoved);
+                switched = true;
+            }
+            AudioEvent::DeviceRemoved(_) => {}
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+    match next_event(&mut events).await {
+        AudioEvent::DefaultOutputChanged(device) => assert_eq!(device, speakers),
+        other => panic!("expected DefaultOutputChanged, got {other:?}"),
+    }
+}
+
+async fn next_level(levels: &mut (impl Stream<Item = f32> + Unpin)) -> Option<f32> {
+    match timeout(Duration::from_secs(1), levels.next()).await {
+        Ok(level) => level,
+        Err(_) => panic!("timed out waiting for a peak level"),
+    }
+}
+
+#[tokio::test]
+async fn peak_monitor_starts_lazily_and_is_shared_by_subscribers() {
+    let (backend, handle, speakers) = fake_with_speakers();
+    let service = start(backend).await;
+    let target = PeakTarget::Device(speakers.key);
+
+    let mut first = Box::pin(service.peak_levels(target, 30));
+    assert!(handle.peak_commands().await.is_empty());
+
+    assert!(poll!(first.next()).is_pending());
+    let mut second = Box::pin(service.peak_levels(target, 5));
+    assert!(poll!(second.next()).is_pending());
+    assert_eq!(
+        handle.peak_commands().await,
+        vec![FakePeakCommand::Start { target, rate: 30 }]
+    );
+
+    handle.emit_peak(target, 0.5).await;
+    assert_eq!(next_level(&mut first).await, Some(0.5));
+    assert_eq!(next_level(&mut second).await, Some(0.5));
+
+    drop(first);
+    assert_eq!(handle.peak_commands().await.len(), 1);
+
+    drop(second);
+    assert_eq!(
+        handle.peak_commands().await,
+        vec![
+            FakePeakCommand::Start { target, rate: 30 },
+            FakePeakCommand::Stop { target },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn peak_levels_end_when_the_target_is_removed() {
+    let (backend, handle, speakers) = fake_with_speakers();
+    let stream = playback(3, "mpv", speakers.index.0);
+    let service = start(backend.with_stream(stream.clone())).await;
+    let target = PeakTarget::Stream(stream.key);
+
+    let mut missing = Box::pin(service.peak_levels(PeakTarget::Device(sink(9, "gone").key), 25));
+    assert_eq!(next_level(&mut missing).await, None);
+
+    let mut ended = Box::pin(service.peak_levels(target, 25));
+    assert!(poll!(ended.next()).is_pending());
+    handle.remove_stream(stream.key).await;
+    assert_eq!(next_level(&mut ended).await, None);
+
+    handle.insert_stream(stream).await;
+    let mut restarted = Box::pin(service.peak_levels(target, 25));
+    assert!(poll!(restarted.next()).is_pending());
+    drop(ended);
+
+    handle.emit_peak(target, 0.25).await;
+    assert_eq!(next_level(&mut restarted).await, Some(0.25));
+    assert_eq!(
+        handle.peak_commands().await,
+        vec![
+            FakePeakCommand::Start { target, rate: 25 },
+            FakePeakCommand::Start { target, rate: 25 },
+        ]
+    );
+}
+
+fn profile(name: &str, available: bool) -> CardProfile {
+    CardProfile {
+        name: name.to_string(),
+        description: name.to_string(),
+        priority: 0,
+        available,
+        sinks: 1,
+        sources: 0,
+    }
+}
+
+fn card(index: u32, name: &str) -> CardInfo {
+    CardInfo {
+        index: CardIndex(index),
+        name: name.to_string(),
+        description: name.to_string(),
+        driver: "module-alsa-card.c".to_string(),
+        profiles: vec![
+            profile("output:analog-stereo", true),
+            profile("output:hdmi-stereo", true),
+            profile("output:hdmi-surround", false),
+        ],
+        active_profile: Some("output:analog-stereo".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn cards_are_discovered_before_service_returns() {
+    let onboard = card(0, "onboard");
+    let (backend, _handle) = FakeBackend::new();
+    let service = start(backend.with_card(onboard.clone())).await;
+
+    assert_eq!(service.card(onboard.index).await.unwrap(), onboard);
+    assert_eq!(service.list_cards().await.unwrap(), vec![onboard]);
+    assert!(matches!(
+        service.card(CardIndex(9)).await,
+        Err(AudioError::CardNotFound(9))
+    ));
+}
+
+#[tokio::test]
+async fn card_hotplug_emits_added_then_removed() {
+    let (backend, handle) = FakeBackend::new();
+    let service = start(backend).await;
+    let mut events = Box::pin(service.events());
+
+    let headset = card(1, "headset");
+    handle.insert_card(headset.clone()).await;
+    match next_event(&mut events).await {
+        AudioEvent::CardAdded(added) => assert_eq!(added, headset),
+        other => panic!("expected CardAdded, got {other:?}"),
+    }
+
+    handle.remove_card(headset.index).await;
+    match next_event(&mut events).await {
+        AudioEvent::CardRemoved(removed) => assert_eq!(removed, headset),
+        other => panic!("expected CardRemoved, got {other:?}"),
+    }
+    assert!(service.card(headset.index).await.is_err());
+}
+
+#[tokio::test]
+async fn setting_card_profile_switches_it_and_emits_profile_changed() {
+    let onboard = card(0, "onboard");
+    let (backend, _handle) = FakeBackend::new();
+    let service = start(backend.with_card(onboard.clone())).await;
+    let mut events = Box::pin(service.events());
+
+    service
+        .set_card_profile(onboard.index, "output:hdmi-stereo")
+        .await
+        .unwrap();
+
+    match next_event(&mut events).await {
+        AudioEvent::CardProfileChanged {
+            card_index,
+            profile: active,
+        } => {
+            assert_eq!(card_index, onboard.index);
+            assert_eq!(active, profile("output:hdmi-stereo", true));
+        }
+        other => panic!("expected CardProfileChanged, got {other:?}"),
+    }
+    assert_eq!(
+        service.card(onboard.index).await.unwrap().active_profile,
+        Some("output:hdmi-stereo".to_string())
+    );
+}
+
+#[tokio::test]
+async fn unavailable_or_unknown_card_profiles_are_rejected() {
+    let onboard = card(0, "onboard");
+    let (backend, _handle) = FakeBackend::new();
+    let service = start(backend.with_card(onboard.clone())).await;
+
+    for name in ["output:hdmi-surround", "output:missing"] {
+        match service.set_card_profile(onboard.index, name).await {
+            Err(AudioError::ProfileUnavailable { card, profile }) => {
+                assert_eq!(card, onboard.index.0);
+                assert_eq!(profile, name);
+            }
+            other => panic!("expected ProfileUnavailable, got {other:?}"),
+        }
+    }
+    assert!(matches!(
+        service
+            .set_card_profile(CardIndex(9), "output:hdmi-stereo")
+            .await,
+        Err(AudioError::CardNotFound(9))
+    ));
+    assert_eq!(service.card(onboard.index).await.unwrap(), onboard);
+}
+
+fn port(name: &str, priority: u32, available: bool) -> DevicePort {
+    DevicePort {
+        name: name.to_string(),
+        description: name.to_string(),
+        priority,
+        available,
+    }
+}
+
+fn sink_with_ports(ports: Vec<DevicePort>, active_port: &str) -> DeviceInfo {
+    let mut device = sink(0, "onboard");
+    device.ports = ports;
+    device.active_port = Some(active_port.to_string());
+    device
+}
+
+#[test]
+fn ports_are_preferred_when_available_and_outranking_the_active_port() {
+    let device = sink_with_ports(
+        vec![
+            port("speaker", 100, true),
+            port("headphones", 200, true),
+            port("line-out", 50, true),
+            port("hdmi", 300, false),
+        ],
+        "speaker",
+    );
+
+    assert!(device.prefers_port("headphones"));
+    assert!(!device.prefers_port("line-out"));
+    assert!(!device.prefers_port("hdmi"));
+    assert!(!device.prefers_port("speaker"));
+    assert!(!device.prefers_port("missing"));
+
+    let unplugged = sink_with_ports(
+        vec![port("headphones", 200, false), port("speaker", 100, true)],
+        "headphones",
+    );
+    assert!(unplugged.prefers_port("speaker"));
+}
+
+/// Wait for the next change of the device's active port
+async fn next_active_port(events: &mut (impl Stream<Item = AudioEvent> + Unpin)) -> String {
+    loop {
+        if let AudioEvent::DeviceChanged(device) = next_event(events).await
+            && let Some(active_port) = device.active_port
+            && device.ports.iter().any(|port| port.name == active_port)
+        {
+            return active_port;
+        }
+    }
+}
+
+#[tokio::test]
+async fn auto_switch_moves_to_higher_priority_ports_only() {
+    let unplugged = sink_with_ports(
+        vec![
+            port("speaker", 100, true),
+            port("line-out", 50, false),
+            port("headphones", 200, false),
+        ],
+        "speaker",
+    );
+    let (backend, handle) = FakeBackend::new();
+    let service = start(backend.with_device(unplugged.clone()))
+        .await
+        .with_port_switch_policy(PortSwitchPolicy::PreferHigherPriority);
+    let mut events = Box::pin(service.events());
+
+    let mut line_out = unplugged.clone();
+    line_out.ports[1].available = true;
+    handle.insert_device(line_out.clone()).await;
+
+    let mut headphones = line_out;
+    headphones.ports[2].available = true;
+    handle.insert_device(headphones.clone()).await;
+
+    assert_eq!(next_active_port(&mut events).await, "speaker");
+    assert_eq!(next_active_port(&mut events).await, "speaker");
+    assert_eq!(next_active_port(&mut events).await, "headphones");
+    assert_eq!(
+        service.device(unplugged.key).await.unwrap().active_port,
+        Some("headphones".to_string())
+    );
+}
+
+#[tokio::test]
+async fn auto_switch_leaves_an_unavailable_active_port() {
+    let headphones = sink_with_ports(
+        vec![port("headphones", 200, true), port("speaker", 100, false)],
+        "headphones",
+    );
+    let (backend, handle) = FakeBackend::new();
+    let service = start(backend.with_device(headphones.clone()))
+        .await
+        .with_port_switch_policy(PortSwitchPolicy::PreferHigherPriority);
+    let mut events = Box::pin(service.events());
+
+    let mut unplugged = headphones.clone();
+    unplugged.ports[0].available = false;
+    unplugged.ports[1].available = true;
+    handle.insert_device(unplugged).await;
+
+    assert_eq!(next_active_port(&mut events).await, "headphones");
+    assert_eq!(next_active_port(&mut events).await, "speaker");
+}
+
+#[tokio::test]
+async fn manual_port_switch_policy_keeps_the_active_port() {
+    let unplugged = sink_with_ports(
+        vec![port("speaker", 100, true), port("headphones", 200, false)],
+        "speaker",
+    );
+    let (backend, handle) = FakeBackend::new();
+    let service = start(backend.with_device(unplugged.clone()))
+        .await
+        .with_port_switch_policy(PortSwitchPolicy::PreferHigherPriority);
+    service.set_port_switch_policy(PortSwitchPolicy::Manual);
+    let mut events = Box::pin(service.events());
+
+    let mut plugged = unplugged.clone();
+    plugged.ports[1].available = true;
+    handle.insert_device(plugged.clone()).await;
+
+    let mut renamed = plugged;
+    renamed.description = "Onboard".to_string();
+    handle.insert_device(renamed).await;
+
+    assert_eq!(next_active_port(&mut events).await, "speaker");
+    assert_eq!(next_active_port(&mut events).await, "speaker");
+}
@@ -0,0 +1,133 @@
+/// Speaker position of a volume channel
+///
+/// Mirrors the PulseAudio channel map, so a channel's volume can be tied to
+/// the speaker it drives instead of an anonymous index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelPosition {
+    /// Single channel
+    Mono,
+    /// Front left
+    FrontLeft,
+    /// Front right
+    FrontRight,
+    /// Front center
+    FrontCenter,
+    /// Rear left
+    RearLeft,
+    /// Rear right
+    RearRight,
+    /// Rear center
+    RearCenter,
+    /// Low-frequency effects (subwoofer)
+    Lfe,
+    /// Front left of center
+    FrontLeftOfCenter,
+    /// Front right of center
+    FrontRightOfCenter,
+    /// Side left
+    SideLeft,
+    /// Side right
+    SideRight,
+    /// Top center
+    TopCenter,
+    /// Top front left
+    TopFrontLeft,
+    /// Top front right
+    TopFrontRight,
+    /// Top front center
+    TopFrontCenter,
+    /// Top rear left
+    TopRearLeft,
+    /// Top rear right
+    TopRearRight,
+    /// Top rear center
+    TopRearCenter,
+    /// Auxiliary channel with its index
+    Aux(u8),
+    /// Position not reported by the sound server
+    Unknown,
+}
+
+impl ChannelPosition {
+    /// Default channel layout for the given channel count
+    ///
+    /// Matches PulseAudio's defaults for the common layouts (mono, stereo,
+    /// quad, 5.1, 7.1); other counts are numbered auxiliary channels.
+    pub fn default_layout(channels: usize) -> Vec<Self> {
+        use ChannelPosition::*;
+
+        match channels {
+            1 => vec![Mono],
+            2 => vec![FrontLeft, FrontRight],
+            3 => vec![FrontLeft, FrontRight, FrontCenter],
+            4 => vec![FrontLeft, FrontRight, RearLeft, RearRight],
+            5 => vec![FrontLeft, FrontRight, FrontCenter, RearLeft, RearRight],
+            6 => vec![FrontLeft, FrontRight, FrontCenter, Lfe, RearLeft, RearRight],
+            8 => vec![
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                Lfe,
+                RearLeft,
+                RearRight,
+                SideLeft,
+                SideRight,
+            ],
+            _ => (0..channels).map(|i| Aux(i as u8)).collect(),
+        }
+    }
+
+    /// Whether the channel is on the listener's left
+    pub fn is_left(self) -> bool {
+        matches!(
+            self,
+            Self::FrontLeft
+                | Self::RearLeft
+                | Self::FrontLeftOfCenter
+                | Self::SideLeft
+                | Self::TopFrontLeft
+                | Self::TopRearLeft
+        )
+    }
+
+    /// Whether the channel is on the listener's right
+    pub fn is_right(self) -> bool {
+        matches!(
+            self,
+            Self::FrontRight
+                | Self::RearRight
+                | Self::FrontRightOfCenter
+                | Self::SideRight
+                | Self::TopFrontRight
+                | Self::TopRearRight
+        )
+    }
+
+    /// Whether the channel is in front of the listener
+    pub fn is_front(self) -> bool {
+        matches!(
+            self,
+            Self::FrontLeft
+                | Self::FrontRight
+                | Self::FrontCenter
+                | Self::FrontLeftOfCenter
+                | Self::FrontRightOfCenter
+                | Self::TopFrontLeft
+                | Self::TopFrontRight
+                | Self::TopFrontCenter
+        )
+    }
+
+    /// Whether the channel is behind the listener
+    pub fn is_rear(self) -> bool {
+        matches!(
+            self,
+            Self::RearLeft
+                | Self::RearRight
+                | Self::RearCenter
+                | Self::TopRearLeft
+                | Self::TopRearRight
+                | Self::TopRearCenter
+        )
+    }
+}
@@ -15,4 +15,12 @@ pub enum VolumeError {
         /// Channel index
         channel: usize,
     },
+    /// Channel positions do not match the channel volumes
+    #[error("Channel map has {positions} positions for {volumes} volumes")]
+    ChannelMapMismatch {
+        /// Number of channel volumes
+        volumes: usize,
+        /// Number of channel positions
+        positions: usize,
+    },
 }
@@ -1,6 +1,10 @@
 /// Volume control types and operations
+mod channel;
 mod error;
+mod scale;
 mod types;
 
+pub use channel::*;
 pub use error::*;
+pub use scale::*;
 pub use types::*;
@@ -0,0 +1,80 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Decibel level treated as silence by [`VolumeScale::Decibel`]
+pub const DECIBEL_FLOOR: f64 = -60.0;
+
+/// Default ceiling for stepped volume changes (200%, the safe limit)
+pub const DEFAULT_MAX_VOLUME: f64 = 2.0;
+
+/// Scale on which user-facing volume levels are expressed
+///
+/// Volume factors are linear amplitude multipliers, which are not heard
+/// linearly: equal steps on the factor jump at low volume and barely move
+/// at high volume. Levels on a perceptual scale make equal steps sound
+/// roughly equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VolumeScale {
+    /// Level equals the amplitude factor
+    Linear,
+    /// Level is the cube root of the factor, as in pavucontrol
+    #[default]
+    Cubic,
+    /// Level maps linearly onto decibels, from -60 dB at 0.0 to 0 dB at 1.0
+    Decibel,
+}
+
+impl VolumeScale {
+    /// Convert a volume factor to a level on this scale
+    pub fn to_level(self, factor: f64) -> f64 {
+        let factor = factor.max(0.0);
+        match self {
+            Self::Linear => factor,
+            Self::Cubic => factor.cbrt(),
+            Self::Decibel if factor == 0.0 => 0.0,
+            Self::Decibel => {
+                ((factor_to_decibels(factor) - DECIBEL_FLOOR) / -DECIBEL_FLOOR).max(0.0)
+            }
+        }
+    }
+
+    /// Convert a level on this scale to a volume factor
+    pub fn to_factor(self, level: f64) -> f64 {
+        let level = level.max(0.0);
+        match self {
+            Self::Linear => level,
+            Self::Cubic => level.powi(3),
+            Self::Decibel if level == 0.0 => 0.0,
+            Self::Decibel => decibels_to_factor(DECIBEL_FLOOR + level * -DECIBEL_FLOOR),
+        }
+    }
+}
+
+/// Convert an amplitude factor to decibels (`-inf` for silence)
+pub fn factor_to_decibels(factor: f64) -> f64 {
+    20.0 * factor.log10()
+}
+
+/// Convert decibels to an amplitude factor
+pub fn decibels_to_factor(decibels: f64) -> f64 {
+    10f64.powf(decibels / 20.0)
+}
+
+/// How volume levels and steps are applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolumeSettings {
+    /// Scale levels and steps are measured on
+    pub scale: VolumeScale,
+    /// Highest volume factor a level or step may reach
+    pub max: f64,
+}
+
+impl Default for VolumeSettings {
+    fn default() -> Self {
+        Self {
+            scale: VolumeScale::default(),
+            max: DEFAULT_MAX_VOLUME,
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::VolumeError;
+use super::{
+    ChannelPosition, VolumeError, VolumeScale, VolumeSettings, decibels_to_factor,
+    factor_to_decibels,
+};
 
 /// Multi-channel volume with safety warnings
 ///
@@ -15,6 +18,7 @@ use super::VolumeError;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Volume {
     volumes: Vec<f64>,
+    positions: Vec<ChannelPosition>,
 }
 
 impl Volume {
@@ -24,8 +28,11 @@ impl Volume {
     /// - 0.0 = Muted
     /// - 1.0 = Normal volume (100%)
     /// - 4.0 = Maximum amplification (400%)
+    ///
+    /// Channels get the default layout for their count (see
+    /// [`ChannelPosition::default_layout`]).
     pub fn new(volumes: Vec<f64>) -> Self {
-        let volumes = volumes.into_iter().map(|v| {
+        let volumes: Vec<f64> = volumes.into_iter().map(|v| {
             let clamped = v.clamp(0.0, 4.0);
             if v > 2.0 && v <= 4.0 {
                 tracing::warn!("Volume {v} exceeds safe limit (2.0). Audio damage possible at high amplification.");
@@ -36,7 +43,30 @@ impl Volume {
             }
             clamped
         }).collect();
-        Self { volumes }
+        let positions = ChannelPosition::default_layout(volumes.len());
+        Self { volumes, positions }
+    }
+
+    /// Create a volume with explicit channel positions
+    ///
+    /// Volume levels are clamped as in [`Volume::new`].
+    ///
+    /// # Errors
+    /// Returns error if the number of positions differs from the number of volumes.
+    pub fn with_positions(
+        volumes: Vec<f64>,
+        positions: Vec<ChannelPosition>,
+    ) -> Result<Self, VolumeError> {
+        if volumes.len() != positions.len() {
+            return Err(VolumeError::ChannelMapMismatch {
+                volumes: volumes.len(),
+                positions: positions.len(),
+            });
+        }
+        Ok(Self {
+            positions,
+            ..Self::new(volumes)
+        })
     }
 
     /// Create volume with amplification (allows up to 4.0)
@@ -53,7 +83,8 @@ impl Volume {
                 return Err(VolumeError::InvalidVolume { channel: 0, volume });
             }
         }
-        Ok(Self { volumes })
+        let positions = ChannelPosition::default_layout(volumes.len());
+        Ok(Self { volumes, positions })
     }
 
     /// Create a mono volume
@@ -104,6 +135,13 @@ impl Volume {
         }
     }
 
+    /// Set every channel to the same volume, keeping channel positions
+    ///
+    /// Volume is automatically clamped to valid range (0.0 to 4.0).
+    pub fn set_all(&mut self, volume: f64) {
+        *self = self.with_volumes(vec![volume; self.volumes.len()]);
+    }
+
     /// Get average volume across all channels
     pub fn average(&self) -> f64 {
         if self.volumes.is_empty() {
@@ -123,6 +161,117 @@ impl Volume {
         &self.volumes
     }
 
+    /// Get the speaker position of a specific channel
+    pub fn position(&self, channel: usize) -> Option<ChannelPosition> {
+        self.positions.get(channel).copied()
+    }
+
+    /// Get the speaker positions of all channels
+    pub fn positions(&self) -> &[ChannelPosition] {
+        &self.positions
+    }
+
+    /// Get the left/right balance
+    ///
+    /// Ranges from -1.0 (left only) through 0.0 (centered) to 1.0 (right
+    /// only). Volumes without both left and right channels, such as mono,
+    /// are always centered.
+    pub fn balance(&self) -> f64 {
+        self.pan(ChannelPosition::is_left, ChannelPosition::is_right)
+    }
+
+    /// Set the left/right balance
+    ///
+    /// `balance` is clamped to -1.0..=1.0. The louder side keeps its volume
+    /// and the other side is attenuated, so overall loudness is preserved;
+    /// center and LFE channels are untouched. Has no effect on volumes
+    /// without both left and right channels.
+    pub fn set_balance(&mut self, balance: f64) {
+        self.set_pan(balance, ChannelPosition::is_left, ChannelPosition::is_right);
+    }
+
+    /// Get the rear/front fade
+    ///
+    /// Ranges from -1.0 (rear only) through 0.0 (even) to 1.0 (front only).
+    /// Volumes without both front and rear channels are always even.
+    pub fn fade(&self) -> f64 {
+        self.pan(ChannelPosition::is_rear, ChannelPosition::is_front)
+    }
+
+    /// Set the rear/front fade
+    ///
+    /// Works like [`Volume::set_balance`] on the front and rear channels.
+    pub fn set_fade(&mut self, fade: f64) {
+        self.set_pan(fade, ChannelPosition::is_rear, ChannelPosition::is_front);
+    }
+
+    fn pan(&self, first: fn(ChannelPosition) -> bool, second: fn(ChannelPosition) -> bool) -> f64 {
+        let (Some(first), Some(second)) = (self.side_average(first), self.side_average(second))
+        else {
+            return 0.0;
+        };
+
+        if first == second {
+            0.0
+        } else if first > second {
+            second / first - 1.0
+        } else {
+            1.0 - first / second
+        }
+    }
+
+    fn set_pan(
+        &mut self,
+        pan: f64,
+        first: fn(ChannelPosition) -> bool,
+        second: fn(ChannelPosition) -> bool,
+    ) {
+        let (Some(old_first), Some(old_second)) =
+            (self.side_average(first), self.side_average(second))
+        else {
+            return;
+        };
+
+        let pan = pan.clamp(-1.0, 1.0);
+        let loudest = old_first.max(old_second);
+        let (new_first, new_second) = if pan <= 0.0 {
+            (loudest, (pan + 1.0) * loudest)
+        } else {
+            ((1.0 - pan) * loudest, loudest)
+        };
+
+        for (volume, &position) in self.volumes.iter_mut().zip(&self.positions) {
+            let (old, new) = if first(position) {
+                (old_first, new_first)
+            } else if second(position) {
+                (old_second, new_second)
+            } else {
+                continue;
+            };
+            *volume = if old == 0.0 {
+                new
+            } else {
+                (*volume * new / old).min(4.0)
+            };
+        }
+    }
+
+    fn side_average(&self, side: fn(ChannelPosition) -> bool) -> Option<f64> {
+        let levels: Vec<f64> = self
+            .volumes
+            .iter()
+            .zip(&self.positions)
+            .filter(|&(_, &position)| side(position))
+            .map(|(&volume, _)| volume)
+            .collect();
+
+        if levels.is_empty() {
+            None
+        } else {
+            Some(levels.iter().sum::<f64>() / levels.len() as f64)
+        }
+    }
+
     /// Create a muted volume (0.0)
     pub fn muted(channels: usize) -> Self {
         Self::new(vec![0.0; channels])
@@ -153,4 +302,53 @@ impl Volume {
     pub fn is_normal(&self) -> bool {
         self.volumes.iter().all(|&v| v == 1.0)
     }
+
+    /// Get average volume as a level on the given scale
+    pub fn level(&self, scale: VolumeScale) -> f64 {
+        scale.to_level(self.average())
+    }
+
+    /// Create a volume from a level on the given scale
+    pub fn from_level(level: f64, scale: VolumeScale, channels: usize) -> Self {
+        Self::new(vec![scale.to_factor(level); channels])
+    }
+
+    /// Get average volume in decibels (`-inf` when muted)
+    pub fn to_decibels(&self) -> f64 {
+        factor_to_decibels(self.average())
+    }
+
+    /// Create a volume from decibels (0 dB = 1.0)
+    pub fn from_decibels(decibels: f64, channels: usize) -> Self {
+        Self::new(vec![decibels_to_factor(decibels); channels])
+    }
+
+    /// Step the volume by `step` on the settings' scale
+    ///
+    /// `step` is a fraction of the scale, e.g. `0.05` for +5%. All channels
+    /// are scaled by the same ratio, preserving balance, and stepping up
+    /// never takes the loudest channel past `settings.max`. A volume already
+    /// above the maximum is left unchanged by upward steps.
+    pub fn stepped(&self, step: f64, settings: VolumeSettings) -> Self {
+        let current = self.average();
+        let target = settings
+            .scale
+            .to_factor(settings.scale.to_level(current) + step);
+        let loudest = self.volumes.iter().copied().fold(0.0, f64::max);
+
+        if loudest == 0.0 {
+            return self.with_volumes(vec![target.min(settings.max); self.volumes.len()]);
+        }
+
+        let ceiling = (settings.max / loudest).max(1.0);
+        let ratio = (target / current).min(ceiling);
+        self.with_volumes(self.volumes.iter().map(|&v| v * ratio).collect())
+    }
+
+    fn with_volumes(&self, volumes: Vec<f64>) -> Self {
+        Self {
+            positions: self.positions.clone(),
+            ..Self::new(volumes)
+        }
+    }
 }
@@ -0,0 +1,117 @@
+use std::{
+    fs::{self, File},
+    io::Error,
+    path::PathBuf,
+    time::SystemTime,
+};
+
+use tracing::warn;
+
+/// Extension of cached art files
+const CACHE_EXTENSION: &str = "png";
+
+/// On-disk album art cache with least-recently-used eviction
+///
+/// Entries are files named after their key. Recency survives restarts
+/// through the files' modification times, which are bumped on every hit.
+/// All operations block on the filesystem, so async callers run them on
+/// the blocking thread pool.
+#[derive(Debug)]
+pub(crate) struct ArtCache {
+    dir: PathBuf,
+    max_entries: usize,
+    /// Cached keys, least recently used first
+    keys: Vec<String>,
+}
+
+impl ArtCache {
+    /// Open the cache in `dir`, creating it if needed
+    ///
+    /// A `max_entries` of zero is treated as one.
+    ///
+    /// # Errors
+    /// Returns error if the directory cannot be created or listed
+    pub(crate) fn open(dir: PathBuf, max_entries: usize) -> Result<Self, Error> {
+        fs::create_dir_all(&dir)?;
+
+        let mut entries: Vec<(SystemTime, String)> = fs::read_dir(&dir)?
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().is_none_or(|ext| ext != CACHE_EXTENSION) {
+                    return None;
+                }
+
+                let key = path.file_stem()?.to_str()?.to_string();
+                let modified = entry.metadata().and_then(|m| m.modified()).ok()?;
+                Some((modified, key))
+            })
+            .collect();
+        entries.sort();
+
+        let mut cache = Self {
+            dir,
+            // The latest entry is always kept so the path handed out by
+            // `insert` stays valid
+            max_entries: max_entries.max(1),
+            keys: entries.into_iter().map(|(_, key)| key).collect(),
+        };
+        cache.evict();
+
+        Ok(cache)
+    }
+
+    /// Get the cached file for `key`, marking it as recently used
+    pub(crate) fn get(&mut self, key: &str) -> Option<PathBuf> {
+        let index = self.keys.iter().position(|cached| cached == key)?;
+        let path = self.path(key);
+
+        if !path.exists() {
+            self.keys.remove(index);
+            return None;
+        }
+
+        let key = self.keys.remove(index);
+        self.keys.push(key);
+        if let Err(e) = File::options()
+            .append(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(SystemTime::now()))
+        {
+            warn!("Failed to touch cached art {}: {e}", path.display());
+        }
+
+        Some(path)
+    }
+
+    /// Store `bytes` under `key`, evicting the least recently used entries
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be written
+    pub(crate) fn insert(&mut self, key: &str, bytes: &[u8]) -> Result<PathBuf, Error> {
+        let path = self.path(key);
+        let partial = path.with_extension("part");
+        fs::write(&partial, bytes)?;
+        fs::rename(&partial, &path)?;
+
+        self.keys.retain(|cached| cached != key);
+        self.keys.push(key.to_string());
+        self.evict();
+
+        Ok(path)
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{CACHE_EXTENSION}"))
+    }
+
+    fn evict(&mut self) {
+        let excess = self.keys.len().saturating_sub(self.max_entries);
+        for key in self.keys.drain(..excess).collect::<Vec<_>>() {
+            let path = self.path(&key);
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("Failed to evict cached art {}: {e}", path.display());
+            }
+        }
+    }
+}
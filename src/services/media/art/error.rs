@@ -0,0 +1,36 @@
+/// Album art resolution errors
+#[derive(thiserror::Error, Debug)]
+pub enum ArtError {
+    /// Art URL uses a scheme the resolver can't load
+    #[error("Unsupported art URL: {0}")]
+    UnsupportedSource(String),
+
+    /// Malformed `data:` URI
+    #[error("Invalid data URI: {0}")]
+    InvalidDataUri(String),
+
+    /// Art is only available over HTTP and fetching is disabled
+    #[error("HTTP art fetching is disabled: {0}")]
+    HttpDisabled(String),
+
+    /// HTTP request for the art failed
+    #[error("Failed to fetch art: {0}")]
+    Http(String),
+
+    /// Art source exceeds the size the resolver will load
+    #[error("Art is {size} bytes (limit: {limit})")]
+    TooLarge {
+        /// Size of the source in bytes
+        size: u64,
+        /// Largest accepted size in bytes
+        limit: u64,
+    },
+
+    /// Art could not be decoded or encoded as an image
+    #[error("Invalid art image: {0}")]
+    Image(#[from] image::ImageError),
+
+    /// Reading the source or writing the cache failed
+    #[error("Art cache I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,268 @@
+mod cache;
+mod error;
+mod source;
+
+use std::{
+    hash::Hasher,
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use image::{GenericImageView, ImageFormat};
+use tokio::sync::Mutex;
+
+use cache::ArtCache;
+pub use error::ArtError;
+pub use source::ArtSource;
+
+/// Name of the art cache directory inside the app data directory
+pub const ART_CACHE_DIR_NAME: &str = "art";
+
+/// Default number of images kept in the art cache
+pub const DEFAULT_ART_CACHE_ENTRIES: usize = 200;
+
+/// Default longest side of cached art in pixels
+pub const DEFAULT_ART_SIZE: u32 = 512;
+
+/// Largest art source, in bytes, the resolver will load
+pub const MAX_ART_SOURCE_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Timeout for fetching art over HTTP
+#[cfg(feature = "art-http")]
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Settings for album art resolution and caching
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtSettings {
+    /// Directory cached art is written to
+    pub cache_dir: PathBuf,
+    /// Number of images kept before the least recently used are evicted;
+    /// at least one is always kept
+    pub max_entries: usize,
+    /// Longest side of cached images in pixels; larger art is scaled down
+    pub thumbnail_size: u32,
+    /// Fetch art from `http(s)://` URLs; requires the `art-http` feature
+    pub fetch_http: bool,
+}
+
+impl ArtSettings {
+    /// Settings with default limits, caching into `cache_dir`
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self {
+            cache_dir,
+            max_entries: DEFAULT_ART_CACHE_ENTRIES,
+            thumbnail_size: DEFAULT_ART_SIZE,
+            fetch_http: true,
+        }
+    }
+}
+
+/// Resolves player art URLs into cached, size-bounded local images
+///
+/// `file://` paths, `data:` URIs and (with the `art-http` feature) HTTP
+/// URLs all resolve to a PNG in the cache directory, so consumers only
+/// ever deal with local files.
+#[derive(Debug)]
+pub struct ArtResolver {
+    settings: ArtSettings,
+    cache: Arc<Mutex<ArtCache>>,
+    #[cfg(feature = "art-http")]
+    client: reqwest::Client,
+}
+
+impl ArtResolver {
+    /// Create a resolver, opening the cache directory
+    ///
+    /// # Errors
+    /// Returns error if the cache directory cannot be created or read, or
+    /// the HTTP client cannot be set up
+    pub async fn new(settings: ArtSettings) -> Result<Self, ArtError> {
+        let (dir, max_entries) = (settings.cache_dir.clone(), settings.max_entries);
+        let cache = tokio::task::spawn_blocking(move || ArtCache::open(dir, max_entries))
+            .await
+            .map_err(|e| ArtError::Io(std::io::Error::other(e)))??;
+
+        #[cfg(feature = "art-http")]
+        let client = reqwest::Client::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .map_err(|e| ArtError::Http(e.to_string()))?;
+
+        Ok(Self {
+            settings,
+            cache: Arc::new(Mutex::new(cache)),
+            #[cfg(feature = "art-http")]
+            client,
+        })
+    }
+
+    /// Resolve an art URL to a cached local image
+    ///
+    /// Cache hits return without touching the source again, except for a
+    /// metadata check on local files so replaced images are picked up.
+    ///
+    /// # Errors
+    /// Returns error if the URL is unsupported, the source cannot be loaded
+    /// or decoded, or the cache cannot be written
+    pub async fn resolve(&self, art_url: &str) -> Result<PathBuf, ArtError> {
+        let source = ArtSource::parse(art_url)?;
+        let key = self.cache_key(&source).await?;
+
+        let cached_key = key.clone();
+        if let Some(path) = self.with_cache(move |cache| cache.get(&cached_key)).await? {
+            return Ok(path);
+        }
+
+        let bytes = self.load(source).await?;
+        let size = self.settings.thumbnail_size;
+        let thumbnail = tokio::task::spawn_blocking(move || thumbnail(&bytes, size))
+            .await
+            .map_err(|e| ArtError::Io(std::io::Error::other(e)))??;
+
+        Ok(self
+            .with_cache(move |cache| cache.insert(&key, &thumbnail))
+            .await??)
+    }
+
+    /// Run `operation` on the cache from the blocking thread pool, since
+    /// cache lookups and writes touch the filesystem
+    async fn with_cache<T, F>(&self, operation: F) -> Result<T, ArtError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ArtCache) -> T + Send + 'static,
+    {
+        let cache = Arc::clone(&self.cache);
+        tokio::task::spawn_blocking(move || operation(&mut cache.blocking_lock()))
+            .await
+            .map_err(|e| ArtError::Io(std::io::Error::other(e)))
+    }
+
+    async fn cache_key(&self, source: &ArtSource) -> Result<String, ArtError> {
+        let mut hasher = Fnv1a::default();
+        hasher.write_u32(self.settings.thumbnail_size);
+
+        match source {
+            ArtSource::File(path) => {
+                let metadata = tokio::fs::metadata(path).await?;
+                let modified = metadata
+                    .modified()?
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or(Duration::ZERO);
+
+                hasher.write(path.as_os_str().as_encoded_bytes());
+                hasher.write_u64(metadata.len());
+                hasher.write_u128(modified.as_nanos());
+            }
+            ArtSource::Data(bytes) => hasher.write(bytes),
+            ArtSource::Http(url) => hasher.write(url.as_bytes()),
+        }
+
+        Ok(format!("{:016x}", hasher.finish()))
+    }
+
+    async fn load(&self, source: ArtSource) -> Result<Vec<u8>, ArtError> {
+        match source {
+            ArtSource::File(path) => read_file(&path).await,
+            ArtSource::Data(bytes) => {
+                check_size(bytes.len() as u64)?;
+                Ok(bytes)
+            }
+            ArtSource::Http(url) => self.fetch(&url).await,
+        }
+    }
+
+    #[cfg(feature = "art-http")]
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, ArtError> {
+        use futures::StreamExt;
+
+        if !self.settings.fetch_http {
+            return Err(ArtError::HttpDisabled(url.to_string()));
+        }
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| ArtError::Http(e.to_string()))?;
+
+        if let Some(length) = response.content_length() {
+            check_size(length)?;
+        }
+
+        // Servers may omit or understate the length, so the limit is also
+        // enforced while reading the body
+        let mut body = response.bytes_stream();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| ArtError::Http(e.to_string()))?;
+            check_size((bytes.len() + chunk.len()) as u64)?;
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(bytes)
+    }
+
+    #[cfg(not(feature = "art-http"))]
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, ArtError> {
+        Err(ArtError::HttpDisabled(url.to_string()))
+    }
+}
+
+async fn read_file(path: &Path) -> Result<Vec<u8>, ArtError> {
+    check_size(tokio::fs::metadata(path).await?.len())?;
+    Ok(tokio::fs::read(path).await?)
+}
+
+fn check_size(size: u64) -> Result<(), ArtError> {
+    if size > MAX_ART_SOURCE_BYTES {
+        return Err(ArtError::TooLarge {
+            size,
+            limit: MAX_ART_SOURCE_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Decode an image and re-encode it as PNG, scaled to fit `size`
+fn thumbnail(bytes: &[u8], size: u32) -> Result<Vec<u8>, ArtError> {
+    let image = image::load_from_memory(bytes)?;
+    let (width, height) = image.dimensions();
+    let image = if width > size || height > size {
+        image.thumbnail(size, size)
+    } else {
+        image
+    };
+
+    let mut png = Vec::new();
+    image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+
+    Ok(png)
+}
+
+/// FNV-1a hasher, whose output is stable across builds unlike
+/// `DefaultHasher`, so cache file names stay valid after upgrades
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use percent_encoding::percent_decode_str;
+
+use super::ArtError;
+
+/// Where a player's `mpris:artUrl` points to
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArtSource {
+    /// Local image file, from a `file://` URL or a bare absolute path
+    File(PathBuf),
+    /// Image bytes embedded in a `data:` URI
+    Data(Vec<u8>),
+    /// Remote image at an `http://` or `https://` URL
+    Http(String),
+}
+
+impl ArtSource {
+    /// Parse an art URL as reported by a player
+    ///
+    /// # Errors
+    /// Returns error if the URL uses an unsupported scheme or is a malformed
+    /// `data:` URI
+    pub fn parse(art_url: &str) -> Result<Self, ArtError> {
+        let art_url = art_url.trim();
+
+        if let Some(path) = art_url.strip_prefix("file://") {
+            let path = path.strip_prefix("localhost").unwrap_or(path);
+            let path = percent_decode_str(path)
+                .decode_utf8()
+                .map_err(|_| ArtError::UnsupportedSource(art_url.to_string()))?;
+
+            if !path.starts_with('/') {
+                return Err(ArtError::UnsupportedSource(art_url.to_string()));
+            }
+
+            return Ok(Self::File(PathBuf::from(path.as_ref())));
+        }
+
+        if art_url.starts_with('/') {
+            return Ok(Self::File(PathBuf::from(art_url)));
+        }
+
+        if let Some(data) = art_url.strip_prefix("data:") {
+            return Self::parse_data(data).map(Self::Data);
+        }
+
+        let scheme = art_url
+            .split_once("://")
+            .map(|(scheme, _)| scheme.to_ascii_lowercase());
+        if matches!(scheme.as_deref(), Some("http" | "https")) {
+            return Ok(Self::Http(art_url.to_string()));
+        }
+
+        Err(ArtError::UnsupportedSource(art_url.to_string()))
+    }
+
+    fn parse_data(data: &str) -> Result<Vec<u8>, ArtError> {
+        let (header, payload) = data
+            .split_once(',')
+            .ok_or_else(|| ArtError::InvalidDataUri("missing ',' separator".to_string()))?;
+
+        if header.ends_with(";base64") {
+            let payload: String = payload.split_whitespace().collect();
+            return STANDARD
+                .decode(payload)
+                .map_err(|e| ArtError::InvalidDataUri(e.to_string()));
+        }
+
+        Ok(percent_decode_str(payload).collect())
+    }
+}
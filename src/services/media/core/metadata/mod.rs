@@ -1,15 +1,16 @@
 pub(crate) mod monitoring;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use futures::stream::Stream;
-use monitoring::TrackMetadataMonitor;
+use monitoring::{ArtMonitor, TrackMetadataMonitor};
 use zbus::zvariant::OwnedValue;
 
 use crate::services::common::Property;
-use crate::services::media::proxy::MediaPlayer2PlayerProxy;
+use crate::services::media::{art::ArtResolver, proxy::MediaPlayer2PlayerProxy};
 use crate::watch_all;
 
 /// Default value for unknown metadata fields.
@@ -36,6 +37,12 @@ pub struct TrackMetadata {
     /// Artwork URL
     pub art_url: Property<Option<String>>,
 
+    /// Artwork resolved to a cached local image
+    ///
+    /// Only set for players from a media service with an art cache, and
+    /// lags `art_url` while the art is being loaded.
+    pub art_path: Property<Option<PathBuf>>,
+
     /// Track ID (unique identifier)
     pub track_id: Property<Option<String>>,
 }
@@ -57,6 +64,11 @@ impl TrackMetadata {
         metadata
     }
 
+    /// Keep `art_path` resolved from `art_url` until the metadata is dropped.
+    pub(crate) fn resolve_art(metadata: &Arc<Self>, resolver: Arc<ArtResolver>) {
+        ArtMonitor::start(metadata, resolver);
+    }
+
     /// Create empty metadata with "Unknown" defaults
     pub fn unknown() -> Self {
         Self {
@@ -66,6 +78,7 @@ impl TrackMetadata {
             album_artist: Property::new(UNKNOWN_METADATA.to_string()),
             length: Property::new(None),
             art_url: Property::new(None),
+            art_path: Property::new(None),
             track_id: Property::new(None),
         }
     }
@@ -97,6 +110,7 @@ impl TrackMetadata {
             album_artist,
             length,
             art_url,
+            art_path,
             track_id
         )
     }
@@ -183,6 +197,8 @@ impl From<HashMap<String, OwnedValue>> for TrackMetadata {
 
             art_url: Property::new(metadata.get("mpris:artUrl").and_then(Self::extract_string)),
 
+            art_path: Property::new(None),
+
             length: Property::new(
                 metadata
                     .get("mpris:length")
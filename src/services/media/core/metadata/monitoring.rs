@@ -1,9 +1,9 @@
 use std::sync::{Arc, Weak};
 
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use tracing::debug;
 
-use crate::services::media::proxy::MediaPlayer2PlayerProxy;
+use crate::services::media::{art::ArtResolver, proxy::MediaPlayer2PlayerProxy};
 
 use super::TrackMetadata;
 
@@ -41,3 +41,51 @@ impl TrackMetadataMonitor {
         debug!("Metadata monitoring ended");
     }
 }
+
+/// Resolves art URLs into cached images and updates `TrackMetadata::art_path`.
+pub(crate) struct ArtMonitor;
+
+impl ArtMonitor {
+    /// Start resolving art for the metadata.
+    ///
+    /// Resolution stops automatically when the TrackMetadata is dropped.
+    pub fn start(metadata: &Arc<TrackMetadata>, resolver: Arc<ArtResolver>) {
+        let weak = Arc::downgrade(metadata);
+        let art_urls = metadata.art_url.watch();
+
+        tokio::spawn(async move {
+            Self::monitor(weak, art_urls, resolver).await;
+        });
+    }
+
+    async fn monitor(
+        weak: Weak<TrackMetadata>,
+        art_urls: impl Stream<Item = Option<String>>,
+        resolver: Arc<ArtResolver>,
+    ) {
+        let mut art_urls = Box::pin(art_urls);
+
+        while let Some(art_url) = art_urls.next().await {
+            let art_path = match art_url.as_deref() {
+                Some(url) => match resolver.resolve(url).await {
+                    Ok(path) => Some(path),
+                    Err(e) => {
+                        debug!("Failed to resolve art: {e}");
+                        None
+                    }
+                },
+                None => None,
+            };
+
+            let Some(metadata) = weak.upgrade() else {
+                break;
+            };
+
+            if metadata.art_url.get() == art_url {
+                metadata.art_path.set(art_path);
+            }
+        }
+
+        debug!("Art monitoring ended");
+    }
+}
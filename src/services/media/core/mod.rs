@@ -2,6 +2,12 @@
 pub mod metadata;
 /// Media player representation with reactive properties.
 pub mod player;
+/// Playlists offered by players implementing the Playlists interface.
+pub mod playlist;
+/// Play queue of players implementing the TrackList interface.
+pub mod track_list;
 
 pub use metadata::{TrackMetadata, UNKNOWN_METADATA};
 pub use player::Player;
+pub use playlist::{Playlist, Playlists};
+pub use track_list::{Track, TrackList};
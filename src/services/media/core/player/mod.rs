@@ -1,21 +1,28 @@
 pub(crate) mod monitoring;
+pub(crate) mod position;
+
+#[cfg(test)]
+mod tests;
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::{unwrap_bool, unwrap_string_or, watch_all};
 use futures::Stream;
 use monitoring::PlayerMonitor;
-use zbus::fdo::PropertiesProxy;
-use zbus::names::{InterfaceName, MemberName};
+use position::{PositionAnchor, PositionMonitor};
 use zbus::{Connection, names::OwnedBusName, zvariant::ObjectPath};
 
 use crate::services::common::Property;
 use crate::services::media::types::{LoopMode, PlaybackState, PlayerId, ShuffleMode, Volume};
 use crate::services::media::{
     MediaError,
-    core::metadata::TrackMetadata,
-    proxy::{MediaPlayer2PlayerProxy, MediaPlayer2Proxy},
+    art::ArtResolver,
+    core::{metadata::TrackMetadata, playlist::Playlists, track_list::TrackList},
+    proxy::{
+        MediaPlayer2PlayerProxy, MediaPlayer2PlaylistsProxy, MediaPlayer2Proxy,
+        MediaPlayer2TrackListProxy,
+    },
 };
 
 /// Reactive player model with fine-grained property updates.
@@ -26,6 +33,8 @@ use crate::services::media::{
 pub struct Player {
     /// D-Bus proxy for controlling this player
     proxy: MediaPlayer2PlayerProxy<'static>,
+    /// D-Bus proxy for the player application itself
+    base_proxy: MediaPlayer2Proxy<'static>,
 
     /// Unique identifier for this player instance
     pub id: PlayerId,
@@ -33,6 +42,12 @@ pub struct Player {
     pub identity: Property<String>,
     /// Desktop file name for the player application
     pub desktop_entry: Property<Option<String>>,
+    /// Whether the player window is fullscreen
+    pub fullscreen: Property<bool>,
+    /// MIME types the player can open
+    pub supported_mime_types: Property<Vec<String>>,
+    /// URI schemes the player can open, e.g. `file` or `https`
+    pub supported_uri_schemes: Property<Vec<String>>,
 
     /// Current playback state (Playing, Paused, Stopped)
     pub playback_state: Property<PlaybackState>,
@@ -42,14 +57,32 @@ pub struct Player {
     pub shuffle_mode: Property<ShuffleMode>,
     /// Current volume level
     pub volume: Property<Volume>,
+    /// Current playback rate (1.0 is normal speed)
+    pub rate: Property<f64>,
+    /// Lowest playback rate the player supports
+    pub minimum_rate: Property<f64>,
+    /// Highest playback rate the player supports
+    pub maximum_rate: Property<f64>,
+    /// Current playback position, interpolated between player signals
+    ///
+    /// Live players publish it several times a second while playing,
+    /// without any D-Bus traffic in between.
+    pub position: Property<Duration>,
+    pub(crate) position_anchor: Property<PositionAnchor>,
 
     /// Current track metadata
     pub metadata: Arc<TrackMetadata>,
+    /// Play queue, if the player implements the TrackList interface
+    pub track_list: Option<Arc<TrackList>>,
+    /// Playlists, if the player implements the Playlists interface
+    pub playlists: Option<Arc<Playlists>>,
 
     /// Whether the player can be controlled
     pub can_control: Property<bool>,
     /// Whether playback can be started
     pub can_play: Property<bool>,
+    /// Whether playback can be paused
+    pub can_pause: Property<bool>,
     /// Whether the player can skip to the next track
     pub can_go_next: Property<bool>,
     /// Whether the player can go to the previous track
@@ -60,6 +93,12 @@ pub struct Player {
     pub can_loop: Property<bool>,
     /// Whether the player supports shuffle
     pub can_shuffle: Property<bool>,
+    /// Whether the player application can be asked to quit
+    pub can_quit: Property<bool>,
+    /// Whether the player window can be raised
+    pub can_raise: Property<bool>,
+    /// Whether the player window can enter or leave fullscreen
+    pub can_set_fullscreen: Property<bool>,
 }
 
 impl PartialEq for Player {
@@ -73,34 +112,51 @@ impl Player {
         id: PlayerId,
         identity: String,
         proxy: MediaPlayer2PlayerProxy<'static>,
+        base_proxy: MediaPlayer2Proxy<'static>,
         metadata: Arc<TrackMetadata>,
     ) -> Self {
         Self {
             proxy,
+            base_proxy,
             id,
             identity: Property::new(identity),
             desktop_entry: Property::new(None),
+            fullscreen: Property::new(false),
+            supported_mime_types: Property::new(Vec::new()),
+            supported_uri_schemes: Property::new(Vec::new()),
 
             playback_state: Property::new(PlaybackState::Stopped),
             loop_mode: Property::new(LoopMode::None),
             shuffle_mode: Property::new(ShuffleMode::Off),
             volume: Property::new(Volume::default()),
+            rate: Property::new(1.0),
+            minimum_rate: Property::new(1.0),
+            maximum_rate: Property::new(1.0),
+            position: Property::new(Duration::ZERO),
+            position_anchor: Property::new(PositionAnchor::new(Duration::ZERO, 1.0, false)),
 
             metadata,
+            track_list: None,
+            playlists: None,
 
             can_control: Property::new(false),
             can_play: Property::new(false),
+            can_pause: Property::new(false),
             can_go_next: Property::new(false),
             can_go_previous: Property::new(false),
             can_seek: Property::new(false),
             can_loop: Property::new(false),
             can_shuffle: Property::new(false),
+            can_quit: Property::new(false),
+            can_raise: Property::new(false),
+            can_set_fullscreen: Property::new(false),
         }
     }
 
     pub(crate) async fn get(
         connection: &Connection,
         player_id: PlayerId,
+        art_resolver: Option<&Arc<ArtResolver>>,
     ) -> Result<Arc<Self>, MediaError> {
         let bus_name = OwnedBusName::try_from(player_id.bus_name())
             .map_err(|e| MediaError::InitializationFailed(format!("Invalid bus name: {e}")))?;
@@ -113,8 +169,9 @@ impl Player {
             .map_err(MediaError::DbusError)?;
 
         let player_proxy = MediaPlayer2PlayerProxy::builder(connection)
-            .destination(bus_name)
+            .destination(bus_name.clone())
             .map_err(MediaError::DbusError)?
+            .uncached_properties(&["Position"])
             .build()
             .await
             .map_err(MediaError::DbusError)?;
@@ -126,7 +183,20 @@ impl Player {
         let desktop_entry = base_proxy.desktop_entry().await.ok();
 
         let metadata = TrackMetadata::new(player_proxy.clone()).await;
-        let player = Self::new(player_id, identity, player_proxy.clone(), metadata);
+        if let Some(resolver) = art_resolver {
+            TrackMetadata::resolve_art(&metadata, Arc::clone(resolver));
+        }
+        let (track_list, playlists) =
+            Self::load_extensions(connection, &bus_name, &base_proxy, false).await;
+        let mut player = Self::new(
+            player_id,
+            identity,
+            player_proxy.clone(),
+            base_proxy,
+            metadata,
+        );
+        player.track_list = track_list;
+        player.playlists = playlists;
         player.desktop_entry.set(desktop_entry);
 
         Self::refresh_properties(&player, &player_proxy).await;
@@ -137,6 +207,7 @@ impl Player {
     pub(crate) async fn get_live(
         connection: &Connection,
         player_id: PlayerId,
+        art_resolver: Option<&Arc<ArtResolver>>,
     ) -> Result<Arc<Self>, MediaError> {
         let bus_name = OwnedBusName::try_from(player_id.bus_name())
             .map_err(|e| MediaError::InitializationFailed(format!("Invalid bus name: {e}")))?;
@@ -149,8 +220,9 @@ impl Player {
             .map_err(MediaError::DbusError)?;
 
         let player_proxy = MediaPlayer2PlayerProxy::builder(connection)
-            .destination(bus_name)
+            .destination(bus_name.clone())
             .map_err(MediaError::DbusError)?
+            .uncached_properties(&["Position"])
             .build()
             .await
             .map_err(MediaError::DbusError)?;
@@ -162,17 +234,88 @@ impl Player {
         let desktop_entry = base_proxy.desktop_entry().await.ok();
 
         let metadata = TrackMetadata::new(player_proxy.clone()).await;
-        let player = Self::new(player_id.clone(), identity, player_proxy.clone(), metadata);
+        if let Some(resolver) = art_resolver {
+            TrackMetadata::resolve_art(&metadata, Arc::clone(resolver));
+        }
+        let (track_list, playlists) =
+            Self::load_extensions(connection, &bus_name, &base_proxy, true).await;
+        let mut player = Self::new(
+            player_id.clone(),
+            identity,
+            player_proxy.clone(),
+            base_proxy.clone(),
+            metadata,
+        );
+        player.track_list = track_list;
+        player.playlists = playlists;
         player.desktop_entry.set(desktop_entry);
 
         Self::refresh_properties(&player, &player_proxy).await;
 
         let player = Arc::new(player);
-        PlayerMonitor::start(player_id, Arc::clone(&player), player_proxy);
+        PlayerMonitor::start(
+            player_id,
+            Arc::clone(&player),
+            player_proxy.clone(),
+            base_proxy,
+        );
+        PositionMonitor::start(Arc::downgrade(&player), player_proxy);
 
         Ok(player)
     }
 
+    /// Load the optional TrackList and Playlists interfaces.
+    ///
+    /// Players that don't implement an interface, or fail to answer it,
+    /// get `None` for it. Only `live` players monitor them for changes;
+    /// snapshots read them once.
+    async fn load_extensions(
+        connection: &Connection,
+        bus_name: &OwnedBusName,
+        base_proxy: &MediaPlayer2Proxy<'static>,
+        live: bool,
+    ) -> (Option<Arc<TrackList>>, Option<Arc<Playlists>>) {
+        let track_list = if base_proxy.has_track_list().await.unwrap_or(false)
+            && let Ok(proxy) = Self::track_list_proxy(connection, bus_name).await
+        {
+            Some(if live {
+                TrackList::get_live(proxy).await
+            } else {
+                TrackList::get(proxy).await
+            })
+        } else {
+            None
+        };
+
+        let playlists = match Self::playlists_proxy(connection, bus_name).await {
+            Ok(proxy) if live => Playlists::get_live(proxy).await,
+            Ok(proxy) => Playlists::get(proxy).await,
+            Err(_) => None,
+        };
+
+        (track_list, playlists)
+    }
+
+    async fn track_list_proxy(
+        connection: &Connection,
+        bus_name: &OwnedBusName,
+    ) -> zbus::Result<MediaPlayer2TrackListProxy<'static>> {
+        MediaPlayer2TrackListProxy::builder(connection)
+            .destination(bus_name.clone())?
+            .build()
+            .await
+    }
+
+    async fn playlists_proxy(
+        connection: &Connection,
+        bus_name: &OwnedBusName,
+    ) -> zbus::Result<MediaPlayer2PlaylistsProxy<'static>> {
+        MediaPlayer2PlaylistsProxy::builder(connection)
+            .destination(bus_name.clone())?
+            .build()
+            .await
+    }
+
     async fn refresh_properties(player: &Player, proxy: &MediaPlayer2PlayerProxy<'_>) {
         if let Ok(status) = proxy.playback_status().await {
             player
@@ -192,8 +335,26 @@ impl Player {
             player.volume.set(Volume::from(volume));
         }
 
+        if let Ok(rate) = proxy.rate().await {
+            player.rate.set(rate);
+        }
+
+        if let Ok(minimum_rate) = proxy.minimum_rate().await {
+            player.minimum_rate.set(minimum_rate);
+        }
+
+        if let Ok(maximum_rate) = proxy.maximum_rate().await {
+            player.maximum_rate.set(maximum_rate);
+        }
+
+        let playing = player.playback_state.get() == PlaybackState::Playing;
+        let anchor = PositionAnchor::new(Duration::ZERO, player.rate.get(), playing);
+        player.position_anchor.set(anchor);
+        let _ = player.position().await;
+
         let can_control = unwrap_bool!(proxy.can_control().await);
         let can_play = unwrap_bool!(proxy.can_play().await);
+        let can_pause = unwrap_bool!(proxy.can_pause().await);
         let can_go_next = unwrap_bool!(proxy.can_go_next().await);
         let can_go_previous = unwrap_bool!(proxy.can_go_previous().await);
         let can_seek = unwrap_bool!(proxy.can_seek().await);
@@ -203,12 +364,37 @@ impl Player {
         player.update_capabilities(
             can_control,
             can_play,
+            can_pause,
             can_go_next,
             can_go_previous,
             can_seek,
             can_loop,
             can_shuffle,
         );
+
+        Self::refresh_application_properties(player).await;
+    }
+
+    async fn refresh_application_properties(player: &Player) {
+        let proxy = &player.base_proxy;
+
+        if let Ok(fullscreen) = proxy.fullscreen().await {
+            player.fullscreen.set(fullscreen);
+        }
+
+        if let Ok(mime_types) = proxy.supported_mime_types().await {
+            player.supported_mime_types.set(mime_types);
+        }
+
+        if let Ok(uri_schemes) = proxy.supported_uri_schemes().await {
+            player.supported_uri_schemes.set(uri_schemes);
+        }
+
+        player.can_quit.set(unwrap_bool!(proxy.can_quit().await));
+        player.can_raise.set(unwrap_bool!(proxy.can_raise().await));
+        player
+            .can_set_fullscreen
+            .set(proxy.can_set_fullscreen().await.unwrap_or(false));
     }
 
     /// Play or pause playback.
@@ -224,6 +410,71 @@ impl Player {
         Ok(())
     }
 
+    /// Start or resume playback.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn play(&self) -> Result<(), MediaError> {
+        self.proxy
+            .play()
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Play failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Pause playback.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn pause(&self) -> Result<(), MediaError> {
+        self.proxy
+            .pause()
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Pause failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Stop playback.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn stop(&self) -> Result<(), MediaError> {
+        self.proxy
+            .stop()
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Stop failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Open a URI and play it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::UnsupportedOperation` if the player doesn't list
+    /// the URI's scheme as supported, or `MediaError::ControlFailed` if the
+    /// D-Bus operation fails
+    pub async fn open_uri(&self, uri: &str) -> Result<(), MediaError> {
+        let schemes = self.supported_uri_schemes.get();
+        let scheme = uri.split_once(':').map(|(scheme, _)| scheme);
+        if !schemes.is_empty()
+            && !scheme.is_some_and(|scheme| schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)))
+        {
+            return Err(MediaError::UnsupportedOperation {
+                player: self.id.clone(),
+                operation: format!("opening '{uri}'"),
+            });
+        }
+
+        self.proxy
+            .open_uri(uri)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Open URI failed: {e}")))?;
+        Ok(())
+    }
+
     /// Skip to next track.
     ///
     /// # Errors
@@ -283,55 +534,55 @@ impl Player {
         Ok(())
     }
 
-    /// Get current playback position.
+    /// Get current playback position from the player.
+    ///
+    /// Queries the player over D-Bus and re-anchors the interpolated
+    /// position. For frequent reads, use [`Player::current_position`] or
+    /// the `position` property instead.
     ///
     /// # Errors
     ///
-    /// Returns error if the D-Bus operation fails
+    /// Returns `MediaError::ControlFailed` if the D-Bus operation fails
     pub async fn position(&self) -> Result<Duration, MediaError> {
-        let connection = Connection::session().await.map_err(MediaError::DbusError)?;
-        let destination = self.proxy.inner().destination().to_owned();
-        let path = self.proxy.inner().path().to_owned();
-
-        let proxy = PropertiesProxy::builder(&connection)
-            .destination(destination)
-            .map_err(|e| {
-                MediaError::ControlFailed(format!("Failed to create properties proxy: {e}"))
-            })?
-            .path(path)
-            .map_err(|e| MediaError::ControlFailed(format!("Failed to set path: {e}")))?
-            .build()
-            .await
-            .map_err(|e| {
-                MediaError::ControlFailed(format!("Failed to build properties proxy: {e}"))
-            })?;
-
-        let interface = InterfaceName::try_from("org.mpris.MediaPlayer2.Player")
-            .map_err(|e| MediaError::ControlFailed(format!("Invalid interface name: {e}")))?;
-        let property = MemberName::try_from("Position")
-            .map_err(|e| MediaError::ControlFailed(format!("Invalid property name: {e}")))?;
-
-        let value = proxy
-            .get(interface, &property)
+        let micros = self
+            .proxy
+            .position()
             .await
             .map_err(|e| MediaError::ControlFailed(format!("Failed to get position: {e}")))?;
+        let position = Duration::from_micros(micros.max(0) as u64);
 
-        let micros = i64::try_from(&value)
-            .map_err(|e| MediaError::ControlFailed(format!("Failed to parse position: {e}")))?;
+        let anchor = self.position_anchor.get();
+        self.position_anchor
+            .set(anchor.at_position(position, Instant::now()));
+        self.position.set(self.current_position());
 
-        Ok(Duration::from_micros(micros.max(0) as u64))
+        Ok(position)
+    }
+
+    /// Get the interpolated playback position.
+    ///
+    /// Computed locally from the last reported position, the playback
+    /// state and rate, so it is cheap enough to call every frame. Never
+    /// exceeds the track length when it is known.
+    pub fn current_position(&self) -> Duration {
+        let position = self.position_anchor.get().position_at(Instant::now());
+        match self.metadata.length.get() {
+            Some(length) => position.min(length),
+            None => position,
+        }
     }
 
     /// Watch position changes for this player.
     ///
-    /// Polls position every second. Use `watch_position_with_interval` for custom intervals.
+    /// Emits the interpolated position as it advances; see the `position`
+    /// property. Only live players from `player_monitored` advance.
     pub fn watch_position(&self) -> impl Stream<Item = Duration> + Send {
-        self.watch_position_with_interval(Duration::from_secs(1))
+        self.position.watch()
     }
 
-    /// Watch position changes with a specified polling interval.
+    /// Watch position changes sampled at a specified interval.
     ///
-    /// Returns a stream that emits the current position at the specified interval.
+    /// Samples the interpolated position locally, without D-Bus calls.
     /// Only emits when position actually changes to avoid redundant updates.
     pub fn watch_position_with_interval(
         &self,
@@ -340,18 +591,15 @@ impl Player {
         let player = self.clone();
         async_stream::stream! {
             let mut last_position: Option<Duration> = None;
+            let mut ticker = tokio::time::interval(interval);
 
             loop {
-                match player.position().await {
-                    Ok(position) => {
-                        if last_position != Some(position) {
-                            last_position = Some(position);
-                            yield position;
-                        }
-                    }
-                    Err(_) => break,
+                ticker.tick().await;
+                let position = player.current_position();
+                if last_position != Some(position) {
+                    last_position = Some(position);
+                    yield position;
                 }
-                tokio::time::sleep(interval).await;
             }
         }
     }
@@ -418,6 +666,87 @@ impl Player {
         Ok(())
     }
 
+    /// Set playback rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::InvalidRate` if the rate is outside the player's
+    /// supported range or not positive, or `MediaError::ControlFailed` if the
+    /// D-Bus operation fails
+    pub async fn set_rate(&self, rate: f64) -> Result<(), MediaError> {
+        check_rate(rate, self.minimum_rate.get(), self.maximum_rate.get())?;
+
+        self.proxy
+            .set_rate(rate)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Set rate failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Set whether the player window is fullscreen.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::UnsupportedOperation` if the player can't change
+    /// fullscreen mode, or `MediaError::ControlFailed` if the D-Bus operation
+    /// fails
+    pub async fn set_fullscreen(&self, fullscreen: bool) -> Result<(), MediaError> {
+        if !self.can_set_fullscreen.get() {
+            return Err(MediaError::UnsupportedOperation {
+                player: self.id.clone(),
+                operation: "fullscreen".to_string(),
+            });
+        }
+
+        self.base_proxy
+            .set_fullscreen(fullscreen)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Set fullscreen failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Bring the player window to the foreground.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::UnsupportedOperation` if the player can't be
+    /// raised, or `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn raise(&self) -> Result<(), MediaError> {
+        if !self.can_raise.get() {
+            return Err(MediaError::UnsupportedOperation {
+                player: self.id.clone(),
+                operation: "raise".to_string(),
+            });
+        }
+
+        self.base_proxy
+            .raise()
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Raise failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Ask the player application to quit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::UnsupportedOperation` if the player can't be
+    /// quit, or `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn quit(&self) -> Result<(), MediaError> {
+        if !self.can_quit.get() {
+            return Err(MediaError::UnsupportedOperation {
+                player: self.id.clone(),
+                operation: "quit".to_string(),
+            });
+        }
+
+        self.base_proxy
+            .quit()
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Quit failed: {e}")))?;
+        Ok(())
+    }
+
     /// Toggle loop mode to the next state.
     ///
     /// Cycles through: None → Track → Playlist → None
@@ -466,6 +795,7 @@ impl Player {
         &self,
         can_control: bool,
         can_play: bool,
+        can_pause: bool,
         can_go_next: bool,
         can_go_previous: bool,
         can_seek: bool,
@@ -474,6 +804,7 @@ impl Player {
     ) {
         self.can_control.set(can_control);
         self.can_play.set(can_play);
+        self.can_pause.set(can_pause);
         self.can_go_next.set(can_go_next);
         self.can_go_previous.set(can_go_previous);
         self.can_seek.set(can_seek);
@@ -485,23 +816,48 @@ impl Player {
     ///
     /// Returns a stream that emits a clone of the player whenever any property changes,
     /// including metadata, playback state, capabilities, or any other tracked field.
+    /// Position is excluded since it advances continuously, and the track list and
+    /// playlists have their own `watch`.
     pub fn watch(&self) -> impl Stream<Item = Player> + Send {
         watch_all!(
             self,
             identity,
             desktop_entry,
+            fullscreen,
+            supported_mime_types,
+            supported_uri_schemes,
             playback_state,
             loop_mode,
             shuffle_mode,
             volume,
+            rate,
+            minimum_rate,
+            maximum_rate,
             metadata,
             can_control,
             can_play,
+            can_pause,
             can_go_next,
             can_go_previous,
             can_seek,
             can_loop,
-            can_shuffle
+            can_shuffle,
+            can_quit,
+            can_raise,
+            can_set_fullscreen
         )
     }
 }
+
+/// Check that `rate` is a finite, positive rate within `minimum..=maximum`
+fn check_rate(rate: f64, minimum: f64, maximum: f64) -> Result<(), MediaError> {
+    if !rate.is_finite() || rate <= 0.0 || !(minimum..=maximum).contains(&rate) {
+        return Err(MediaError::InvalidRate {
+            rate,
+            minimum,
+            maximum,
+        });
+    }
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use futures::StreamExt;
 use tracing::{debug, instrument};
 
 use crate::services::media::{
-    proxy::MediaPlayer2PlayerProxy,
+    proxy::{MediaPlayer2PlayerProxy, MediaPlayer2Proxy},
     types::{LoopMode, PlaybackState, PlayerId, ShuffleMode, Volume},
 };
 
@@ -17,25 +17,29 @@ impl PlayerMonitor {
     /// Start monitoring a player's D-Bus properties.
     ///
     /// Monitoring stops automatically when the Player is dropped.
-    #[instrument(skip(player, proxy))]
+    #[allow(clippy::too_many_lines)]
+    #[instrument(skip(player, proxy, base_proxy))]
     pub fn start(
         player_id: PlayerId,
         player: Arc<Player>,
         proxy: MediaPlayer2PlayerProxy<'static>,
+        base_proxy: MediaPlayer2Proxy<'static>,
     ) {
         debug!("Starting property monitoring for player: {}", player_id);
 
         let weak = Arc::downgrade(&player);
         tokio::spawn(async move {
-            Self::monitor_properties(player_id, weak, proxy).await;
+            Self::monitor_properties(player_id, weak, proxy, base_proxy).await;
         });
     }
 
-    #[instrument(skip(player, proxy))]
+    #[allow(clippy::too_many_lines)]
+    #[instrument(skip(player, proxy, base_proxy))]
     async fn monitor_properties(
         player_id: PlayerId,
         player: Weak<Player>,
         proxy: MediaPlayer2PlayerProxy<'static>,
+        base_proxy: MediaPlayer2Proxy<'static>,
     ) {
         let mut playback_status_changes = proxy.receive_playback_status_changed().await;
         let mut loop_status_changes = proxy.receive_loop_status_changed().await;
@@ -45,6 +49,15 @@ impl PlayerMonitor {
         let mut can_go_previous_changes = proxy.receive_can_go_previous_changed().await;
         let mut can_play_changes = proxy.receive_can_play_changed().await;
         let mut can_seek_changes = proxy.receive_can_seek_changed().await;
+        let mut can_pause_changes = proxy.receive_can_pause_changed().await;
+        let mut minimum_rate_changes = proxy.receive_minimum_rate_changed().await;
+        let mut maximum_rate_changes = proxy.receive_maximum_rate_changed().await;
+        let mut fullscreen_changes = base_proxy.receive_fullscreen_changed().await;
+        let mut can_set_fullscreen_changes = base_proxy.receive_can_set_fullscreen_changed().await;
+        let mut can_raise_changes = base_proxy.receive_can_raise_changed().await;
+        let mut can_quit_changes = base_proxy.receive_can_quit_changed().await;
+        let mut mime_type_changes = base_proxy.receive_supported_mime_types_changed().await;
+        let mut uri_scheme_changes = base_proxy.receive_supported_uri_schemes_changed().await;
 
         loop {
             let Some(player) = player.upgrade() else {
@@ -104,6 +117,60 @@ impl PlayerMonitor {
                     }
                 }
 
+                Some(change) = can_pause_changes.next() => {
+                    if let Ok(can_pause) = change.get().await {
+                        player.can_pause.set(can_pause);
+                    }
+                }
+
+                Some(change) = minimum_rate_changes.next() => {
+                    if let Ok(minimum_rate) = change.get().await {
+                        player.minimum_rate.set(minimum_rate);
+                    }
+                }
+
+                Some(change) = maximum_rate_changes.next() => {
+                    if let Ok(maximum_rate) = change.get().await {
+                        player.maximum_rate.set(maximum_rate);
+                    }
+                }
+
+                Some(change) = fullscreen_changes.next() => {
+                    if let Ok(fullscreen) = change.get().await {
+                        player.fullscreen.set(fullscreen);
+                    }
+                }
+
+                Some(change) = can_set_fullscreen_changes.next() => {
+                    if let Ok(can_set_fullscreen) = change.get().await {
+                        player.can_set_fullscreen.set(can_set_fullscreen);
+                    }
+                }
+
+                Some(change) = can_raise_changes.next() => {
+                    if let Ok(can_raise) = change.get().await {
+                        player.can_raise.set(can_raise);
+                    }
+                }
+
+                Some(change) = can_quit_changes.next() => {
+                    if let Ok(can_quit) = change.get().await {
+                        player.can_quit.set(can_quit);
+                    }
+                }
+
+                Some(change) = mime_type_changes.next() => {
+                    if let Ok(mime_types) = change.get().await {
+                        player.supported_mime_types.set(mime_types);
+                    }
+                }
+
+                Some(change) = uri_scheme_changes.next() => {
+                    if let Ok(uri_schemes) = change.get().await {
+                        player.supported_uri_schemes.set(uri_schemes);
+                    }
+                }
+
                 else => {
                     debug!("All property streams ended for player {}", player_id);
                     break;
@@ -0,0 +1,178 @@
+use std::{
+    sync::Weak,
+    time::{Duration, Instant},
+};
+
+use futures::StreamExt;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{debug, warn};
+
+use crate::services::media::{proxy::MediaPlayer2PlayerProxy, types::PlaybackState};
+
+use super::Player;
+
+/// How often the interpolated position of a playing player is published
+const POSITION_TICK: Duration = Duration::from_millis(100);
+
+/// How often the position of a playing player is read back from D-Bus to
+/// correct drift
+const RESYNC_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Last position reported by a player and when it was reported
+///
+/// MPRIS players only announce position jumps (the `Seeked` signal), so
+/// the position in between is interpolated from this anchor, the playback
+/// state and the playback rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PositionAnchor {
+    position: Duration,
+    at: Instant,
+    rate: f64,
+    playing: bool,
+}
+
+impl PositionAnchor {
+    pub(crate) fn new(position: Duration, rate: f64, playing: bool) -> Self {
+        Self {
+            position,
+            at: Instant::now(),
+            rate,
+            playing,
+        }
+    }
+
+    /// Interpolated position at `now`
+    pub(crate) fn position_at(&self, now: Instant) -> Duration {
+        if !self.playing || self.rate <= 0.0 {
+            return self.position;
+        }
+
+        let elapsed = now.saturating_duration_since(self.at);
+        self.position + elapsed.mul_f64(self.rate)
+    }
+
+    /// Anchor at a freshly reported position
+    pub(crate) fn at_position(self, position: Duration, now: Instant) -> Self {
+        Self {
+            position,
+            at: now,
+            ..self
+        }
+    }
+
+    /// Anchor at the current interpolated position with a new rate
+    pub(crate) fn with_rate(self, rate: f64, now: Instant) -> Self {
+        Self {
+            position: self.position_at(now),
+            at: now,
+            rate,
+            ..self
+        }
+    }
+
+    /// Anchor at the current interpolated position with a new playback state
+    pub(crate) fn with_playing(self, playing: bool, now: Instant) -> Self {
+        Self {
+            position: self.position_at(now),
+            at: now,
+            playing,
+            ..self
+        }
+    }
+
+    pub(crate) fn is_playing(&self) -> bool {
+        self.playing
+    }
+}
+
+/// Tracks a player's position from signals and publishes it interpolated.
+pub(crate) struct PositionMonitor;
+
+impl PositionMonitor {
+    /// Start tracking the player's position.
+    ///
+    /// Tracking stops automatically when the Player is dropped.
+    pub fn start(player: Weak<Player>, proxy: MediaPlayer2PlayerProxy<'static>) {
+        tokio::spawn(async move {
+            Self::monitor(player, proxy).await;
+        });
+    }
+
+    async fn monitor(weak: Weak<Player>, proxy: MediaPlayer2PlayerProxy<'static>) {
+        let Ok(mut seeked) = proxy.receive_seeked().await else {
+            warn!("Failed to subscribe to Seeked, position will not be tracked");
+            return;
+        };
+        let mut rate_changes = proxy.receive_rate_changed().await;
+
+        let (mut states, mut tracks) = {
+            let Some(player) = weak.upgrade() else {
+                return;
+            };
+            (
+                Box::pin(player.playback_state.watch()),
+                Box::pin(player.metadata.track_id.watch()),
+            )
+        };
+
+        let mut tick = interval(POSITION_TICK);
+        tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut resync = interval(RESYNC_INTERVAL);
+        resync.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            let playing = weak
+                .upgrade()
+                .is_some_and(|player| player.position_anchor.get().is_playing());
+
+            tokio::select! {
+                Some(signal) = seeked.next() => {
+                    if let (Ok(args), Some(player)) = (signal.args(), weak.upgrade()) {
+                        let position = Duration::from_micros((*args.position()).max(0) as u64);
+                        let anchor = player.position_anchor.get();
+                        player
+                            .position_anchor
+                            .set(anchor.at_position(position, Instant::now()));
+                    }
+                }
+
+                Some(change) = rate_changes.next() => {
+                    if let (Ok(rate), Some(player)) = (change.get().await, weak.upgrade()) {
+                        let anchor = player.position_anchor.get();
+                        player.position_anchor.set(anchor.with_rate(rate, Instant::now()));
+                        player.rate.set(rate);
+                    }
+                }
+
+                Some(state) = states.next() => {
+                    if let Some(player) = weak.upgrade() {
+                        let anchor = player.position_anchor.get();
+                        let playing = state == PlaybackState::Playing;
+                        player.position_anchor.set(anchor.with_playing(playing, Instant::now()));
+                        let _ = player.position().await;
+                    }
+                }
+
+                Some(_) = tracks.next() => {
+                    if let Some(player) = weak.upgrade() {
+                        let _ = player.position().await;
+                    }
+                }
+
+                _ = resync.tick() => {
+                    if playing && let Some(player) = weak.upgrade() {
+                        let _ = player.position().await;
+                    }
+                }
+
+                _ = tick.tick(), if playing => {}
+            }
+
+            let Some(player) = weak.upgrade() else {
+                debug!("Player dropped, stopping position tracking");
+                return;
+            };
+            player.position.set(player.current_position());
+        }
+    }
+}
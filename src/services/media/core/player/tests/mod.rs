@@ -0,0 +1,63 @@
+//! Unit tests for player position tracking
+//!
+//! Exercises `PositionAnchor` interpolation with explicit instants and
+//! playback rate validation, so no player or D-Bus connection is required.
+
+#![allow(clippy::panic)]
+
+use std::time::{Duration, Instant};
+
+use super::{check_rate, position::PositionAnchor};
+use crate::services::media::MediaError;
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+#[test]
+fn playing_anchor_advances_with_rate() {
+    let start = Instant::now();
+    let anchor = PositionAnchor::new(secs(10), 1.0, true).at_position(secs(10), start);
+
+    assert_eq!(anchor.position_at(start + secs(5)), secs(15));
+
+    let faster = anchor.with_rate(2.0, start + secs(5));
+    assert_eq!(faster.position_at(start + secs(8)), secs(21));
+}
+
+#[test]
+fn paused_anchor_holds_position() {
+    let start = Instant::now();
+    let anchor = PositionAnchor::new(secs(0), 1.0, true).at_position(secs(30), start);
+
+    let paused = anchor.with_playing(false, start + secs(4));
+    assert_eq!(paused.position_at(start + secs(60)), secs(34));
+
+    let resumed = paused.with_playing(true, start + secs(60));
+    assert_eq!(resumed.position_at(start + secs(62)), secs(36));
+}
+
+#[test]
+fn seek_reanchors_position() {
+    let start = Instant::now();
+    let anchor = PositionAnchor::new(secs(0), 1.0, true).at_position(secs(50), start);
+
+    let seeked = anchor.at_position(secs(5), start + secs(10));
+    assert_eq!(seeked.position_at(start + secs(12)), secs(7));
+}
+
+#[test]
+fn rate_must_be_finite_positive_and_supported() {
+    assert!(check_rate(1.5, 0.5, 2.0).is_ok());
+    assert!(check_rate(2.0, 0.5, 2.0).is_ok());
+
+    for rate in [f64::NAN, f64::INFINITY, 0.0, -1.0, 0.25, 4.0] {
+        assert!(
+            matches!(
+                check_rate(rate, 0.5, 2.0),
+                Err(MediaError::InvalidRate { .. })
+            ),
+            "rate {rate} was accepted"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+pub(crate) mod monitoring;
+
+use std::sync::Arc;
+
+use futures::stream::Stream;
+use monitoring::PlaylistsMonitor;
+use tracing::warn;
+use zbus::zvariant::{ObjectPath, OwnedObjectPath};
+
+use crate::services::common::Property;
+use crate::services::media::{MediaError, proxy::MediaPlayer2PlaylistsProxy};
+use crate::watch_all;
+
+/// Ordering requested when listing playlists, if the player supports it.
+const PREFERRED_ORDERING: &str = "Alphabetical";
+
+/// A playlist offered by a player
+#[derive(Debug, Clone, PartialEq)]
+pub struct Playlist {
+    /// Playlist ID (D-Bus object path)
+    pub id: String,
+
+    /// Human-readable playlist name
+    pub name: String,
+
+    /// Icon URI, if the player provides one
+    pub icon: Option<String>,
+}
+
+impl From<(OwnedObjectPath, String, String)> for Playlist {
+    fn from((id, name, icon): (OwnedObjectPath, String, String)) -> Self {
+        Self {
+            id: id.to_string(),
+            name,
+            icon: (!icon.is_empty()).then_some(icon),
+        }
+    }
+}
+
+/// Reactive playlists of a player implementing the Playlists interface
+#[derive(Debug, Clone)]
+pub struct Playlists {
+    /// D-Bus proxy for the player's playlists
+    proxy: MediaPlayer2PlaylistsProxy<'static>,
+
+    /// Available playlists
+    pub playlists: Property<Vec<Playlist>>,
+
+    /// Currently active playlist
+    pub active: Property<Option<Playlist>>,
+}
+
+impl Playlists {
+    /// Fetch a snapshot of the playlists.
+    ///
+    /// The playlists are read once and not updated afterwards.
+    /// Returns `None` if the player doesn't implement the interface.
+    pub async fn get(proxy: MediaPlayer2PlaylistsProxy<'static>) -> Option<Arc<Self>> {
+        let count = proxy.playlist_count().await.ok()?;
+
+        let playlists = Arc::new(Self {
+            proxy: proxy.clone(),
+            playlists: Property::new(Vec::new()),
+            active: Property::new(None),
+        });
+
+        playlists.load(count).await;
+        if let Ok(active) = proxy.active_playlist().await {
+            playlists.set_active(active);
+        }
+
+        Some(playlists)
+    }
+
+    /// Create playlists with D-Bus monitoring.
+    ///
+    /// Fetches the initial playlists and starts monitoring for changes.
+    /// Returns `None` if the player doesn't implement the interface.
+    pub async fn get_live(proxy: MediaPlayer2PlaylistsProxy<'static>) -> Option<Arc<Self>> {
+        let playlists = Self::get(proxy.clone()).await?;
+        PlaylistsMonitor::start(Arc::clone(&playlists), proxy);
+        Some(playlists)
+    }
+
+    /// Fetch the first `count` playlists.
+    pub(crate) async fn load(&self, count: u32) {
+        let orderings = self.proxy.orderings().await.unwrap_or_default();
+        let ordering = orderings
+            .iter()
+            .find(|ordering| *ordering == PREFERRED_ORDERING)
+            .or(orderings.first())
+            .map_or(PREFERRED_ORDERING, String::as_str);
+
+        match self.proxy.get_playlists(0, count, ordering, false).await {
+            Ok(playlists) => {
+                self.playlists
+                    .set(playlists.into_iter().map(Playlist::from).collect());
+            }
+            Err(e) => warn!("Failed to fetch playlists: {e}"),
+        }
+    }
+
+    pub(crate) fn set_active(&self, (valid, playlist): (bool, (OwnedObjectPath, String, String))) {
+        self.active.set(valid.then(|| Playlist::from(playlist)));
+    }
+
+    pub(crate) fn update(&self, changed: Playlist) {
+        let mut playlists = self.playlists.get();
+        if let Some(playlist) = playlists.iter_mut().find(|p| p.id == changed.id) {
+            *playlist = changed.clone();
+            self.playlists.set(playlists);
+        }
+
+        if self
+            .active
+            .get()
+            .is_some_and(|active| active.id == changed.id)
+        {
+            self.active.set(Some(changed));
+        }
+    }
+
+    /// Start playing a playlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::ControlFailed` if the playlist ID is invalid or
+    /// the D-Bus operation fails
+    pub async fn activate(&self, playlist_id: &str) -> Result<(), MediaError> {
+        let playlist_path = ObjectPath::try_from(playlist_id)
+            .map_err(|e| MediaError::ControlFailed(format!("Invalid playlist ID: {e}")))?;
+
+        self.proxy
+            .activate_playlist(&playlist_path)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Activate playlist failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Watch for any playlist changes.
+    ///
+    /// Emits whenever playlists are added, removed or renamed, or another
+    /// playlist becomes active.
+    pub fn watch(&self) -> impl Stream<Item = Playlists> + Send {
+        watch_all!(self, playlists, active)
+    }
+}
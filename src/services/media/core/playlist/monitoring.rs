@@ -0,0 +1,64 @@
+use std::sync::{Arc, Weak};
+
+use futures::StreamExt;
+use tracing::{debug, warn};
+
+use crate::services::media::proxy::MediaPlayer2PlaylistsProxy;
+
+use super::{Playlist, Playlists};
+
+/// Monitors D-Bus playlist properties and updates the reactive Playlists model.
+pub(crate) struct PlaylistsMonitor;
+
+impl PlaylistsMonitor {
+    /// Start monitoring for playlist changes.
+    ///
+    /// Monitoring stops automatically when the Playlists are dropped.
+    pub fn start(playlists: Arc<Playlists>, proxy: MediaPlayer2PlaylistsProxy<'static>) {
+        let weak = Arc::downgrade(&playlists);
+
+        tokio::spawn(async move {
+            Self::monitor(weak, proxy).await;
+        });
+    }
+
+    async fn monitor(weak: Weak<Playlists>, proxy: MediaPlayer2PlaylistsProxy<'static>) {
+        let Ok(mut playlist_changed) = proxy.receive_playlist_changed().await else {
+            warn!("Failed to subscribe to PlaylistChanged");
+            return;
+        };
+        let mut count_changes = proxy.receive_playlist_count_changed().await;
+        let mut active_changes = proxy.receive_active_playlist_changed().await;
+
+        loop {
+            tokio::select! {
+                Some(signal) = playlist_changed.next() => {
+                    if let (Ok(args), Some(playlists)) = (signal.args(), weak.upgrade()) {
+                        playlists.update(Playlist::from(args.playlist().clone()));
+                    }
+                }
+
+                Some(change) = count_changes.next() => {
+                    if let (Ok(count), Some(playlists)) = (change.get().await, weak.upgrade()) {
+                        playlists.load(count).await;
+                    }
+                }
+
+                Some(change) = active_changes.next() => {
+                    if let (Ok(active), Some(playlists)) = (change.get().await, weak.upgrade()) {
+                        playlists.set_active(active);
+                    }
+                }
+
+                else => break,
+            }
+
+            if weak.strong_count() == 0 {
+                debug!("Playlists dropped, stopping monitor");
+                return;
+            }
+        }
+
+        debug!("Playlist monitoring ended");
+    }
+}
@@ -0,0 +1,267 @@
+pub(crate) mod monitoring;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::stream::Stream;
+use monitoring::TrackListMonitor;
+use tracing::warn;
+use zbus::zvariant::{ObjectPath, OwnedObjectPath, OwnedValue};
+
+use crate::services::common::Property;
+use crate::services::media::{
+    MediaError, core::metadata::TrackMetadata, proxy::MediaPlayer2TrackListProxy,
+};
+use crate::watch_all;
+
+/// Track ID that stands for "no track" in the TrackList interface.
+pub const NO_TRACK: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+
+/// A track in a player's queue
+#[derive(Debug, Clone)]
+pub struct Track {
+    /// Track ID (D-Bus object path) identifying the track in the queue
+    pub id: String,
+
+    /// Track metadata, updated in place when the player reports changes
+    pub metadata: Arc<TrackMetadata>,
+}
+
+impl PartialEq for Track {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && Arc::ptr_eq(&self.metadata, &other.metadata)
+    }
+}
+
+/// Reactive play queue of a player implementing the TrackList interface
+#[derive(Debug, Clone)]
+pub struct TrackList {
+    /// D-Bus proxy for the player's track list
+    proxy: MediaPlayer2TrackListProxy<'static>,
+
+    /// Tracks in queue order
+    pub tracks: Property<Vec<Track>>,
+
+    /// Whether tracks can be added and removed
+    pub can_edit_tracks: Property<bool>,
+}
+
+impl TrackList {
+    /// Fetch a snapshot of the track list.
+    ///
+    /// The queue is read once and not updated afterwards.
+    pub async fn get(proxy: MediaPlayer2TrackListProxy<'static>) -> Arc<Self> {
+        let track_list = Arc::new(Self {
+            proxy: proxy.clone(),
+            tracks: Property::new(Vec::new()),
+            can_edit_tracks: Property::new(false),
+        });
+
+        if let Ok(can_edit_tracks) = proxy.can_edit_tracks().await {
+            track_list.can_edit_tracks.set(can_edit_tracks);
+        }
+
+        if let Ok(track_ids) = proxy.tracks().await {
+            track_list.load(&track_ids).await;
+        }
+
+        track_list
+    }
+
+    /// Create a track list with D-Bus monitoring.
+    ///
+    /// Fetches the initial queue and starts monitoring for changes.
+    pub async fn get_live(proxy: MediaPlayer2TrackListProxy<'static>) -> Arc<Self> {
+        let track_list = Self::get(proxy.clone()).await;
+        TrackListMonitor::start(Arc::clone(&track_list), proxy);
+        track_list
+    }
+
+    /// Replace the queue with the given tracks, fetching their metadata.
+    ///
+    /// Tracks that are already known keep their metadata instance.
+    pub(crate) async fn load(&self, track_ids: &[OwnedObjectPath]) {
+        let current = self.tracks.get();
+        let missing = missing_tracks(&current, track_ids);
+
+        let mut fetched: HashMap<String, Arc<TrackMetadata>> = HashMap::new();
+        if !missing.is_empty() {
+            match self.proxy.get_tracks_metadata(&missing).await {
+                Ok(tracks_metadata) => {
+                    for dbus_metadata in tracks_metadata {
+                        let metadata = TrackMetadata::from(dbus_metadata);
+                        if let Some(id) = metadata.track_id.get() {
+                            fetched.insert(id, Arc::new(metadata));
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to fetch track list metadata: {e}"),
+            }
+        }
+
+        self.tracks.set(merge_tracks(&current, track_ids, fetched));
+    }
+
+    pub(crate) fn insert_after(&self, dbus_metadata: HashMap<String, OwnedValue>, after: &str) {
+        let metadata = Arc::new(TrackMetadata::from(dbus_metadata));
+        let Some(id) = metadata.track_id.get() else {
+            return;
+        };
+
+        let mut tracks = self.tracks.get();
+        insert_track_after(&mut tracks, Track { id, metadata }, after);
+        self.tracks.set(tracks);
+    }
+
+    pub(crate) fn remove(&self, track_id: &str) {
+        let mut tracks = self.tracks.get();
+        remove_track(&mut tracks, track_id);
+        self.tracks.set(tracks);
+    }
+
+    pub(crate) fn update_metadata(
+        &self,
+        track_id: &str,
+        dbus_metadata: HashMap<String, OwnedValue>,
+    ) {
+        if let Some(track) = self.tracks.get().iter().find(|track| track.id == track_id) {
+            TrackMetadata::update_from_dbus(&track.metadata, dbus_metadata);
+        }
+    }
+
+    /// Skip to a track in the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::ControlFailed` if the track ID is invalid or the
+    /// D-Bus operation fails
+    pub async fn go_to(&self, track_id: &str) -> Result<(), MediaError> {
+        let track_path = Self::track_path(track_id)?;
+        self.proxy
+            .go_to(&track_path)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Go to track failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Add a URI to the queue.
+    ///
+    /// The track is inserted after `after_track`, or at the start of the
+    /// queue when it is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::OperationNotSupported` if the queue can't be
+    /// edited, or `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn add_track(
+        &self,
+        uri: &str,
+        after_track: Option<&str>,
+        set_as_current: bool,
+    ) -> Result<(), MediaError> {
+        self.ensure_editable()?;
+
+        let after_path = Self::track_path(after_track.unwrap_or(NO_TRACK))?;
+        self.proxy
+            .add_track(uri, &after_path, set_as_current)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Add track failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Remove a track from the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MediaError::OperationNotSupported` if the queue can't be
+    /// edited, or `MediaError::ControlFailed` if the D-Bus operation fails
+    pub async fn remove_track(&self, track_id: &str) -> Result<(), MediaError> {
+        self.ensure_editable()?;
+
+        let track_path = Self::track_path(track_id)?;
+        self.proxy
+            .remove_track(&track_path)
+            .await
+            .map_err(|e| MediaError::ControlFailed(format!("Remove track failed: {e}")))?;
+        Ok(())
+    }
+
+    /// Watch for any track list changes.
+    ///
+    /// Emits whenever tracks are added, removed or reordered, or editing
+    /// support changes. Per-track metadata is watched on each track.
+    pub fn watch(&self) -> impl Stream<Item = TrackList> + Send {
+        watch_all!(self, tracks, can_edit_tracks)
+    }
+
+    fn ensure_editable(&self) -> Result<(), MediaError> {
+        if self.can_edit_tracks.get() {
+            return Ok(());
+        }
+
+        Err(MediaError::OperationNotSupported(
+            "Track list editing not supported".to_string(),
+        ))
+    }
+
+    fn track_path(track_id: &str) -> Result<ObjectPath<'_>, MediaError> {
+        ObjectPath::try_from(track_id)
+            .map_err(|e| MediaError::ControlFailed(format!("Invalid track ID: {e}")))
+    }
+}
+
+/// Track IDs of the new queue that `current` doesn't hold yet
+fn missing_tracks<'a>(current: &[Track], track_ids: &'a [OwnedObjectPath]) -> Vec<ObjectPath<'a>> {
+    track_ids
+        .iter()
+        .filter(|id| !current.iter().any(|track| track.id == id.as_str()))
+        .map(|id| ObjectPath::clone(id))
+        .collect()
+}
+
+/// Build the queue for `track_ids` in order
+///
+/// Tracks already in `current` are reused with their metadata instance,
+/// new tracks take theirs from `fetched`, or unknown metadata if the
+/// player didn't report any.
+fn merge_tracks(
+    current: &[Track],
+    track_ids: &[OwnedObjectPath],
+    mut fetched: HashMap<String, Arc<TrackMetadata>>,
+) -> Vec<Track> {
+    track_ids
+        .iter()
+        .map(|id| {
+            current
+                .iter()
+                .find(|track| track.id == id.as_str())
+                .cloned()
+                .unwrap_or_else(|| Track {
+                    id: id.to_string(),
+                    metadata: fetched
+                        .remove(id.as_str())
+                        .unwrap_or_else(|| Arc::new(TrackMetadata::unknown())),
+                })
+        })
+        .collect()
+}
+
+/// Insert `track` after the track with ID `after`
+///
+/// The track goes to the start of the queue when `after` is [`NO_TRACK`] or
+/// not in the queue. A track with the same ID already queued is replaced.
+fn insert_track_after(tracks: &mut Vec<Track>, track: Track, after: &str) {
+    remove_track(tracks, &track.id);
+    let index = tracks
+        .iter()
+        .position(|queued| queued.id == after)
+        .map_or(0, |index| index + 1);
+    tracks.insert(index, track);
+}
+
+fn remove_track(tracks: &mut Vec<Track>, track_id: &str) {
+    tracks.retain(|track| track.id != track_id);
+}
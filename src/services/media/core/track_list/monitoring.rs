@@ -0,0 +1,88 @@
+use std::sync::{Arc, Weak};
+
+use futures::StreamExt;
+use tracing::{debug, warn};
+
+use crate::services::media::proxy::MediaPlayer2TrackListProxy;
+
+use super::TrackList;
+
+/// Monitors D-Bus track list signals and updates the reactive TrackList model.
+pub(crate) struct TrackListMonitor;
+
+impl TrackListMonitor {
+    /// Start monitoring for track list changes.
+    ///
+    /// Monitoring stops automatically when the TrackList is dropped.
+    pub fn start(track_list: Arc<TrackList>, proxy: MediaPlayer2TrackListProxy<'static>) {
+        let weak = Arc::downgrade(&track_list);
+
+        tokio::spawn(async move {
+            Self::monitor(weak, proxy).await;
+        });
+    }
+
+    #[allow(clippy::cognitive_complexity)]
+    async fn monitor(weak: Weak<TrackList>, proxy: MediaPlayer2TrackListProxy<'static>) {
+        let (Ok(mut replaced), Ok(mut added), Ok(mut removed), Ok(mut metadata_changed)) = (
+            proxy.receive_track_list_replaced().await,
+            proxy.receive_track_added().await,
+            proxy.receive_track_removed().await,
+            proxy.receive_track_metadata_changed().await,
+        ) else {
+            warn!("Failed to subscribe to track list signals");
+            return;
+        };
+        let mut tracks_changes = proxy.receive_tracks_changed().await;
+        let mut can_edit_changes = proxy.receive_can_edit_tracks_changed().await;
+
+        loop {
+            tokio::select! {
+                Some(signal) = replaced.next() => {
+                    if let (Ok(args), Some(track_list)) = (signal.args(), weak.upgrade()) {
+                        track_list.load(args.tracks()).await;
+                    }
+                }
+
+                Some(signal) = added.next() => {
+                    if let (Ok(args), Some(track_list)) = (signal.args(), weak.upgrade()) {
+                        track_list.insert_after(args.metadata().clone(), args.after_track().as_str());
+                    }
+                }
+
+                Some(signal) = removed.next() => {
+                    if let (Ok(args), Some(track_list)) = (signal.args(), weak.upgrade()) {
+                        track_list.remove(args.track_id().as_str());
+                    }
+                }
+
+                Some(signal) = metadata_changed.next() => {
+                    if let (Ok(args), Some(track_list)) = (signal.args(), weak.upgrade()) {
+                        track_list.update_metadata(args.track_id().as_str(), args.metadata().clone());
+                    }
+                }
+
+                Some(change) = tracks_changes.next() => {
+                    if let (Ok(track_ids), Some(track_list)) = (change.get().await, weak.upgrade()) {
+                        track_list.load(&track_ids).await;
+                    }
+                }
+
+                Some(change) = can_edit_changes.next() => {
+                    if let (Ok(can_edit_tracks), Some(track_list)) = (change.get().await, weak.upgrade()) {
+                        track_list.can_edit_tracks.set(can_edit_tracks);
+                    }
+                }
+
+                else => break,
+            }
+
+            if weak.strong_count() == 0 {
+                debug!("TrackList dropped, stopping monitor");
+                return;
+            }
+        }
+
+        debug!("Track list monitoring ended");
+    }
+}
@@ -0,0 +1,105 @@
+//! Unit tests for track list queue updates
+//!
+//! Exercises the queue merging, insertion and removal rules on plain track
+//! vectors, so no player or D-Bus connection is required.
+
+#![allow(clippy::panic)]
+
+use std::{collections::HashMap, sync::Arc};
+
+use zbus::zvariant::OwnedObjectPath;
+
+use super::{NO_TRACK, Track, insert_track_after, merge_tracks, missing_tracks, remove_track};
+use crate::services::media::core::metadata::TrackMetadata;
+
+fn track(id: &str) -> Track {
+    Track {
+        id: id.to_string(),
+        metadata: Arc::new(TrackMetadata::unknown()),
+    }
+}
+
+fn paths(ids: &[&str]) -> Vec<OwnedObjectPath> {
+    ids.iter()
+        .map(|id| OwnedObjectPath::try_from(*id).unwrap())
+        .collect()
+}
+
+fn ids(tracks: &[Track]) -> Vec<&str> {
+    tracks.iter().map(|track| track.id.as_str()).collect()
+}
+
+#[test]
+fn only_unknown_tracks_are_fetched() {
+    let current = vec![track("/t/1"), track("/t/2")];
+    let track_ids = paths(&["/t/2", "/t/3", "/t/1", "/t/4"]);
+
+    let missing = missing_tracks(&current, &track_ids);
+    let missing: Vec<&str> = missing.iter().map(|id| id.as_str()).collect();
+
+    assert_eq!(missing, vec!["/t/3", "/t/4"]);
+}
+
+#[test]
+fn merged_queue_reuses_metadata_of_known_tracks() {
+    let current = vec![track("/t/1"), track("/t/2")];
+    let fetched_metadata = Arc::new(TrackMetadata::unknown());
+    let fetched = HashMap::from([("/t/3".to_string(), Arc::clone(&fetched_metadata))]);
+
+    let merged = merge_tracks(&current, &paths(&["/t/2", "/t/3", "/t/1", "/t/4"]), fetched);
+
+    assert_eq!(ids(&merged), vec!["/t/2", "/t/3", "/t/1", "/t/4"]);
+    assert_eq!(merged[0], current[1]);
+    assert_eq!(merged[2], current[0]);
+    assert!(Arc::ptr_eq(&merged[1].metadata, &fetched_metadata));
+    assert_eq!(merged[3].metadata.track_id.get(), None);
+}
+
+#[test]
+fn merged_queue_drops_tracks_no_longer_listed() {
+    let current = vec![track("/t/1"), track("/t/2")];
+
+    let merged = merge_tracks(&current, &paths(&["/t/2"]), HashMap::new());
+
+    assert_eq!(merged, vec![current[1].clone()]);
+}
+
+#[test]
+fn track_is_inserted_after_the_given_track() {
+    let mut tracks = vec![track("/t/1"), track("/t/2")];
+
+    insert_track_after(&mut tracks, track("/t/3"), "/t/1");
+
+    assert_eq!(ids(&tracks), vec!["/t/1", "/t/3", "/t/2"]);
+}
+
+#[test]
+fn track_after_no_track_or_unknown_track_goes_first() {
+    let mut tracks = vec![track("/t/1"), track("/t/2")];
+
+    insert_track_after(&mut tracks, track("/t/3"), NO_TRACK);
+    insert_track_after(&mut tracks, track("/t/4"), "/t/missing");
+
+    assert_eq!(ids(&tracks), vec!["/t/4", "/t/3", "/t/1", "/t/2"]);
+}
+
+#[test]
+fn inserting_a_queued_track_replaces_it() {
+    let mut tracks = vec![track("/t/1"), track("/t/2"), track("/t/3")];
+    let replacement = track("/t/1");
+
+    insert_track_after(&mut tracks, replacement.clone(), "/t/3");
+
+    assert_eq!(ids(&tracks), vec!["/t/2", "/t/3", "/t/1"]);
+    assert_eq!(tracks[2], replacement);
+}
+
+#[test]
+fn removing_a_track_keeps_the_rest_in_order() {
+    let mut tracks = vec![track("/t/1"), track("/t/2"), track("/t/3")];
+
+    remove_track(&mut tracks, "/t/2");
+    remove_track(&mut tracks, "/t/missing");
+
+    assert_eq!(ids(&tracks), vec!["/t/1", "/t/3"]);
+}
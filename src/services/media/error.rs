@@ -35,6 +35,17 @@ pub enum MediaError {
         length: Option<Duration>,
     },
 
+    /// Playback rate is outside the range the player supports
+    #[error("Invalid playback rate: {rate} (supported: {minimum} to {maximum})")]
+    InvalidRate {
+        /// Requested rate
+        rate: f64,
+        /// Lowest rate the player supports
+        minimum: f64,
+        /// Highest rate the player supports
+        maximum: f64,
+    },
+
     /// Player is not responding to requests
     #[error("Player {0:?} not responding")]
     PlayerUnresponsive(PlayerId),
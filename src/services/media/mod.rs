@@ -3,6 +3,8 @@
 //! This module provides reactive media player control through the D-Bus MPRIS protocol.
 //! It automatically discovers players and provides streams for UI updates.
 
+/// Album art resolution and caching
+pub mod art;
 /// Error types for the MPRIS service
 pub mod error;
 /// Main MPRIS service implementation
@@ -15,8 +17,17 @@ pub mod core;
 
 mod monitoring;
 mod proxy;
+mod selection;
 
-pub use core::{Player, TrackMetadata, UNKNOWN_METADATA};
+#[cfg(test)]
+mod tests;
+
+pub use art::{
+    ART_CACHE_DIR_NAME, ArtError, ArtResolver, ArtSettings, DEFAULT_ART_CACHE_ENTRIES,
+    DEFAULT_ART_SIZE,
+};
+pub use core::{Player, Playlist, Playlists, Track, TrackList, TrackMetadata, UNKNOWN_METADATA};
 pub use error::MediaError;
+pub use selection::ActivePlayerPolicy;
 pub use service::{Config, MediaService};
 pub use types::*;
@@ -1,14 +1,17 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use futures::StreamExt;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, instrument, warn};
 use zbus::{Connection, fdo::DBusProxy};
 
 use crate::runtime_state::RuntimeState;
 use crate::services::common::Property;
-use crate::services::media::{MediaError, PlayerId, core::Player};
+use crate::services::media::{
+    MediaError, PlayerId, art::ArtResolver, core::Player, selection::ActivePlayerSelector,
+};
 
 const MPRIS_BUS_PREFIX: &str = "org.mpris.MediaPlayer2.";
 
@@ -28,34 +31,36 @@ impl MprisMonitoring {
         players: Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
         player_list: Property<Vec<Arc<Player>>>,
         active_player: Property<Option<Arc<Player>>>,
+        selector: Arc<Mutex<ActivePlayerSelector>>,
+        art_resolver: Option<Arc<ArtResolver>>,
         ignored_patterns: Vec<String>,
     ) -> Result<(), MediaError> {
+        if let Ok(Some(saved_player_id)) = RuntimeState::get_active_player().await {
+            selector
+                .lock()
+                .await
+                .pin(Some(PlayerId::from_bus_name(&saved_player_id)));
+            debug!("Restored pinned player from state: {}", saved_player_id);
+        }
+
         Self::discover_existing_players(
             connection,
             &players,
             &player_list,
             &active_player,
+            &selector,
+            art_resolver.as_ref(),
             &ignored_patterns,
         )
         .await?;
 
-        if let Ok(Some(saved_player_id)) = RuntimeState::get_active_player().await {
-            let players_map = players.read().await;
-            if let Some(player_id) = players_map
-                .keys()
-                .find(|id| id.bus_name() == saved_player_id)
-            {
-                let pl = Player::get_live(connection, player_id.clone()).await?;
-                active_player.set(Some(pl));
-                debug!("Restored active player from state: {}", saved_player_id);
-            }
-        }
-
         Self::spawn_name_monitoring(
             connection,
             players,
             player_list,
             active_player,
+            selector,
+            art_resolver,
             ignored_patterns,
         );
 
@@ -67,6 +72,8 @@ impl MprisMonitoring {
         players: &Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
         player_list: &Property<Vec<Arc<Player>>>,
         active_player: &Property<Option<Arc<Player>>>,
+        selector: &Arc<Mutex<ActivePlayerSelector>>,
+        art_resolver: Option<&Arc<ArtResolver>>,
         ignored_patterns: &[String],
     ) -> Result<(), MediaError> {
         let dbus_proxy = DBusProxy::new(connection)
@@ -86,6 +93,8 @@ impl MprisMonitoring {
                     players,
                     player_list,
                     active_player,
+                    selector,
+                    art_resolver,
                     player_id,
                 )
                 .await;
@@ -100,6 +109,8 @@ impl MprisMonitoring {
         players: Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
         player_list: Property<Vec<Arc<Player>>>,
         active_player: Property<Option<Arc<Player>>>,
+        selector: Arc<Mutex<ActivePlayerSelector>>,
+        art_resolver: Option<Arc<ArtResolver>>,
         ignored_patterns: Vec<String>,
     ) {
         let connection = connection.clone();
@@ -133,12 +144,20 @@ impl MprisMonitoring {
                         &players,
                         &player_list,
                         &active_player,
+                        &selector,
+                        art_resolver.as_ref(),
                         player_id.clone(),
                     )
                     .await;
                 } else if is_player_removed {
-                    Self::handle_player_removed(&players, &player_list, &active_player, player_id)
-                        .await;
+                    Self::handle_player_removed(
+                        &players,
+                        &player_list,
+                        &active_player,
+                        &selector,
+                        player_id,
+                    )
+                    .await;
                 }
             }
 
@@ -151,21 +170,36 @@ impl MprisMonitoring {
         players: &Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
         player_list: &Property<Vec<Arc<Player>>>,
         active_player: &Property<Option<Arc<Player>>>,
+        selector: &Arc<Mutex<ActivePlayerSelector>>,
+        art_resolver: Option<&Arc<ArtResolver>>,
         player_id: PlayerId,
     ) {
-        match Player::get_live(connection, player_id.clone()).await {
+        match Player::get_live(connection, player_id.clone(), art_resolver).await {
             Ok(player) => {
                 let mut players_map = players.write().await;
                 players_map.insert(player_id.clone(), Arc::clone(&player));
 
-                if active_player.get().is_none() {
-                    active_player.set(Some(player.clone()));
-                }
-
                 let mut current_list = player_list.get();
                 current_list.push(player.clone());
                 player_list.set(current_list);
 
+                selector.lock().await.record(
+                    &player_id,
+                    player.playback_state.get(),
+                    Instant::now(),
+                );
+                Self::select_active_player(&players_map, player_list, active_player, selector)
+                    .await;
+                drop(players_map);
+
+                Self::spawn_activity_monitoring(
+                    &player,
+                    players,
+                    player_list,
+                    active_player,
+                    selector,
+                );
+
                 debug!("Player {} added", player_id);
             }
             Err(e) => {
@@ -178,18 +212,12 @@ impl MprisMonitoring {
         players: &Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
         player_list: &Property<Vec<Arc<Player>>>,
         active_player: &Property<Option<Arc<Player>>>,
+        selector: &Arc<Mutex<ActivePlayerSelector>>,
         player_id: PlayerId,
     ) {
         let mut players_map = players.write().await;
         players_map.remove(&player_id);
 
-        if let Some(current_active) = active_player.get() {
-            if current_active.id == player_id {
-                let new_active = players_map.values().next().cloned();
-                active_player.set(new_active);
-            }
-        }
-
         let current_list = player_list.get();
         let updated_list: Vec<Arc<Player>> = current_list
             .into_iter()
@@ -197,9 +225,82 @@ impl MprisMonitoring {
             .collect();
         player_list.set(updated_list);
 
+        selector.lock().await.remove(&player_id);
+        Self::select_active_player(&players_map, player_list, active_player, selector).await;
+
         debug!("Player {} removed", player_id);
     }
 
+    /// Re-rank players whenever this player's playback state changes.
+    ///
+    /// Ends when the player is dropped, which closes its property streams.
+    fn spawn_activity_monitoring(
+        player: &Arc<Player>,
+        players: &Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
+        player_list: &Property<Vec<Arc<Player>>>,
+        active_player: &Property<Option<Arc<Player>>>,
+        selector: &Arc<Mutex<ActivePlayerSelector>>,
+    ) {
+        let player_id = player.id.clone();
+        let mut states = Box::pin(player.playback_state.watch());
+        let players = Arc::clone(players);
+        let player_list = player_list.clone();
+        let active_player = active_player.clone();
+        let selector = Arc::clone(selector);
+
+        tokio::spawn(async move {
+            while let Some(state) = states.next().await {
+                let players_map = players.read().await;
+                if !players_map.contains_key(&player_id) {
+                    break;
+                }
+
+                selector
+                    .lock()
+                    .await
+                    .record(&player_id, state, Instant::now());
+                Self::select_active_player(&players_map, &player_list, &active_player, &selector)
+                    .await;
+            }
+
+            debug!("Activity monitoring for {} ended", player_id);
+        });
+    }
+
+    /// Apply the selection policy to the active player.
+    ///
+    /// Only ever picks players from the monitored map, so the active player
+    /// keeps updating.
+    pub(crate) async fn select_active_player(
+        players_map: &HashMap<PlayerId, Arc<Player>>,
+        player_list: &Property<Vec<Arc<Player>>>,
+        active_player: &Property<Option<Arc<Player>>>,
+        selector: &Arc<Mutex<ActivePlayerSelector>>,
+    ) {
+        let candidates: Vec<PlayerId> = player_list
+            .get()
+            .iter()
+            .map(|player| player.id.clone())
+            .filter(|id| players_map.contains_key(id))
+            .collect();
+        let current = active_player.get();
+        let current_id = current.as_ref().map(|player| &player.id);
+
+        let selected = selector
+            .lock()
+            .await
+            .select(&candidates, current_id)
+            .cloned();
+        if selected.as_ref() == current_id {
+            return;
+        }
+
+        if let Some(ref id) = selected {
+            debug!("Active player switched to {}", id);
+        }
+        active_player.set(selected.and_then(|id| players_map.get(&id).cloned()));
+    }
+
     fn should_ignore(bus_name: &str, ignored_patterns: &[String]) -> bool {
         ignored_patterns
             .iter()
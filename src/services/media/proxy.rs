@@ -1,5 +1,8 @@
 use std::collections::HashMap;
-use zbus::{Result, proxy, zvariant::ObjectPath};
+use zbus::{
+    Result, proxy,
+    zvariant::{ObjectPath, OwnedObjectPath, OwnedValue},
+};
 
 /// MPRIS MediaPlayer2 interface proxy
 ///
@@ -173,3 +176,107 @@ pub trait MediaPlayer2Player {
     #[zbus(property)]
     fn can_control(&self) -> Result<bool>;
 }
+
+/// MPRIS MediaPlayer2.TrackList interface proxy
+///
+/// Provides access to the play queue of players that expose one
+#[proxy(
+    interface = "org.mpris.MediaPlayer2.TrackList",
+    default_service = "org.mpris.MediaPlayer2",
+    default_path = "/org/mpris/MediaPlayer2"
+)]
+pub trait MediaPlayer2TrackList {
+    /// Get metadata for the given tracks
+    fn get_tracks_metadata(
+        &self,
+        track_ids: &[ObjectPath<'_>],
+    ) -> Result<Vec<HashMap<String, OwnedValue>>>;
+
+    /// Add a URI to the track list after the given track
+    fn add_track(
+        &self,
+        uri: &str,
+        after_track: &ObjectPath<'_>,
+        set_as_current: bool,
+    ) -> Result<()>;
+
+    /// Remove a track from the track list
+    fn remove_track(&self, track_id: &ObjectPath<'_>) -> Result<()>;
+
+    /// Skip to a track in the track list
+    fn go_to(&self, track_id: &ObjectPath<'_>) -> Result<()>;
+
+    /// Signal emitted when the whole track list is replaced
+    #[zbus(signal)]
+    fn track_list_replaced(
+        &self,
+        tracks: Vec<OwnedObjectPath>,
+        current_track: OwnedObjectPath,
+    ) -> Result<()>;
+
+    /// Signal emitted when a track is added
+    #[zbus(signal)]
+    fn track_added(
+        &self,
+        metadata: HashMap<String, OwnedValue>,
+        after_track: OwnedObjectPath,
+    ) -> Result<()>;
+
+    /// Signal emitted when a track is removed
+    #[zbus(signal)]
+    fn track_removed(&self, track_id: OwnedObjectPath) -> Result<()>;
+
+    /// Signal emitted when a track's metadata changes
+    #[zbus(signal)]
+    fn track_metadata_changed(
+        &self,
+        track_id: OwnedObjectPath,
+        metadata: HashMap<String, OwnedValue>,
+    ) -> Result<()>;
+
+    /// Track IDs in the track list, in order
+    #[zbus(property)]
+    fn tracks(&self) -> Result<Vec<OwnedObjectPath>>;
+
+    /// Whether tracks can be added and removed
+    #[zbus(property)]
+    fn can_edit_tracks(&self) -> Result<bool>;
+}
+
+/// MPRIS MediaPlayer2.Playlists interface proxy
+///
+/// Provides access to the playlists of players that expose them
+#[proxy(
+    interface = "org.mpris.MediaPlayer2.Playlists",
+    default_service = "org.mpris.MediaPlayer2",
+    default_path = "/org/mpris/MediaPlayer2"
+)]
+pub trait MediaPlayer2Playlists {
+    /// Start playing a playlist
+    fn activate_playlist(&self, playlist_id: &ObjectPath<'_>) -> Result<()>;
+
+    /// Get a page of playlists as (id, name, icon) entries
+    fn get_playlists(
+        &self,
+        index: u32,
+        max_count: u32,
+        order: &str,
+        reverse_order: bool,
+    ) -> Result<Vec<(OwnedObjectPath, String, String)>>;
+
+    /// Signal emitted when a playlist's name or icon changes
+    #[zbus(signal)]
+    fn playlist_changed(&self, playlist: (OwnedObjectPath, String, String)) -> Result<()>;
+
+    /// Number of playlists available
+    #[zbus(property)]
+    fn playlist_count(&self) -> Result<u32>;
+
+    /// Orderings the player can sort playlists by
+    #[zbus(property)]
+    fn orderings(&self) -> Result<Vec<String>>;
+
+    /// Currently active playlist, if the first field is true
+    #[zbus(property)]
+    fn active_playlist(&self) -> Result<(bool, (OwnedObjectPath, String, String))>;
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{PlaybackState, PlayerId};
+
+/// How the media service picks the active player on its own
+///
+/// A player pinned with [`MediaService::set_active_player`] stays active
+/// under either policy for as long as it exists.
+///
+/// [`MediaService::set_active_player`]: super::MediaService::set_active_player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivePlayerPolicy {
+    /// Follow playback: the player that most recently started playing wins,
+    /// then the one most recently paused
+    #[default]
+    Recent,
+    /// Keep the active player until it disappears, then fall back as with
+    /// `recent`
+    Manual,
+}
+
+/// Playback history of one player
+#[derive(Debug, Clone, Copy)]
+struct PlayerActivity {
+    state: PlaybackState,
+    started_playing: Option<Instant>,
+    paused: Option<Instant>,
+}
+
+impl Default for PlayerActivity {
+    fn default() -> Self {
+        Self {
+            state: PlaybackState::Stopped,
+            started_playing: None,
+            paused: None,
+        }
+    }
+}
+
+/// Ranks players for the active slot from their playback history
+#[derive(Debug, Default)]
+pub(crate) struct ActivePlayerSelector {
+    policy: ActivePlayerPolicy,
+    pinned: Option<PlayerId>,
+    activity: HashMap<PlayerId, PlayerActivity>,
+}
+
+impl ActivePlayerSelector {
+    pub(crate) fn new(policy: ActivePlayerPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn pin(&mut self, player_id: Option<PlayerId>) {
+        self.pinned = player_id;
+    }
+
+    pub(crate) fn pinned(&self) -> Option<&PlayerId> {
+        self.pinned.as_ref()
+    }
+
+    /// Record a player's playback state, stamping transitions with `now`
+    pub(crate) fn record(&mut self, player_id: &PlayerId, state: PlaybackState, now: Instant) {
+        let activity = self.activity.entry(player_id.clone()).or_default();
+
+        match state {
+            PlaybackState::Playing if activity.state != PlaybackState::Playing => {
+                activity.started_playing = Some(now);
+            }
+            PlaybackState::Paused if activity.state != PlaybackState::Paused => {
+                activity.paused = Some(now);
+            }
+            _ => {}
+        }
+
+        activity.state = state;
+    }
+
+    /// Forget a player that disappeared
+    ///
+    /// The pin is kept, so a pinned player that restarts becomes active
+    /// again.
+    pub(crate) fn remove(&mut self, player_id: &PlayerId) {
+        self.activity.remove(player_id);
+    }
+
+    /// Pick the active player among `players`, given in discovery order
+    ///
+    /// Order of preference: the pinned player, the current player under the
+    /// manual policy, the player that most recently started playing, the
+    /// player most recently paused, the current player, the first player.
+    pub(crate) fn select<'a>(
+        &self,
+        players: &'a [PlayerId],
+        current: Option<&PlayerId>,
+    ) -> Option<&'a PlayerId> {
+        let present = |id: Option<&PlayerId>| id.and_then(|id| players.iter().find(|p| *p == id));
+
+        if let Some(pinned) = present(self.pinned.as_ref()) {
+            return Some(pinned);
+        }
+
+        if self.policy == ActivePlayerPolicy::Manual
+            && let Some(current) = present(current)
+        {
+            return Some(current);
+        }
+
+        self.most_recent(players, PlaybackState::Playing, |a| a.started_playing)
+            .or_else(|| self.most_recent(players, PlaybackState::Paused, |a| a.paused))
+            .or_else(|| present(current))
+            .or_else(|| players.first())
+    }
+
+    fn most_recent<'a>(
+        &self,
+        players: &'a [PlayerId],
+        state: PlaybackState,
+        since: impl Fn(&PlayerActivity) -> Option<Instant>,
+    ) -> Option<&'a PlayerId> {
+        players
+            .iter()
+            .filter_map(|id| {
+                let activity = self.activity.get(id)?;
+                (activity.state == state).then_some((id, since(activity)))
+            })
+            .max_by_key(|&(_, at)| at)
+            .map(|(id, _)| id)
+    }
+}
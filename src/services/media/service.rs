@@ -2,19 +2,29 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::Stream;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tracing::{info, instrument};
 use zbus::Connection;
 
 use crate::services::common::Property;
 
-use super::{MediaError, PlayerId, core::Player, monitoring::MprisMonitoring};
+use super::{
+    MediaError, PlayerId,
+    art::{ArtResolver, ArtSettings},
+    core::Player,
+    monitoring::MprisMonitoring,
+    selection::{ActivePlayerPolicy, ActivePlayerSelector},
+};
 
 /// Configuration for the MPRIS service
 #[derive(Default)]
 pub struct Config {
     /// Patterns to ignore when discovering players
     pub ignored_players: Vec<String>,
+    /// How the active player is chosen when none is pinned
+    pub active_player_policy: ActivePlayerPolicy,
+    /// Album art cache settings; art paths are not resolved when `None`
+    pub art: Option<ArtSettings>,
 }
 
 /// MPRIS service with reactive property-based architecture.
@@ -26,6 +36,8 @@ pub struct MediaService {
     players: Arc<RwLock<HashMap<PlayerId, Arc<Player>>>>,
     player_list: Property<Vec<Arc<Player>>>,
     active_player: Property<Option<Arc<Player>>>,
+    selector: Arc<Mutex<ActivePlayerSelector>>,
+    art_resolver: Option<Arc<ArtResolver>>,
     ignored_patterns: Vec<String>,
 }
 
@@ -38,7 +50,11 @@ impl MediaService {
     ///
     /// Returns `MediaError::InitializationFailed` if D-Bus connection fails
     pub async fn new(ignored_players: Vec<String>) -> Result<Self, MediaError> {
-        Self::start(Config { ignored_players }).await
+        Self::start(Config {
+            ignored_players,
+            ..Config::default()
+        })
+        .await
     }
 
     /// Start the MPRIS service with the given configuration.
@@ -54,11 +70,22 @@ impl MediaService {
             MediaError::InitializationFailed(format!("D-Bus connection failed: {e}"))
         })?;
 
+        let art_resolver = match config.art {
+            Some(settings) => Some(Arc::new(ArtResolver::new(settings).await.map_err(|e| {
+                MediaError::InitializationFailed(format!("Art cache failed: {e}"))
+            })?)),
+            None => None,
+        };
+
         let service = Self {
             connection,
             players: Arc::new(RwLock::new(HashMap::new())),
             player_list: Property::new(Vec::new()),
             active_player: Property::new(None),
+            selector: Arc::new(Mutex::new(ActivePlayerSelector::new(
+                config.active_player_policy,
+            ))),
+            art_resolver,
             ignored_patterns: config.ignored_players,
         };
 
@@ -67,6 +94,8 @@ impl MediaService {
             Arc::clone(&service.players),
             service.player_list.clone(),
             service.active_player.clone(),
+            Arc::clone(&service.selector),
+            service.art_resolver.clone(),
             service.ignored_patterns.clone(),
         )
         .await?;
@@ -85,7 +114,12 @@ impl MediaService {
     /// Returns `MediaError::PlayerNotFound` if the player doesn't exist.
     /// Returns `MediaError::DbusError` if D-Bus operations fail.
     pub async fn player(&self, player_id: &PlayerId) -> Result<Arc<Player>, MediaError> {
-        Player::get(&self.connection, player_id.clone()).await
+        Player::get(
+            &self.connection,
+            player_id.clone(),
+            self.art_resolver.as_ref(),
+        )
+        .await
     }
 
     /// Get a live-updating instance of a specific media player.
@@ -99,7 +133,20 @@ impl MediaService {
     /// Returns `MediaError::PlayerNotFound` if the player doesn't exist.
     /// Returns `MediaError::DbusError` if D-Bus operations fail.
     pub async fn player_monitored(&self, player_id: &PlayerId) -> Result<Arc<Player>, MediaError> {
-        Player::get_live(&self.connection, player_id.clone()).await
+        Player::get_live(
+            &self.connection,
+            player_id.clone(),
+            self.art_resolver.as_ref(),
+        )
+        .await
+    }
+
+    /// Get the album art resolver, if the service has an art cache.
+    ///
+    /// Useful for resolving art URLs that don't come from a player's
+    /// current track, such as track list entries.
+    pub fn art_resolver(&self) -> Option<Arc<ArtResolver>> {
+        self.art_resolver.clone()
     }
 
     /// Get the current list of available media players.
@@ -134,28 +181,39 @@ impl MediaService {
         self.active_player.watch()
     }
 
-    /// Set which media player should be considered active.
+    /// Get the player pinned as active, if any.
     ///
-    /// Sets the specified player as the active one, or clears the active
-    /// player if None is provided.
+    /// A pinned player stays active regardless of playback changes for as
+    /// long as it exists, and becomes active again when it reappears.
+    pub async fn pinned_player(&self) -> Option<PlayerId> {
+        self.selector.lock().await.pinned().cloned()
+    }
+
+    /// Pin which media player should be considered active.
+    ///
+    /// Pins the specified player as the active one, overriding the selection
+    /// policy. Passing None removes the pin and lets the policy pick the
+    /// active player again.
     ///
     /// # Errors
     ///
     /// Returns `MediaError::PlayerNotFound` if the specified player doesn't exist.
     pub async fn set_active_player(&self, player_id: Option<PlayerId>) -> Result<(), MediaError> {
-        if let Some(ref id) = player_id {
-            let players = self.players.read().await;
-            if !players.contains_key(id) {
-                return Err(MediaError::PlayerNotFound(id.clone()));
-            }
+        let players = self.players.read().await;
+        if let Some(ref id) = player_id
+            && !players.contains_key(id)
+        {
+            return Err(MediaError::PlayerNotFound(id.clone()));
         }
 
-        let player = match player_id {
-            Some(ref id) => self.player(id).await.ok(),
-            None => None,
-        };
-
-        self.active_player.set(player);
+        self.selector.lock().await.pin(player_id);
+        MprisMonitoring::select_active_player(
+            &players,
+            &self.player_list,
+            &self.active_player,
+            &self.selector,
+        )
+        .await;
 
         Ok(())
     }
@@ -0,0 +1,365 @@
+//! Unit tests for media service
+//!
+//! Exercises active player selection on plain player ids and explicit
+//! instants, and album art resolution against temporary cache directories
+//! and a local HTTP stand-in, so no MPRIS players or D-Bus connection are
+//! required.
+
+#![allow(clippy::panic)]
+
+use std::{
+    io::Cursor,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use image::{GenericImageView, ImageFormat, RgbImage};
+use tempfile::TempDir;
+
+use crate::services::media::{
+    ActivePlayerPolicy, ArtError, ArtResolver, ArtSettings, PlaybackState, PlayerId,
+    art::ArtSource, selection::ActivePlayerSelector,
+};
+
+fn ids() -> [PlayerId; 3] {
+    [
+        PlayerId::from_bus_name("org.mpris.MediaPlayer2.spotify"),
+        PlayerId::from_bus_name("org.mpris.MediaPlayer2.firefox"),
+        PlayerId::from_bus_name("org.mpris.MediaPlayer2.mpv"),
+    ]
+}
+
+#[test]
+fn most_recently_started_player_wins() {
+    let [spotify, firefox, mpv] = ids();
+    let players = [spotify.clone(), firefox.clone(), mpv.clone()];
+    let start = Instant::now();
+    let mut selector = ActivePlayerSelector::new(ActivePlayerPolicy::Recent);
+
+    selector.record(&spotify, PlaybackState::Playing, start);
+    selector.record(
+        &firefox,
+        PlaybackState::Playing,
+        start + Duration::from_secs(1),
+    );
+    assert_eq!(selector.select(&players, Some(&spotify)), Some(&firefox));
+
+    selector.record(
+        &firefox,
+        PlaybackState::Paused,
+        start + Duration::from_secs(2),
+    );
+    assert_eq!(selector.select(&players, Some(&firefox)), Some(&spotify));
+}
+
+#[test]
+fn falls_back_to_most_recently_paused_player() {
+    let [spotify, firefox, mpv] = ids();
+    let players = [spotify.clone(), firefox.clone(), mpv.clone()];
+    let start = Instant::now();
+    let mut selector = ActivePlayerSelector::new(ActivePlayerPolicy::Recent);
+
+    selector.record(&spotify, PlaybackState::Playing, start);
+    selector.record(
+        &firefox,
+        PlaybackState::Playing,
+        start + Duration::from_secs(1),
+    );
+    selector.record(
+        &firefox,
+        PlaybackState::Paused,
+        start + Duration::from_secs(2),
+    );
+    selector.record(
+        &spotify,
+        PlaybackState::Paused,
+        start + Duration::from_secs(3),
+    );
+    selector.record(&mpv, PlaybackState::Stopped, start + Duration::from_secs(4));
+
+    assert_eq!(selector.select(&players, Some(&mpv)), Some(&spotify));
+
+    selector.remove(&spotify);
+    let remaining = [firefox.clone(), mpv.clone()];
+    assert_eq!(selector.select(&remaining, None), Some(&firefox));
+}
+
+#[test]
+fn pinned_player_stays_active() {
+    let [spotify, firefox, mpv] = ids();
+    let players = [spotify.clone(), firefox.clone(), mpv.clone()];
+    let start = Instant::now();
+    let mut selector = ActivePlayerSelector::new(ActivePlayerPolicy::Recent);
+
+    selector.pin(Some(mpv.clone()));
+    selector.record(&firefox, PlaybackState::Playing, start);
+    assert_eq!(selector.select(&players, Some(&mpv)), Some(&mpv));
+
+    let without_mpv = [spotify.clone(), firefox.clone()];
+    assert_eq!(selector.select(&without_mpv, Some(&mpv)), Some(&firefox));
+
+    selector.pin(None);
+    assert_eq!(selector.select(&players, Some(&mpv)), Some(&firefox));
+}
+
+#[test]
+fn manual_policy_keeps_current_player() {
+    let [spotify, firefox, mpv] = ids();
+    let players = [spotify.clone(), firefox.clone(), mpv.clone()];
+    let mut selector = ActivePlayerSelector::new(ActivePlayerPolicy::Manual);
+
+    selector.record(&firefox, PlaybackState::Playing, Instant::now());
+    assert_eq!(selector.select(&players, Some(&spotify)), Some(&spotify));
+    assert_eq!(selector.select(&players, None), Some(&firefox));
+}
+
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let Ok(()) =
+        RgbImage::new(width, height).write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+    else {
+        panic!("failed to encode test image");
+    };
+    bytes
+}
+
+fn data_uri(bytes: &[u8]) -> String {
+    format!("data:image/png;base64,{}", STANDARD.encode(bytes))
+}
+
+async fn resolver(cache: &TempDir, max_entries: usize) -> ArtResolver {
+    let settings = ArtSettings {
+        max_entries,
+        thumbnail_size: 256,
+        ..ArtSettings::new(cache.path().to_path_buf())
+    };
+    let Ok(resolver) = ArtResolver::new(settings).await else {
+        panic!("failed to open art cache");
+    };
+    resolver
+}
+
+fn dimensions(path: &Path) -> (u32, u32) {
+    let Ok(image) = image::open(path) else {
+        panic!("cached art at {} is not an image", path.display());
+    };
+    image.dimensions()
+}
+
+async fn resolve(resolver: &ArtResolver, art_url: &str) -> std::path::PathBuf {
+    match resolver.resolve(art_url).await {
+        Ok(path) => path,
+        Err(e) => panic!("failed to resolve {art_url}: {e}"),
+    }
+}
+
+#[test]
+fn art_source_parses_supported_urls() {
+    assert!(matches!(
+        ArtSource::parse("file:///home/user/My%20Music/cover.jpg"),
+        Ok(ArtSource::File(path)) if path == Path::new("/home/user/My Music/cover.jpg")
+    ));
+    assert!(matches!(
+        ArtSource::parse("file://localhost/tmp/cover.png"),
+        Ok(ArtSource::File(path)) if path == Path::new("/tmp/cover.png")
+    ));
+    assert!(matches!(
+        ArtSource::parse("/tmp/cover.png"),
+        Ok(ArtSource::File(path)) if path == Path::new("/tmp/cover.png")
+    ));
+    assert!(matches!(
+        ArtSource::parse("data:image/png;base64,AQID"),
+        Ok(ArtSource::Data(bytes)) if bytes == [1, 2, 3]
+    ));
+    assert!(matches!(
+        ArtSource::parse("https://i.scdn.co/image/abc"),
+        Ok(ArtSource::Http(url)) if url == "https://i.scdn.co/image/abc"
+    ));
+    assert!(matches!(
+        ArtSource::parse("spotify:image:abc"),
+        Err(ArtError::UnsupportedSource(_))
+    ));
+    assert!(matches!(
+        ArtSource::parse("data:image/png;base64"),
+        Err(ArtError::InvalidDataUri(_))
+    ));
+}
+
+#[tokio::test]
+async fn art_resolver_caches_bounded_thumbnails() {
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 10).await;
+    let art_url = data_uri(&png(1024, 512));
+
+    let path = resolve(&resolver, &art_url).await;
+    assert!(path.starts_with(cache.path()));
+    assert_eq!(dimensions(&path), (256, 128));
+
+    let small = resolve(&resolver, &data_uri(&png(64, 32))).await;
+    assert_eq!(dimensions(&small), (64, 32));
+
+    assert_eq!(resolve(&resolver, &art_url).await, path);
+}
+
+#[tokio::test]
+async fn art_resolver_picks_up_replaced_local_files() {
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let source = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 10).await;
+    let cover = source.path().join("cover.png");
+    let art_url = format!("file://{}", cover.display());
+
+    std::fs::write(&cover, png(32, 32)).unwrap_or_else(|e| panic!("{e}"));
+    let first = resolve(&resolver, &art_url).await;
+    assert_eq!(dimensions(&first), (32, 32));
+
+    std::fs::write(&cover, png(48, 16)).unwrap_or_else(|e| panic!("{e}"));
+    let second = resolve(&resolver, &art_url).await;
+    assert_ne!(first, second);
+    assert_eq!(dimensions(&second), (48, 16));
+}
+
+#[tokio::test]
+async fn art_cache_evicts_least_recently_used() {
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 2).await;
+
+    let first = resolve(&resolver, &data_uri(&png(10, 10))).await;
+    let second = resolve(&resolver, &data_uri(&png(20, 20))).await;
+    resolve(&resolver, &data_uri(&png(10, 10))).await;
+    let third = resolve(&resolver, &data_uri(&png(30, 30))).await;
+
+    assert!(first.exists());
+    assert!(!second.exists());
+    assert!(third.exists());
+
+    let reopened = self::resolver(&cache, 2).await;
+    assert_eq!(resolve(&reopened, &data_uri(&png(30, 30))).await, third);
+    assert!(first.exists());
+}
+
+#[tokio::test]
+async fn art_cache_of_size_zero_keeps_the_latest_entry() {
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 0).await;
+
+    let first = resolve(&resolver, &data_uri(&png(10, 10))).await;
+    assert!(first.exists());
+
+    let second = resolve(&resolver, &data_uri(&png(20, 20))).await;
+    assert!(!first.exists());
+    assert!(second.exists());
+}
+
+#[tokio::test]
+async fn art_resolver_respects_disabled_http() {
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let settings = ArtSettings {
+        fetch_http: false,
+        ..ArtSettings::new(cache.path().to_path_buf())
+    };
+    let Ok(resolver) = ArtResolver::new(settings).await else {
+        panic!("failed to open art cache");
+    };
+
+    assert!(matches!(
+        resolver.resolve("http://127.0.0.1:9/cover.png").await,
+        Err(ArtError::HttpDisabled(_))
+    ));
+}
+
+/// Accept one connection on `listener` and wait for the end of its request
+#[cfg(feature = "art-http")]
+async fn accept_request(listener: &tokio::net::TcpListener) -> Option<tokio::net::TcpStream> {
+    use tokio::io::AsyncReadExt;
+
+    let (mut socket, _) = listener.accept().await.ok()?;
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        match socket.read(&mut buffer).await {
+            Ok(0) | Err(_) => return None,
+            Ok(read) => request.extend_from_slice(&buffer[..read]),
+        }
+    }
+
+    Some(socket)
+}
+
+#[cfg(feature = "art-http")]
+#[tokio::test]
+async fn art_resolver_fetches_http_art() {
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap_or_else(|e| panic!("{e}"));
+    let address = listener.local_addr().unwrap_or_else(|e| panic!("{e}"));
+    let body = png(600, 300);
+
+    let server = tokio::spawn(async move {
+        let Some(mut socket) = accept_request(&listener).await else {
+            return;
+        };
+
+        let header = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        let _ = socket.write_all(header.as_bytes()).await;
+        let _ = socket.write_all(&body).await;
+    });
+
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 10).await;
+    let art_url = format!("http://{address}/cover.png");
+
+    let path = resolve(&resolver, &art_url).await;
+    assert_eq!(dimensions(&path), (256, 128));
+
+    let _ = server.await;
+    assert_eq!(resolve(&resolver, &art_url).await, path);
+}
+
+#[cfg(feature = "art-http")]
+#[tokio::test]
+async fn art_resolver_stops_reading_oversized_http_bodies() {
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    use crate::services::media::art::MAX_ART_SOURCE_BYTES;
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap_or_else(|e| panic!("{e}"));
+    let address = listener.local_addr().unwrap_or_else(|e| panic!("{e}"));
+
+    let server = tokio::spawn(async move {
+        let Some(mut socket) = accept_request(&listener).await else {
+            return;
+        };
+
+        let header = "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nConnection: close\r\n\r\n";
+        let _ = socket.write_all(header.as_bytes()).await;
+
+        let chunk = vec![0; 64 * 1024];
+        let mut sent = 0;
+        while sent <= 2 * MAX_ART_SOURCE_BYTES {
+            if socket.write_all(&chunk).await.is_err() {
+                return;
+            }
+            sent += chunk.len() as u64;
+        }
+    });
+
+    let cache = TempDir::new().unwrap_or_else(|e| panic!("{e}"));
+    let resolver = resolver(&cache, 10).await;
+    let art_url = format!("http://{address}/cover.png");
+
+    let result = resolver.resolve(&art_url).await;
+    assert!(
+        matches!(result, Err(ArtError::TooLarge { limit, .. }) if limit == MAX_ART_SOURCE_BYTES)
+    );
+
+    let _ = server.await;
+}
@@ -9,7 +9,8 @@ pub mod audio;
 
 pub use media::MediaService;
 pub use audio::{
-    AudioError, AudioEvent, AudioService, DeviceIndex, DeviceInfo, DeviceManager, DeviceStreams,
-    DeviceType, DeviceVolumeController, StreamIndex, StreamInfo, StreamManager, StreamStreams,
-    StreamType, StreamVolumeController, Volume,
+    AudioDevice, AudioError, AudioEvent, AudioService, AudioStream, CardIndex, CardInfo,
+    CardManager, CardProfile, CardStreams, ConnectionState, DeviceIndex, DeviceInfo, DeviceManager,
+    DeviceStreams, DeviceType, DeviceVolumeController, StreamIndex, StreamInfo, StreamManager,
+    StreamStreams, StreamType, StreamVolumeController, Volume,
 };